@@ -2,11 +2,35 @@
 pub const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
 pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+// Query pagination
+pub const DEFAULT_QUERY_LIMIT: u32 = 10;
+pub const MAX_QUERY_LIMIT: u32 = 30;
+
 pub const STATE_KEY: &str = "state";
 pub const COMMITS_KEY: &str = "commits";
+pub const COMMITS_STATE_INDEX_KEY: &str = "commits__state";
+pub const COMMITMENT_HISTORY_KEY: &str = "commitment_history";
 pub const PAID_IN_CAPITAL_KEY: &str = "paid_in_capital";
+pub const OFFERING_TOTALS_KEY: &str = "offering_totals";
 pub const SECURITIES_MAP_KEY: &str = "security_types_map";
+pub const CLOSED_SECURITIES_KEY: &str = "closed_securities";
 pub const REMAINING_SECURITIES_KEY: &str = "remaining_securities_map";
 pub const AVAILABLE_CAPITAL_KEY: &str = "available_capital";
-pub const LOAN_POOL_COLLATERAL: &str = "paid_in_capital";
+pub const LOAN_POOL_COLLATERAL: &str = "loan_pool_collateral";
 pub const WHITELIST_CONTRIBUTORS: &str = "whitelist_contributors";
+pub const CAPITAL_CALLS_KEY: &str = "capital_calls";
+pub const COUNTER_PROPOSALS_KEY: &str = "counter_proposals";
+pub const DISTRIBUTION_INDEXES_KEY: &str = "distribution_indexes";
+pub const HOLDINGS_KEY: &str = "holdings";
+pub const HOLDERS_KEY: &str = "holders";
+pub const REDEMPTION_WINDOWS_KEY: &str = "redemption_windows";
+pub const REDEMPTION_QUEUES_KEY: &str = "redemption_queues";
+pub const REDEMPTION_REQUESTS_KEY: &str = "redemption_requests";
+pub const NAV_HISTORY_KEY: &str = "nav_history";
+pub const REQUIRED_ATTRIBUTES_KEY: &str = "required_attributes";
+pub const LP_ALLOWLIST_KEY: &str = "lp_allowlist";
+pub const SETTLEMENT_TIMES_KEY: &str = "settlement_times";
+pub const FEE_SCHEDULE_KEY: &str = "fee_schedule";
+pub const FEE_ACCRUALS_KEY: &str = "fee_accruals";
+pub const PENDING_GP_KEY: &str = "pending_gp";
+pub const OPERATORS_KEY: &str = "operators";
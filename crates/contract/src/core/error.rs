@@ -94,6 +94,105 @@ pub enum ContractError {
 
     #[error("Invalid address: {message}")]
     InvalidAddress { message: String },
+
+    #[error("Invalid capital call")]
+    InvalidCapitalCall {},
+
+    #[error("The capital call exceeds the commitment amount")]
+    CapitalCallExceedsCommitment {},
+
+    #[error("The deposit exceeds the called capital")]
+    DepositExceedsCapitalCall {},
+
+    #[error("The capital call is past its due date")]
+    CapitalCallOverdue {},
+
+    #[error("The called capital has already been released to the GP")]
+    CapitalAlreadyReleased {},
+
+    #[error("The commitment does not have a counter proposal")]
+    MissingCounterProposal {},
+
+    #[error("Invalid minimum raise")]
+    InvalidMinimumRaise {},
+
+    #[error("The minimum raise has not been met")]
+    MinimumRaiseNotMet {},
+
+    #[error("A minimum raise requires a settlement time")]
+    MinimumRaiseRequiresSettlementTime {},
+
+    #[error("A refund is not available for this commitment")]
+    RefundUnavailable {},
+
+    #[error("Settlment time for this commitment has not expired")]
+    SettlementNotExpired {},
+
+    #[error("Invalid distribution")]
+    InvalidDistribution {},
+
+    #[error("There are no holders of the security to distribute to")]
+    NoDistributionHolders {},
+
+    #[error("There are no distributions to claim")]
+    NoClaimableDistributions {},
+
+    #[error("Invalid redemption window")]
+    InvalidRedemptionWindow {},
+
+    #[error("The security does not have an open redemption window")]
+    RedemptionWindowClosed {},
+
+    #[error("The redemption window's reserve cannot cover the payment")]
+    InsufficientRedemptionReserve {},
+
+    #[error("The lp does not hold enough units of the security")]
+    InsufficientUnits {},
+
+    #[error("Invalid NAV")]
+    InvalidNav {},
+
+    #[error("The security cannot be resized below the amount that has been accepted")]
+    InvalidSecurityResize {},
+
+    #[error("The security is closed to new proposals")]
+    SecurityClosed {},
+
+    #[error("The offering status does not allow this action")]
+    InvalidOfferingStatus {},
+
+    #[error("The lp is not eligible to invest in {security}")]
+    IneligibleLp { security: String },
+
+    #[error("Invalid eligibility rules")]
+    InvalidEligibilityRules {},
+
+    #[error("Empty lp list")]
+    EmptyLpList {},
+
+    #[error("Invalid allocation limits")]
+    InvalidAllocationLimits {},
+
+    #[error("The investment tokens are still locked up")]
+    InvestmentLocked {},
+
+    #[error("Invalid investment transfer")]
+    InvalidInvestmentTransfer {},
+
+    #[error("Invalid fee schedule")]
+    InvalidFeeSchedule {},
+
+    #[error("Invalid GP transfer")]
+    InvalidGpTransfer {},
+
+    #[error("The GP cannot be an operator")]
+    InvalidOperator {},
+
+    #[error("The contract is paused")]
+    ContractPaused {},
+
+    #[error("The commitment exceeds the {limit} of {security}")]
+    AllocationLimitExceeded { security: String, limit: String },
 }
 
 pub fn contract_error(err: &str) -> ProvTxResponse {
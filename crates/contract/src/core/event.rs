@@ -0,0 +1,275 @@
+use cosmwasm_std::{Addr, Coin, Event};
+
+use crate::execute::settlement::commitment::CommitmentState;
+
+use super::security::SecurityCommitment;
+
+// Every execute handler emits one of these events. The names and attribute keys are read by
+// indexers, so they must stay stable across contract versions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EventType {
+    // Commitment lifecycle
+    Proposed,
+    Accepted,
+    CounterProposed,
+    Deposited,
+    CapitalCalled,
+    CapitalReleased,
+    CommitmentsWithdrawn,
+    Settled,
+    Rejected,
+    Refunded,
+    Reclaimed,
+    Canceled,
+    SettlementTimeUpdated,
+    // Loan pools
+    LoanPoolAdded,
+    LoanPoolWithdrawn,
+    LoanPoolContributorsWhitelisted,
+    LoanPoolContributorsRemoved,
+    // Offering
+    SecurityAdded,
+    SecurityResized,
+    SecurityClosed,
+    EligibilityUpdated,
+    AllowlistAdded,
+    AllowlistRemoved,
+    OfferingClosed,
+    OfferingFinalized,
+    // Servicing
+    Distributed,
+    DistributionsClaimed,
+    NavUpdated,
+    InvestmentTransferred,
+    RedemptionWindowOpened,
+    RedemptionQueued,
+    Redeemed,
+    RedemptionQueueProcessed,
+    // Administration
+    GpTransferProposed,
+    GpTransferred,
+    OperatorAdded,
+    OperatorRemoved,
+    ContractPaused,
+    ContractUnpaused,
+}
+
+impl EventType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EventType::Proposed => "proposed",
+            EventType::Accepted => "accepted",
+            EventType::CounterProposed => "counter_proposed",
+            EventType::Deposited => "deposited",
+            EventType::CapitalCalled => "capital_called",
+            EventType::CapitalReleased => "capital_released",
+            EventType::CommitmentsWithdrawn => "commitments_withdrawn",
+            EventType::Settled => "settled",
+            EventType::Rejected => "rejected",
+            EventType::Refunded => "refunded",
+            EventType::Reclaimed => "reclaimed",
+            EventType::Canceled => "canceled",
+            EventType::SettlementTimeUpdated => "settlement_time_updated",
+            EventType::LoanPoolAdded => "loan_pool_added",
+            EventType::LoanPoolWithdrawn => "loan_pool_withdrawn",
+            EventType::LoanPoolContributorsWhitelisted => "loan_pool_contributors_whitelisted",
+            EventType::LoanPoolContributorsRemoved => "loan_pool_contributors_removed",
+            EventType::SecurityAdded => "security_added",
+            EventType::SecurityResized => "security_resized",
+            EventType::SecurityClosed => "security_closed",
+            EventType::EligibilityUpdated => "eligibility_updated",
+            EventType::AllowlistAdded => "allowlist_added",
+            EventType::AllowlistRemoved => "allowlist_removed",
+            EventType::OfferingClosed => "offering_closed",
+            EventType::OfferingFinalized => "offering_finalized",
+            EventType::Distributed => "distributed",
+            EventType::DistributionsClaimed => "distributions_claimed",
+            EventType::NavUpdated => "nav_updated",
+            EventType::InvestmentTransferred => "investment_transferred",
+            EventType::RedemptionWindowOpened => "redemption_window_opened",
+            EventType::RedemptionQueued => "redemption_queued",
+            EventType::Redeemed => "redeemed",
+            EventType::RedemptionQueueProcessed => "redemption_queue_processed",
+            EventType::GpTransferProposed => "gp_transfer_proposed",
+            EventType::GpTransferred => "gp_transferred",
+            EventType::OperatorAdded => "operator_added",
+            EventType::OperatorRemoved => "operator_removed",
+            EventType::ContractPaused => "contract_paused",
+            EventType::ContractUnpaused => "contract_unpaused",
+        }
+    }
+}
+
+// Builds the event for an action. Every event names the gp, and the shared attributes are always
+// written in the same order (gp, lp, securities, amount, state) no matter the order they are set in.
+// Attributes that only apply to one action follow them.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContractEvent {
+    event_type: EventType,
+    gp: Addr,
+    lp: Option<Addr>,
+    securities: Option<Vec<SecurityCommitment>>,
+    amount: Option<Coin>,
+    state: Option<CommitmentState>,
+    attributes: Vec<(String, String)>,
+}
+
+impl ContractEvent {
+    pub fn new(event_type: EventType, gp: &Addr) -> Self {
+        ContractEvent {
+            event_type,
+            gp: gp.clone(),
+            lp: None,
+            securities: None,
+            amount: None,
+            state: None,
+            attributes: vec![],
+        }
+    }
+
+    pub fn lp(mut self, lp: &Addr) -> Self {
+        self.lp = Some(lp.clone());
+        self
+    }
+
+    // The units of each security that the action applies to
+    pub fn securities(mut self, securities: &[SecurityCommitment]) -> Self {
+        self.securities = Some(securities.to_vec());
+        self
+    }
+
+    // The funds or marker coins that were moved by the action
+    pub fn amount(mut self, amount: Coin) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    // The state of the lp's commitment after the action
+    pub fn state(mut self, state: &CommitmentState) -> Self {
+        self.state = Some(state.clone());
+        self
+    }
+
+    pub fn attribute(mut self, key: &str, value: impl Into<String>) -> Self {
+        self.attributes.push((key.to_string(), value.into()));
+        self
+    }
+}
+
+impl From<ContractEvent> for Event {
+    fn from(event: ContractEvent) -> Self {
+        let mut result =
+            Event::new(event.event_type.as_str()).add_attribute("gp", event.gp.to_string());
+        if let Some(lp) = event.lp {
+            result = result.add_attribute("lp", lp.to_string());
+        }
+        if let Some(securities) = event.securities {
+            result = result.add_attribute("securities", format_securities(&securities));
+        }
+        if let Some(amount) = event.amount {
+            result = result.add_attribute("amount", amount.to_string());
+        }
+        if let Some(state) = event.state {
+            result = result.add_attribute("state", state_name(&state));
+        }
+        result.add_attributes(event.attributes)
+    }
+}
+
+// Securities are written as a comma separated list of name:amount pairs
+fn format_securities(securities: &[SecurityCommitment]) -> String {
+    securities
+        .iter()
+        .map(|security| format!("{}:{}", security.name, security.amount))
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+fn state_name(state: &CommitmentState) -> &'static str {
+    match state {
+        CommitmentState::PENDING => "pending",
+        CommitmentState::ACCEPTED => "accepted",
+        CommitmentState::SETTLED => "settled",
+        CommitmentState::REJECTED => "rejected",
+        CommitmentState::EXPIRED => "expired",
+        CommitmentState::CANCELED => "canceled",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{Addr, Coin, Event, Uint128};
+
+    use crate::{
+        core::security::SecurityCommitment, execute::settlement::commitment::CommitmentState,
+    };
+
+    use super::{ContractEvent, EventType};
+
+    #[test]
+    fn test_event_only_has_gp_by_default() {
+        let event: Event =
+            ContractEvent::new(EventType::OfferingClosed, &Addr::unchecked("gp")).into();
+        assert_eq!(
+            Event::new("offering_closed").add_attribute("gp", "gp"),
+            event
+        );
+    }
+
+    #[test]
+    fn test_shared_attributes_have_a_fixed_order() {
+        let securities = vec![
+            SecurityCommitment {
+                name: "Security1".to_string(),
+                amount: Uint128::new(100),
+            },
+            SecurityCommitment {
+                name: "Security2".to_string(),
+                amount: Uint128::new(50),
+            },
+        ];
+        let event: Event = ContractEvent::new(EventType::Deposited, &Addr::unchecked("gp"))
+            .attribute("reason", "test")
+            .state(&CommitmentState::ACCEPTED)
+            .amount(Coin::new(1500u128, "denom"))
+            .securities(&securities)
+            .lp(&Addr::unchecked("lp"))
+            .into();
+
+        assert_eq!(
+            Event::new("deposited")
+                .add_attribute("gp", "gp")
+                .add_attribute("lp", "lp")
+                .add_attribute("securities", "Security1:100,Security2:50")
+                .add_attribute("amount", "1500denom")
+                .add_attribute("state", "accepted")
+                .add_attribute("reason", "test"),
+            event
+        );
+    }
+
+    #[test]
+    fn test_state_names() {
+        let names: Vec<String> = [
+            CommitmentState::PENDING,
+            CommitmentState::ACCEPTED,
+            CommitmentState::SETTLED,
+            CommitmentState::REJECTED,
+            CommitmentState::EXPIRED,
+            CommitmentState::CANCELED,
+        ]
+        .iter()
+        .map(|state| {
+            let event: Event = ContractEvent::new(EventType::Canceled, &Addr::unchecked("gp"))
+                .state(state)
+                .into();
+            event.attributes[1].value.clone()
+        })
+        .collect();
+
+        assert_eq!(
+            vec!["pending", "accepted", "settled", "rejected", "expired", "canceled"],
+            names
+        );
+    }
+}
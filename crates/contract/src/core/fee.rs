@@ -1,8 +1,26 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Coin};
+use cosmwasm_std::{Addr, Coin, Decimal};
 
 #[cw_serde]
 pub struct Fee {
     pub recipient: Option<Addr>,
     pub amount: Coin,
 }
+
+// The ongoing fees charged to each lp. The management fee accrues over time on the lp's capital,
+// and carry is charged on the distributions an lp receives beyond their capital and hurdle.
+#[cw_serde]
+pub struct FeeSchedule {
+    pub recipient: Addr,
+    pub management_fee_rate: Decimal, // annual rate
+    pub management_fee_basis: FeeBasis,
+    pub carry_rate: Decimal,
+    pub hurdle: Decimal, // return on paid in capital that the lp receives before carry is charged
+}
+
+#[cw_serde]
+#[derive(Eq)]
+pub enum FeeBasis {
+    Committed, // the capital the lp committed to once they are accepted
+    Called,    // the capital the lp has paid in
+}
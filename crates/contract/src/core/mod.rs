@@ -2,6 +2,7 @@ pub mod aliases;
 pub mod collateral;
 pub mod constants;
 pub mod error;
+pub mod event;
 pub mod fee;
 pub mod msg;
 pub mod security;
@@ -3,14 +3,22 @@ use crate::core::security::{
     ContributeLoanPools, LoanPoolContributors, RemoveLoanPoolContributors, WithdrawLoanPools,
 };
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Uint64};
+use cosmwasm_std::{Addr, Coin, Decimal, Uint128, Uint64};
 use cw2::ContractVersion;
 
 use crate::execute::settlement::commitment::{Commitment, CommitmentState};
+use crate::storage::capital_calls::CapitalCall;
+use crate::storage::commitment_history::HistoryEntry;
+use crate::storage::fees::FeeAccrual;
+use crate::storage::nav::NavEntry;
+use crate::storage::state::DepositMode;
 
 use super::{
-    fee::Fee,
-    security::{AcceptedCommitment, Security, SecurityCommitment},
+    fee::{Fee, FeeSchedule},
+    security::{
+        AcceptedCommitment, CapitalCallAmount, MinimumRaise, RedemptionTerms, Security,
+        SecurityCommitment,
+    },
 };
 
 #[cw_serde]
@@ -19,7 +27,12 @@ pub struct InstantiateMsg {
     pub securities: Vec<Security>,
     pub capital_denom: String,
     pub settlement_time: Option<Uint64>,
+    pub minimum_raise: Option<MinimumRaise>,
     pub fee: Option<Fee>,
+    pub maximum_lp_commitment: Option<Uint128>, // most capital a single lp can commit across every security
+    pub lockup_period: Option<Uint64>, // seconds after settlement before a lp can transfer their investment tokens
+    pub fee_schedule: Option<FeeSchedule>, // ongoing management fee and carry charged to each lp
+    pub deposit_mode: Option<DepositMode>, // how lps pay in capital, which defaults to marker transfers
 }
 
 #[cw_serde]
@@ -30,6 +43,11 @@ pub enum ExecuteMsg {
     AcceptCommitment {
         commitments: Vec<AcceptedCommitment>,
     },
+    CounterProposeCommitment {
+        lp: Addr,
+        securities: Vec<SecurityCommitment>,
+    },
+    AcceptCounterProposal {},
     DepositCommitment {
         securities: Vec<SecurityCommitment>,
     },
@@ -43,6 +61,81 @@ pub enum ExecuteMsg {
     CancelCommitment {
         lp: Addr,
     },
+    RejectCommitment {
+        lp: Addr,
+        reason: String,
+    },
+    IssueCapitalCall {
+        call: CapitalCallAmount,
+        due_date: Option<Uint64>,
+    },
+    ClaimRefund {
+        lp: Addr,
+    },
+    ReclaimExpiredDeposit {},
+    Distribute {
+        security: String,
+        amount: Uint128,
+    },
+    DistributeWaterfall {
+        amount: Uint128,
+    },
+    ClaimDistributions {},
+    OpenRedemptionWindow {
+        security: String,
+        terms: RedemptionTerms,
+    },
+    Redeem {
+        security: String,
+        amount: Uint128,
+    },
+    ProcessRedemptionQueue {
+        security: String,
+        limit: Option<u32>,
+    },
+    UpdateNav {
+        security: String,
+        nav_per_unit: Coin,
+    },
+    AddSecurity {
+        security: Security,
+    },
+    ResizeSecurity {
+        security: String,
+        amount: Uint128,
+    },
+    CloseSecurity {
+        security: String,
+    },
+    CloseOffering {},
+    FinalizeOffering {},
+    UpdateEligibility {
+        security: String,
+        required_attributes: Vec<String>,
+    },
+    AddToAllowlist {
+        lps: Vec<Addr>,
+    },
+    RemoveFromAllowlist {
+        lps: Vec<Addr>,
+    },
+    TransferInvestment {
+        security: String,
+        to: Addr,
+        amount: Uint128,
+    },
+    ProposeGpTransfer {
+        gp: Addr,
+    },
+    AcceptGpTransfer {},
+    AddOperator {
+        operator: Addr,
+    },
+    RemoveOperator {
+        operator: Addr,
+    },
+    Pause {},
+    Unpause {},
     ContributeLoanPool {
         loan_pools: ContributeLoanPools,
     },
@@ -64,7 +157,11 @@ pub enum QueryMsg {
     QueryInvestor { investor: Addr },
 
     #[returns(QueryCommitmentsResponse)]
-    QueryCommitments { commitment_state: CommitmentState },
+    QueryCommitments {
+        commitment_state: CommitmentState,
+        start_after: Option<Addr>,
+        limit: Option<u32>,
+    },
 
     #[returns(QuerySecuritizationsResponse)]
     QuerySecuritizations { securities: Vec<String> },
@@ -76,21 +173,73 @@ pub enum QueryMsg {
     QueryVersion {},
 
     #[returns(QueryLoanPoolCollateralResponse)]
-    QueryCollaterals {},
+    QueryCollaterals {
+        start_after: Option<Addr>,
+        limit: Option<u32>,
+    },
 
     #[returns(QueryLoanPoolContributorsResponse)]
-    QueryLoanPoolContributors {},
+    QueryLoanPoolContributors {
+        start_after: Option<Addr>,
+        limit: Option<u32>,
+    },
+
+    #[returns(QueryClaimableResponse)]
+    QueryClaimable { lp: Addr },
+
+    #[returns(QueryNavHistoryResponse)]
+    QueryNavHistory {
+        security: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+
+    #[returns(QueryFeeAccrualsResponse)]
+    QueryFeeAccruals { lp: Addr },
+
+    #[returns(QueryCommitmentHistoryResponse)]
+    QueryCommitmentHistory {
+        lp: Addr,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+
+    #[returns(QueryCapTableResponse)]
+    QueryCapTable {
+        security: Option<String>,
+        start_after: Option<Addr>,
+        limit: Option<u32>,
+    },
+
+    #[returns(QueryOfferingStatusResponse)]
+    QueryOfferingStatus {},
+
+    #[returns(QuerySimulateDepositResponse)]
+    QuerySimulateDeposit {
+        lp: Addr,
+        securities: Vec<SecurityCommitment>,
+    },
+
+    #[returns(QuerySimulateProposeResponse)]
+    QuerySimulatePropose {
+        lp: Addr,
+        securities: Vec<SecurityCommitment>,
+    },
 }
 
 #[cw_serde]
 pub struct QueryInvestorResponse {
     pub commitment: Commitment,
     pub paid_in_capital: Vec<SecurityCommitment>,
+    pub capital_call: Option<CapitalCall>,
+    pub counter_proposal: Option<Vec<SecurityCommitment>>,
 }
 
 #[cw_serde]
 pub struct QueryCommitmentsResponse {
     pub commitments: Vec<Commitment>,
+    // The lp to start the next page after, if there are more commitments
+    pub next_start_after: Option<Addr>,
 }
 
 #[cw_serde]
@@ -104,16 +253,126 @@ pub struct QueryStateResponse {
     pub securities: Vec<String>,
     pub capital_denom: String,
     pub settlement_time: Option<Uint64>,
+    pub paused: bool,
+    pub deposit_mode: DepositMode,
 }
 
 #[cw_serde]
 pub struct QueryLoanPoolCollateralResponse {
     pub collaterals: Vec<LoanPoolMarkerCollateral>,
+    // The marker address to start the next page after, if there are more collaterals
+    pub next_start_after: Option<Addr>,
 }
 
 #[cw_serde]
 pub struct QueryLoanPoolContributorsResponse {
     pub contributors: Vec<Addr>,
+    // The contributor to start the next page after, if there are more contributors
+    pub next_start_after: Option<Addr>,
+}
+
+#[cw_serde]
+pub struct QueryClaimableResponse {
+    pub claimable: Coin,
+}
+
+#[cw_serde]
+pub struct QueryNavHistoryResponse {
+    pub history: Vec<NavEntry>,
+    // The sequence number to start the next page after, if there are more entries
+    pub next_start_after: Option<u64>,
+}
+
+#[cw_serde]
+pub struct QueryFeeAccrualsResponse {
+    pub accrual: FeeAccrual,
+    pub outstanding: Coin,
+}
+
+#[cw_serde]
+pub struct QueryCommitmentHistoryResponse {
+    pub history: Vec<HistoryEntry>,
+    // The sequence number to start the next page after, if there are more entries
+    pub next_start_after: Option<u64>,
+}
+
+#[cw_serde]
+pub struct QueryCapTableResponse {
+    pub securities: Vec<CapTableSecurity>,
+    pub entries: Vec<CapTableEntry>,
+    // The lp to start the next page after, if there are more lps
+    pub next_start_after: Option<Addr>,
+}
+
+#[cw_serde]
+pub struct CapTableSecurity {
+    pub name: String,
+    pub total: Uint128,
+    pub sold: Uint128,
+    pub remaining: Uint128,
+}
+
+#[cw_serde]
+pub struct CapTableEntry {
+    pub lp: Addr,
+    pub security: String,
+    pub committed: Uint128,
+    pub paid_in_capital: Uint128,
+    // The investment tokens minted to the lp when their commitment settled
+    pub settled: Uint128,
+    // The investment tokens the lp currently holds, including any transferred to them
+    pub investment_tokens: Uint128,
+    pub percentage: Decimal,
+}
+
+#[cw_serde]
+pub struct QueryOfferingStatusResponse {
+    pub securities: Vec<OfferingSecurityStatus>,
+    pub totals: OfferingTotals,
+}
+
+// The units of a security at each stage of the offering
+#[cw_serde]
+pub struct OfferingSecurityStatus {
+    pub name: String,
+    pub total: Uint128,
+    // Proposed by lps whose commitments haven't been accepted yet
+    pub pending: Uint128,
+    // Accepted by the gp but not yet settled
+    pub accepted: Uint128,
+    // Paid for by lps with accepted or settled commitments
+    pub paid_in: Uint128,
+    pub settled: Uint128,
+    pub remaining: Uint128,
+}
+
+// The value of every security at each stage of the offering, priced at its price per unit
+#[cw_serde]
+pub struct OfferingTotals {
+    pub total: Coin,
+    pub pending: Coin,
+    pub accepted: Coin,
+    pub paid_in: Coin,
+    pub settled: Coin,
+    pub remaining: Coin,
+    // Capital deposited by lps that the gp hasn't withdrawn yet
+    pub available_capital: Coin,
+}
+
+#[cw_serde]
+pub struct QuerySimulateDepositResponse {
+    // The funds that will be transferred from the lp, or empty if the deposit would fail
+    pub funds: Vec<Coin>,
+    // The error that the deposit would fail with
+    pub error: Option<String>,
+}
+
+#[cw_serde]
+pub struct QuerySimulateProposeResponse {
+    // The lp's commitment once the proposal is added to it, or none if the proposal would fail
+    pub commitment: Option<Commitment>,
+    // The error that the proposal would fail with
+    pub error: Option<String>,
 }
 
 #[cw_serde]
@@ -122,4 +381,8 @@ pub struct QueryVersionResponse {
 }
 
 #[cw_serde]
-pub struct MigrateMsg {}
+#[derive(Default)]
+pub struct MigrateMsg {
+    // Leaves the contract paused or unpaused once every migration step has run
+    pub paused: Option<bool>,
+}
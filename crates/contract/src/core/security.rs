@@ -1,5 +1,5 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Coin, Uint128};
+use cosmwasm_std::{Addr, Coin, Decimal, Uint128, Uint64};
 
 #[cw_serde]
 #[derive(Eq)]
@@ -9,19 +9,50 @@ pub struct Security {
     pub security_type: SecurityType,
     pub minimum_amount: Uint128,
     pub price_per_unit: Coin,
+    #[serde(default)]
+    pub maximum_amount: Option<Uint128>, // most units a single lp can commit to
+    #[serde(default)]
+    pub maximum_percentage: Option<Decimal>, // largest share of the amount a single lp can commit to
+}
+
+impl Security {
+    // A lp must be able to commit to at least the minimum amount without breaking a limit.
+    // Option::is_none_or would read better, but it needs Rust 1.82.
+    #[allow(clippy::unnecessary_map_or)]
+    pub fn limits_are_valid(&self) -> bool {
+        let maximum_is_valid = self.maximum_amount.map_or(true, |maximum| {
+            maximum >= self.minimum_amount && !maximum.is_zero()
+        });
+        let percentage_is_valid = self.maximum_percentage.map_or(true, |percentage| {
+            !percentage.is_zero()
+                && percentage <= Decimal::one()
+                && self.amount.mul_floor(percentage) >= self.minimum_amount
+        });
+        maximum_is_valid && percentage_is_valid
+    }
 }
 
 #[cw_serde]
-#[derive(Eq)]
-pub struct FundSecurity {}
+#[derive(Eq, Default)]
+pub struct FundSecurity {
+    #[serde(default)]
+    pub pricing_agent: Option<Addr>, // account other than the GP that can publish the NAV
+}
 
 #[cw_serde]
 #[derive(Eq)]
 pub struct PrimarySecurity {}
 
 #[cw_serde]
-#[derive(Eq)]
-pub struct TrancheSecurity {}
+#[derive(Eq, Default)]
+pub struct TrancheSecurity {
+    #[serde(default)]
+    pub seniority: u32, // rank in the waterfall, where 0 is the most senior tranche
+    #[serde(default)]
+    pub target_return: Decimal, // return on principal that is paid before junior tranches, e.g. 0.08
+    #[serde(default)]
+    pub principal_cap: Option<Uint128>, // maximum principal that the tranche is repaid
+}
 
 #[cw_serde]
 #[derive(Eq)]
@@ -32,6 +63,7 @@ pub enum SecurityType {
 }
 
 #[cw_serde]
+#[derive(Eq)]
 pub struct SecurityCommitment {
     pub name: String,
     pub amount: Uint128,
@@ -43,6 +75,27 @@ pub struct AcceptedCommitment {
     pub securities: Vec<SecurityCommitment>,
 }
 
+#[cw_serde]
+pub enum CapitalCallAmount {
+    Percentage(Decimal), // percentage of every accepted commitment being called
+    Commitments(Vec<AcceptedCommitment>), // explicit amounts being called from each listed lp
+}
+
+#[cw_serde]
+#[derive(Eq)]
+pub enum MinimumRaise {
+    Securities(Vec<SecurityCommitment>), // minimum units of each security that must be paid in
+    Offering(Uint128), // minimum capital that must be paid in across every security
+}
+
+#[cw_serde]
+pub struct RedemptionTerms {
+    pub price_per_unit: Uint128, // capital paid for each redeemed unit
+    pub start_time: Uint64,      // first second that units can be redeemed
+    pub end_time: Uint64,        // last second that units can be redeemed
+    pub gate: Decimal,           // maximum share of the outstanding units redeemed in the window
+}
+
 #[cw_serde]
 pub struct ContributeLoanPools {
     pub markers: Vec<String>, // marker denom's for loan pools being contributed.
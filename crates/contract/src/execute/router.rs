@@ -2,17 +2,28 @@ use cosmwasm_std::{Env, MessageInfo};
 
 use crate::core::{
     aliases::{ProvDepsMut, ProvTxResponse},
+    error::ContractError,
     msg::ExecuteMsg,
 };
+use crate::storage::state::{self, OfferingStatus, State};
 
 use crate::execute::settlement::{
-    accept_commitments, cancel_commitment, deposit_commitment, propose_commitment,
-    remove_whitelist_loanpool_contributors, update_settlement_time,
-    whitelist_loanpool_contributors, withdraw_all_commitments, withdraw_commitment,
+    accept_commitments, accept_counter_proposal, accept_gp_transfer, add_operator, add_security,
+    add_to_allowlist, cancel_commitment, claim_distributions, claim_refund, close_offering,
+    close_security, counter_propose_commitment, deposit_commitment, distribute,
+    distribute_waterfall, finalize_offering, issue_capital_call, open_redemption_window, pause,
+    process_redemption_queue, propose_commitment, propose_gp_transfer, reclaim_expired_deposit,
+    redeem, reject_commitment, remove_from_allowlist, remove_operator,
+    remove_whitelist_loanpool_contributors, resize_security, transfer_investment,
+    update_eligibility, update_nav, update_settlement_time, whitelist_loanpool_contributors,
+    withdraw_all_commitments, withdraw_commitment,
 };
 use crate::execute::settlement::{add_loan_pool, withdraw_loan_pool};
 
 pub fn route(deps: ProvDepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> ProvTxResponse {
+    let state = state::get(deps.storage)?;
+    check_allowed(&state, &msg)?;
+
     match msg {
         ExecuteMsg::ProposeCommitment { securities } => {
             propose_commitment::handle(deps, &env, info.sender, securities)
@@ -20,8 +31,14 @@ pub fn route(deps: ProvDepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) ->
         ExecuteMsg::AcceptCommitment { commitments } => {
             accept_commitments::handle(deps, env, info.sender, commitments)
         }
+        ExecuteMsg::CounterProposeCommitment { lp, securities } => {
+            counter_propose_commitment::handle(deps, env, info.sender, lp, securities)
+        }
+        ExecuteMsg::AcceptCounterProposal {} => {
+            accept_counter_proposal::handle(deps, env, info.sender)
+        }
         ExecuteMsg::DepositCommitment { securities } => {
-            deposit_commitment::handle(deps, env, info.sender, securities)
+            deposit_commitment::handle(deps, env, info.sender, info.funds, securities)
         }
         ExecuteMsg::WithdrawCommitment { lp } => {
             withdraw_commitment::handle(deps, env, info.sender, lp)
@@ -35,6 +52,72 @@ pub fn route(deps: ProvDepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) ->
         ExecuteMsg::CancelCommitment { lp } => {
             cancel_commitment::handle(deps, env, info.sender, lp)
         }
+        ExecuteMsg::RejectCommitment { lp, reason } => {
+            reject_commitment::handle(deps, env, info.sender, lp, reason)
+        }
+        ExecuteMsg::IssueCapitalCall { call, due_date } => {
+            issue_capital_call::handle(deps, env, info.sender, call, due_date)
+        }
+        ExecuteMsg::ClaimRefund { lp } => claim_refund::handle(deps, env, info.sender, lp),
+        ExecuteMsg::ReclaimExpiredDeposit {} => {
+            reclaim_expired_deposit::handle(deps, env, info.sender)
+        }
+        ExecuteMsg::Distribute { security, amount } => {
+            distribute::handle(deps, env, info.sender, info.funds, security, amount)
+        }
+        ExecuteMsg::DistributeWaterfall { amount } => {
+            distribute_waterfall::handle(deps, env, info.sender, info.funds, amount)
+        }
+        ExecuteMsg::ClaimDistributions {} => claim_distributions::handle(deps, env, info.sender),
+        ExecuteMsg::OpenRedemptionWindow { security, terms } => {
+            open_redemption_window::handle(deps, env, info.sender, info.funds, security, terms)
+        }
+        ExecuteMsg::Redeem { security, amount } => {
+            redeem::handle(deps, env, info.sender, security, amount)
+        }
+        ExecuteMsg::ProcessRedemptionQueue { security, limit } => {
+            process_redemption_queue::handle(deps, env, info.sender, security, limit)
+        }
+        ExecuteMsg::UpdateNav {
+            security,
+            nav_per_unit,
+        } => update_nav::handle(deps, env, info.sender, security, nav_per_unit),
+        ExecuteMsg::AddSecurity { security } => {
+            add_security::handle(deps, env, info.sender, security)
+        }
+        ExecuteMsg::ResizeSecurity { security, amount } => {
+            resize_security::handle(deps, env, info.sender, security, amount)
+        }
+        ExecuteMsg::CloseSecurity { security } => {
+            close_security::handle(deps, env, info.sender, security)
+        }
+        ExecuteMsg::CloseOffering {} => close_offering::handle(deps, env, info.sender),
+        ExecuteMsg::FinalizeOffering {} => finalize_offering::handle(deps, env, info.sender),
+        ExecuteMsg::UpdateEligibility {
+            security,
+            required_attributes,
+        } => update_eligibility::handle(deps, env, info.sender, security, required_attributes),
+        ExecuteMsg::AddToAllowlist { lps } => add_to_allowlist::handle(deps, env, info.sender, lps),
+        ExecuteMsg::RemoveFromAllowlist { lps } => {
+            remove_from_allowlist::handle(deps, env, info.sender, lps)
+        }
+        ExecuteMsg::TransferInvestment {
+            security,
+            to,
+            amount,
+        } => transfer_investment::handle(deps, env, info.sender, security, to, amount),
+        ExecuteMsg::ProposeGpTransfer { gp } => {
+            propose_gp_transfer::handle(deps, env, info.sender, gp)
+        }
+        ExecuteMsg::AcceptGpTransfer {} => accept_gp_transfer::handle(deps, env, info.sender),
+        ExecuteMsg::AddOperator { operator } => {
+            add_operator::handle(deps, env, info.sender, operator)
+        }
+        ExecuteMsg::RemoveOperator { operator } => {
+            remove_operator::handle(deps, env, info.sender, operator)
+        }
+        ExecuteMsg::Pause {} => pause::handle(deps, env, info.sender, true),
+        ExecuteMsg::Unpause {} => pause::handle(deps, env, info.sender, false),
         ExecuteMsg::ContributeLoanPool { loan_pools } => {
             add_loan_pool::handle(deps, env, info, loan_pools)
         }
@@ -58,12 +141,88 @@ pub fn route(deps: ProvDepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) ->
     }
 }
 
+// Rejects the message if the contract is paused or the offering is past the point it can be sent
+pub fn check_allowed(state: &State, msg: &ExecuteMsg) -> Result<(), ContractError> {
+    if state.paused && !pause_allows(msg) {
+        return Err(ContractError::ContractPaused {});
+    }
+    if !status_allows(&state.status, msg) {
+        return Err(ContractError::InvalidOfferingStatus {});
+    }
+    Ok(())
+}
+
+// While paused, only messages that unwind commitments, return capital to the lps,
+// or revoke an operator can be sent
+fn pause_allows(msg: &ExecuteMsg) -> bool {
+    matches!(
+        msg,
+        ExecuteMsg::Pause {}
+            | ExecuteMsg::Unpause {}
+            | ExecuteMsg::CancelCommitment { .. }
+            | ExecuteMsg::RejectCommitment { .. }
+            | ExecuteMsg::ClaimRefund { .. }
+            | ExecuteMsg::ReclaimExpiredDeposit {}
+            | ExecuteMsg::RemoveOperator { .. }
+    )
+}
+
+// A closed offering no longer takes new commitments, counter proposals, or securities, but a
+// counter proposal made before it closed can still be accepted. Once it is finalized only the
+// investment itself, and who can hold it, can be serviced
+fn status_allows(status: &OfferingStatus, msg: &ExecuteMsg) -> bool {
+    match status {
+        OfferingStatus::Open => true,
+        OfferingStatus::Closed => !matches!(
+            msg,
+            ExecuteMsg::ProposeCommitment { .. }
+                | ExecuteMsg::CounterProposeCommitment { .. }
+                | ExecuteMsg::AddSecurity { .. }
+                | ExecuteMsg::CloseOffering {}
+        ),
+        OfferingStatus::Finalized => matches!(
+            msg,
+            ExecuteMsg::Distribute { .. }
+                | ExecuteMsg::DistributeWaterfall { .. }
+                | ExecuteMsg::ClaimDistributions {}
+                | ExecuteMsg::OpenRedemptionWindow { .. }
+                | ExecuteMsg::Redeem { .. }
+                | ExecuteMsg::ProcessRedemptionQueue { .. }
+                | ExecuteMsg::UpdateNav { .. }
+                | ExecuteMsg::UpdateEligibility { .. }
+                | ExecuteMsg::AddToAllowlist { .. }
+                | ExecuteMsg::RemoveFromAllowlist { .. }
+                | ExecuteMsg::TransferInvestment { .. }
+                | ExecuteMsg::ProposeGpTransfer { .. }
+                | ExecuteMsg::AcceptGpTransfer {}
+                | ExecuteMsg::AddOperator { .. }
+                | ExecuteMsg::RemoveOperator { .. }
+                | ExecuteMsg::Pause {}
+                | ExecuteMsg::Unpause {}
+                | ExecuteMsg::ContributeLoanPool { .. }
+                | ExecuteMsg::WithdrawLoanPool { .. }
+                | ExecuteMsg::WhiteListLoanPoolContributors { .. }
+                | ExecuteMsg::RemoveWhiteListLoanPoolContributors { .. }
+        ),
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{
+        testing::{message_info, mock_env},
+        Addr, Decimal, Uint128, Uint64,
+    };
     use provwasm_mocks::mock_provenance_dependencies;
 
-    use crate::util::{self, testing::test_security_commitments};
+    use crate::{
+        contract::execute,
+        core::{error::ContractError, msg::ExecuteMsg, security::MinimumRaise},
+        storage::{commits, state},
+        util::{
+            self, provenance_utilities::transfer_marker_coins, testing::test_security_commitments,
+        },
+    };
 
     #[test]
     fn test_propose_commitment_is_routed() {
@@ -80,6 +239,16 @@ mod tests {
         util::testing::accept_test_commitment(deps.as_mut(), mock_env(), "gp", &["lp"]).unwrap();
     }
 
+    #[test]
+    fn test_counter_propose_commitment() {
+        let mut deps = mock_provenance_dependencies();
+        util::testing::instantiate_contract(deps.as_mut()).unwrap();
+        util::testing::propose_test_commitment(deps.as_mut(), mock_env(), "lp").unwrap();
+        util::testing::counter_propose_test_commitment(deps.as_mut(), mock_env(), "gp", "lp")
+            .unwrap();
+        util::testing::accept_counter_proposal_test(deps.as_mut(), mock_env(), "lp").unwrap();
+    }
+
     #[test]
     fn test_deposit_commitment() {
         let mut deps = mock_provenance_dependencies();
@@ -155,4 +324,318 @@ mod tests {
         util::testing::propose_test_commitment(deps.as_mut(), mock_env(), "lp").unwrap();
         util::testing::cancel_test(deps.as_mut(), mock_env(), "lp", "lp").unwrap();
     }
+
+    #[test]
+    fn test_issue_capital_call() {
+        let mut deps = mock_provenance_dependencies();
+        util::testing::instantiate_contract(deps.as_mut()).unwrap();
+        util::testing::propose_test_commitment(deps.as_mut(), mock_env(), "lp").unwrap();
+        util::testing::accept_test_commitment(deps.as_mut(), mock_env(), "gp", &["lp"]).unwrap();
+        util::testing::issue_capital_call_test(
+            deps.as_mut(),
+            mock_env(),
+            "gp",
+            Decimal::percent(50),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_reject_commitment() {
+        let mut deps = mock_provenance_dependencies();
+        util::testing::instantiate_contract(deps.as_mut()).unwrap();
+        util::testing::propose_test_commitment(deps.as_mut(), mock_env(), "lp").unwrap();
+        util::testing::reject_test(deps.as_mut(), mock_env(), "gp", "lp").unwrap();
+    }
+
+    #[test]
+    fn test_claim_refund() {
+        let mut deps = mock_provenance_dependencies();
+        let mut env = mock_env();
+        util::testing::instantiate_contract(deps.as_mut()).unwrap();
+        util::testing::propose_test_commitment(deps.as_mut(), mock_env(), "lp").unwrap();
+        util::testing::accept_test_commitment(deps.as_mut(), mock_env(), "gp", &["lp"]).unwrap();
+
+        let mut contract_state = state::get(&deps.storage).unwrap();
+        contract_state.minimum_raise = Some(MinimumRaise::Offering(Uint128::new(1)));
+        contract_state.settlement_time = Some(Uint64::new(env.block.time.seconds()));
+        state::set(deps.as_mut().storage, &contract_state).unwrap();
+        env.block.time = env.block.time.plus_seconds(1);
+
+        util::testing::claim_refund_test(deps.as_mut(), env, "anyone", "lp").unwrap();
+    }
+
+    #[test]
+    fn test_reclaim_expired_deposit() {
+        let mut deps = mock_provenance_dependencies();
+        let mut env = mock_env();
+        util::testing::instantiate_contract(deps.as_mut()).unwrap();
+        util::testing::propose_test_commitment(deps.as_mut(), mock_env(), "lp").unwrap();
+        util::testing::accept_test_commitment(deps.as_mut(), mock_env(), "gp", &["lp"]).unwrap();
+
+        let mut commitment = commits::get(&deps.storage, Addr::unchecked("lp")).unwrap();
+        commitment.settlment_date = Some(Uint64::new(env.block.time.seconds()));
+        commits::set(deps.as_mut().storage, &commitment).unwrap();
+        env.block.time = env.block.time.plus_seconds(1);
+
+        util::testing::reclaim_expired_deposit_test(deps.as_mut(), env, "lp").unwrap();
+    }
+
+    #[test]
+    fn test_distribute_and_claim_distributions() {
+        let mut deps = mock_provenance_dependencies();
+        util::testing::instantiate_contract(deps.as_mut()).unwrap();
+        util::testing::propose_test_commitment(deps.as_mut(), mock_env(), "lp").unwrap();
+        util::testing::accept_test_commitment(deps.as_mut(), mock_env(), "gp", &["lp"]).unwrap();
+        util::testing::deposit_test(
+            deps.as_mut(),
+            mock_env(),
+            "lp",
+            &test_security_commitments(),
+        )
+        .unwrap();
+        util::testing::withdraw_test(deps.as_mut(), mock_env(), "gp", "lp").unwrap();
+        util::testing::distribute_test(deps.as_mut(), mock_env(), "gp", Uint128::new(100)).unwrap();
+        util::testing::claim_distributions_test(deps.as_mut(), mock_env(), "lp").unwrap();
+    }
+
+    #[test]
+    fn test_distribute_waterfall() {
+        let mut deps = mock_provenance_dependencies();
+        util::testing::instantiate_contract(deps.as_mut()).unwrap();
+        let info = message_info(&Addr::unchecked("gp"), &[]);
+        let msg = ExecuteMsg::DistributeWaterfall {
+            amount: Uint128::new(100),
+        };
+
+        // The test securities are not tranches, so there is nobody to distribute to
+        let error = execute(deps.as_mut(), mock_env(), info, msg).unwrap_err();
+        assert_eq!(
+            ContractError::NoDistributionHolders {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_redeem_and_process_redemption_queue() {
+        let mut deps = mock_provenance_dependencies();
+        util::testing::instantiate_contract(deps.as_mut()).unwrap();
+        util::testing::mock_investment_markers(&mut deps);
+        util::testing::propose_test_commitment(deps.as_mut(), mock_env(), "lp").unwrap();
+        util::testing::accept_test_commitment(deps.as_mut(), mock_env(), "gp", &["lp"]).unwrap();
+        util::testing::deposit_test(
+            deps.as_mut(),
+            mock_env(),
+            "lp",
+            &test_security_commitments(),
+        )
+        .unwrap();
+        util::testing::withdraw_test(deps.as_mut(), mock_env(), "gp", "lp").unwrap();
+        util::testing::open_redemption_window_test(
+            deps.as_mut(),
+            mock_env(),
+            "gp",
+            Decimal::percent(10),
+        )
+        .unwrap();
+        util::testing::redeem_test(deps.as_mut(), mock_env(), "lp", Uint128::new(15)).unwrap();
+        util::testing::process_redemption_queue_test(deps.as_mut(), mock_env(), "gp").unwrap();
+    }
+
+    #[test]
+    fn test_update_nav() {
+        let mut deps = mock_provenance_dependencies();
+        util::testing::instantiate_contract(deps.as_mut()).unwrap();
+        util::testing::update_nav_test(deps.as_mut(), mock_env(), "gp", Uint128::new(110)).unwrap();
+        util::testing::update_eligibility_test(
+            deps.as_mut(),
+            mock_env(),
+            "gp",
+            &["accredited.investor.pb"],
+        )
+        .unwrap();
+        util::testing::add_to_allowlist_test(deps.as_mut(), mock_env(), "gp", "buyer").unwrap();
+        util::testing::remove_from_allowlist_test(deps.as_mut(), mock_env(), "gp", "buyer")
+            .unwrap();
+    }
+
+    #[test]
+    fn test_add_resize_and_close_security() {
+        let mut deps = mock_provenance_dependencies();
+        util::testing::instantiate_contract(deps.as_mut()).unwrap();
+        util::testing::add_security_test(deps.as_mut(), mock_env(), "gp").unwrap();
+        util::testing::resize_security_test(deps.as_mut(), mock_env(), "gp", Uint128::new(2000))
+            .unwrap();
+        util::testing::close_security_test(deps.as_mut(), mock_env(), "gp").unwrap();
+
+        let error =
+            util::testing::propose_test_commitment(deps.as_mut(), mock_env(), "lp").unwrap_err();
+        assert_eq!(
+            ContractError::SecurityClosed {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_offering_status_is_enforced() {
+        let mut deps = mock_provenance_dependencies();
+        util::testing::instantiate_contract(deps.as_mut()).unwrap();
+        util::testing::propose_test_commitment(deps.as_mut(), mock_env(), "lp").unwrap();
+        util::testing::propose_test_commitment(deps.as_mut(), mock_env(), "lp3").unwrap();
+        util::testing::counter_propose_test_commitment(deps.as_mut(), mock_env(), "gp", "lp3")
+            .unwrap();
+        util::testing::close_offering_test(deps.as_mut(), mock_env(), "gp").unwrap();
+
+        let error =
+            util::testing::propose_test_commitment(deps.as_mut(), mock_env(), "lp2").unwrap_err();
+        assert_eq!(
+            ContractError::InvalidOfferingStatus {}.to_string(),
+            error.to_string()
+        );
+        let error =
+            util::testing::counter_propose_test_commitment(deps.as_mut(), mock_env(), "gp", "lp")
+                .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidOfferingStatus {}.to_string(),
+            error.to_string()
+        );
+        util::testing::accept_counter_proposal_test(deps.as_mut(), mock_env(), "lp3").unwrap();
+        util::testing::accept_test_commitment(deps.as_mut(), mock_env(), "gp", &["lp"]).unwrap();
+        util::testing::finalize_offering_test(deps.as_mut(), mock_env(), "gp").unwrap();
+
+        let error = util::testing::deposit_test(
+            deps.as_mut(),
+            mock_env(),
+            "lp",
+            &test_security_commitments(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidOfferingStatus {}.to_string(),
+            error.to_string()
+        );
+        util::testing::update_nav_test(deps.as_mut(), mock_env(), "gp", Uint128::new(110)).unwrap();
+    }
+
+    #[test]
+    fn test_eligibility_is_routed() {
+        let mut deps = mock_provenance_dependencies();
+        util::testing::instantiate_contract(deps.as_mut()).unwrap();
+        util::testing::update_eligibility_test(
+            deps.as_mut(),
+            mock_env(),
+            "gp",
+            &["accredited.investor.pb"],
+        )
+        .unwrap();
+        util::testing::mock_lp_attributes(&mut deps, &[]);
+
+        let error =
+            util::testing::propose_test_commitment(deps.as_mut(), mock_env(), "lp").unwrap_err();
+        assert_eq!(
+            ContractError::IneligibleLp {
+                security: "Security1".to_string()
+            }
+            .to_string(),
+            error.to_string()
+        );
+
+        util::testing::add_to_allowlist_test(deps.as_mut(), mock_env(), "gp", "lp").unwrap();
+        util::testing::propose_test_commitment(deps.as_mut(), mock_env(), "lp").unwrap();
+        util::testing::remove_from_allowlist_test(deps.as_mut(), mock_env(), "gp", "lp").unwrap();
+    }
+
+    #[test]
+    fn test_transfer_investment_is_routed() {
+        let mut deps = mock_provenance_dependencies();
+        util::testing::instantiate_contract(deps.as_mut()).unwrap();
+        util::testing::create_testing_commitments(&mut deps);
+        util::testing::close_offering_test(deps.as_mut(), mock_env(), "gp").unwrap();
+        util::testing::finalize_offering_test(deps.as_mut(), mock_env(), "gp").unwrap();
+        util::testing::transfer_investment_test(
+            deps.as_mut(),
+            mock_env(),
+            "lp7",
+            "buyer",
+            Uint128::new(10),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_roles_are_routed() {
+        let mut deps = mock_provenance_dependencies();
+        let env = mock_env();
+        util::testing::instantiate_contract(deps.as_mut()).unwrap();
+        util::testing::add_operator_test(deps.as_mut(), env.clone(), "gp", "operator").unwrap();
+        util::testing::propose_test_commitment(deps.as_mut(), env.clone(), "lp").unwrap();
+        util::testing::accept_test_commitment(deps.as_mut(), env.clone(), "operator", &["lp"])
+            .unwrap();
+        util::testing::deposit_test(
+            deps.as_mut(),
+            env.clone(),
+            "lp",
+            &test_security_commitments(),
+        )
+        .unwrap();
+
+        // Capital withdrawn by an operator is still released to the GP
+        let res =
+            util::testing::withdraw_test(deps.as_mut(), env.clone(), "operator", "lp").unwrap();
+        assert_eq!(
+            transfer_marker_coins(
+                20000,
+                "denom",
+                Addr::unchecked("gp"),
+                env.contract.address.clone(),
+                env.contract.address.clone()
+            )
+            .unwrap(),
+            res.messages.last().unwrap().msg
+        );
+
+        let error =
+            util::testing::update_settlement_time_test(deps.as_mut(), env.clone(), "operator")
+                .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {}.to_string(),
+            error.to_string()
+        );
+        util::testing::remove_operator_test(deps.as_mut(), env.clone(), "gp", "operator").unwrap();
+
+        util::testing::propose_gp_transfer_test(deps.as_mut(), env.clone(), "gp", "gp2").unwrap();
+        util::testing::accept_gp_transfer_test(deps.as_mut(), env.clone(), "gp2").unwrap();
+        let error = util::testing::add_operator_test(deps.as_mut(), env.clone(), "gp", "operator")
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {}.to_string(),
+            error.to_string()
+        );
+        util::testing::add_operator_test(deps.as_mut(), env, "gp2", "operator").unwrap();
+    }
+
+    #[test]
+    fn test_pause_is_enforced() {
+        let mut deps = mock_provenance_dependencies();
+        util::testing::instantiate_contract(deps.as_mut()).unwrap();
+        util::testing::propose_test_commitment(deps.as_mut(), mock_env(), "lp").unwrap();
+        util::testing::pause_test(deps.as_mut(), mock_env(), "gp").unwrap();
+
+        let error =
+            util::testing::propose_test_commitment(deps.as_mut(), mock_env(), "lp2").unwrap_err();
+        assert_eq!(
+            ContractError::ContractPaused {}.to_string(),
+            error.to_string()
+        );
+        let error = util::testing::accept_test_commitment(deps.as_mut(), mock_env(), "gp", &["lp"])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::ContractPaused {}.to_string(),
+            error.to_string()
+        );
+        util::testing::cancel_test(deps.as_mut(), mock_env(), "lp", "lp").unwrap();
+
+        util::testing::unpause_test(deps.as_mut(), mock_env(), "gp").unwrap();
+        util::testing::propose_test_commitment(deps.as_mut(), mock_env(), "lp2").unwrap();
+    }
 }
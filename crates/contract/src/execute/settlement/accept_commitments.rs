@@ -1,18 +1,25 @@
-use cosmwasm_std::{Addr, Env, Event, Response, Storage};
+use cosmwasm_std::{Addr, Env, Response, Storage};
 
 use crate::{
     core::{
         aliases::{ProvDepsMut, ProvTxResponse},
         error::ContractError,
+        event::{ContractEvent, EventType},
         security::{AcceptedCommitment, SecurityCommitment},
     },
     storage::{
+        commitment_history,
         commits::{self},
+        counter_proposals,
         paid_in_capital::{self},
         remaining_securities,
         state::{self},
     },
-    util::settlement::timestamp_is_expired,
+    util::{
+        auth::{authorize, Role},
+        fees,
+        settlement::{check_allocation_limits, timestamp_is_expired},
+    },
 };
 
 use super::commitment::{Commitment, CommitmentState};
@@ -23,10 +30,7 @@ pub fn handle(
     sender: Addr,
     commitments: Vec<AcceptedCommitment>,
 ) -> ProvTxResponse {
-    let state = state::get(deps.storage)?;
-    if sender != state.gp {
-        return Err(crate::core::error::ContractError::Unauthorized {});
-    }
+    let state = authorize(deps.storage, &sender, Role::Operator)?;
 
     if timestamp_is_expired(deps.storage, &env.block.time)? {
         return Err(crate::core::error::ContractError::SettlmentExpired {});
@@ -34,10 +38,24 @@ pub fn handle(
 
     let mut response = Response::new()
         .add_attribute("action", "accept_commitments")
-        .add_attribute("gp", state.gp);
+        .add_attribute("gp", state.gp.clone());
     for commitment in commitments {
         accept_commitment(deps.storage, commitment.clone())?;
-        response = response.add_event(Event::new("accepted").add_attribute("lp", commitment.lp));
+        commitment_history::record(
+            deps.storage,
+            &env,
+            &commitment.lp,
+            &sender,
+            "accepted",
+            &commitment.securities,
+        )?;
+        fees::accrue_management_fee(deps.storage, commitment.lp.clone(), env.block.time)?;
+        response = response.add_event(
+            ContractEvent::new(EventType::Accepted, &state.gp)
+                .lp(&commitment.lp)
+                .securities(&commitment.securities)
+                .state(&CommitmentState::ACCEPTED),
+        );
     }
 
     Ok(response)
@@ -47,17 +65,33 @@ fn accept_commitment(
     storage: &mut dyn Storage,
     accepted_commitment: AcceptedCommitment,
 ) -> Result<(), ContractError> {
-    let mut commitment = commits::get(storage, accepted_commitment.lp)?;
+    let commitment = commits::get(storage, accepted_commitment.lp)?;
 
     if commitment.state != CommitmentState::PENDING {
         return Err(ContractError::InvalidCommitmentState {});
     }
 
-    // Do the check here to verify the securities are same
+    // The GP can accept less than was proposed, but never more
     if !securities_match(&commitment.commitments, &accepted_commitment.securities) {
         return Err(ContractError::AcceptedAndProposalMismatch {});
     }
 
+    accept(storage, commitment, accepted_commitment.securities)
+}
+
+// Moves the commitment into the ACCEPTED state for the accepted amounts of each security
+pub fn accept(
+    storage: &mut dyn Storage,
+    mut commitment: Commitment,
+    accepted_securities: Vec<SecurityCommitment>,
+) -> Result<(), ContractError> {
+    check_allocation_limits(
+        storage,
+        &state::get(storage)?.maximum_lp_commitment,
+        &accepted_securities,
+    )?;
+    commitment.commitments = accepted_securities;
+
     // Remove from remaining
     for security_commitment in &commitment.commitments {
         if !remaining_securities::subtract(
@@ -71,6 +105,7 @@ fn accept_commitment(
         }
     }
 
+    counter_proposals::remove(storage, commitment.lp.clone());
     commitment.state = CommitmentState::ACCEPTED;
     commitment.settlment_date = state::get_settlement_time(storage)?;
     commits::set(storage, &commitment)?;
@@ -85,9 +120,11 @@ fn securities_match(expected: &[SecurityCommitment], actual: &[SecurityCommitmen
     }
 
     expected.iter().all(|security| {
-        actual
-            .iter()
-            .any(|other| security.name == other.name && security.amount == other.amount)
+        actual.iter().any(|other| {
+            security.name == other.name
+                && !other.amount.is_zero()
+                && other.amount <= security.amount
+        })
     })
 }
 
@@ -101,7 +138,7 @@ fn track_paid_capital(
 
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::{testing::mock_env, Addr, Attribute, Uint128};
+    use cosmwasm_std::{testing::mock_env, Addr, Attribute, Decimal, Uint128};
     use provwasm_mocks::mock_provenance_dependencies;
 
     use crate::{
@@ -112,13 +149,14 @@ mod tests {
         },
         storage::{
             commits::{self},
+            counter_proposals,
             paid_in_capital::{self},
-            remaining_securities,
+            remaining_securities, securities,
             state::{self, State},
         },
         util::testing::{
-            create_test_state, test_create_accepted_commitments, test_security_commitments,
-            SettlementTester,
+            create_test_state, instantiate_contract, propose_test_commitment,
+            test_create_accepted_commitments, test_security_commitments, SettlementTester,
         },
     };
 
@@ -148,6 +186,22 @@ mod tests {
         assert_eq!(false, securities_match(&securities1, &securities2));
     }
 
+    #[test]
+    fn test_securities_match_returns_false_on_larger_amount() {
+        let securities1 = test_security_commitments();
+        let mut securities2 = test_security_commitments();
+        securities2[0].amount += Uint128::one();
+        assert_eq!(false, securities_match(&securities1, &securities2));
+    }
+
+    #[test]
+    fn test_securities_match_returns_true_on_smaller_amount() {
+        let securities1 = test_security_commitments();
+        let mut securities2 = test_security_commitments();
+        securities2[0].amount -= Uint128::one();
+        assert_eq!(true, securities_match(&securities1, &securities2));
+    }
+
     #[test]
     fn test_securities_match_returns_true_on_success() {
         let securities1 = test_security_commitments();
@@ -155,6 +209,38 @@ mod tests {
         assert_eq!(true, securities_match(&securities1, &securities2));
     }
 
+    #[test]
+    fn test_accepted_commit_must_be_within_allocation_limits() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        propose_test_commitment(deps.as_mut(), mock_env(), "lp").unwrap();
+
+        // The GP lowered the limit after the lp proposed
+        let mut security = securities::get(&deps.storage, "Security1".to_string()).unwrap();
+        security.maximum_percentage = Some(Decimal::percent(5));
+        securities::set(deps.as_mut().storage, &security).unwrap();
+
+        let error = accept_commitment(
+            deps.as_mut().storage,
+            test_create_accepted_commitments(&["lp"])[0].clone(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::AllocationLimitExceeded {
+                security: "Security1".to_string(),
+                limit: "maximum percentage".to_string()
+            }
+            .to_string(),
+            error.to_string()
+        );
+        assert_eq!(
+            CommitmentState::PENDING,
+            commits::get(&deps.storage, Addr::unchecked("lp"))
+                .unwrap()
+                .state
+        );
+    }
+
     #[test]
     fn test_accepted_commit_must_match_securities() {
         let lp = Addr::unchecked("address");
@@ -217,6 +303,8 @@ mod tests {
         let mut deps = mock_provenance_dependencies();
         let mut settlement_tester = SettlementTester::new();
         settlement_tester.create_security_commitments(1);
+        settlement_tester.setup_test_state(deps.as_mut().storage);
+        settlement_tester.setup_test_securities(deps.as_mut().storage);
         let commitment =
             Commitment::new(lp.clone(), settlement_tester.security_commitments.clone());
         commits::set(deps.as_mut().storage, &commitment).unwrap();
@@ -260,6 +348,7 @@ mod tests {
         let mut deps = mock_provenance_dependencies();
         let mut settlement_tester = SettlementTester::new();
         settlement_tester.create_security_commitments(1);
+        settlement_tester.setup_test_securities(deps.as_mut().storage);
         let commitment =
             Commitment::new(lp.clone(), settlement_tester.security_commitments.clone());
         create_test_state(&mut deps, &mock_env(), true);
@@ -297,6 +386,7 @@ mod tests {
         let mut settlement_tester = SettlementTester::new();
         create_test_state(&mut deps, &mock_env(), false);
         settlement_tester.create_security_commitments(1);
+        settlement_tester.setup_test_securities(deps.as_mut().storage);
         let commitment =
             Commitment::new(lp.clone(), settlement_tester.security_commitments.clone());
         commits::set(deps.as_mut().storage, &commitment).unwrap();
@@ -324,6 +414,55 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_accept_commit_succeeds_with_partial_amount() {
+        let lp = Addr::unchecked("address");
+        let mut deps = mock_provenance_dependencies();
+        let mut settlement_tester = SettlementTester::new();
+        create_test_state(&mut deps, &mock_env(), false);
+        settlement_tester.create_security_commitments(1);
+        settlement_tester.setup_test_securities(deps.as_mut().storage);
+        let commitment =
+            Commitment::new(lp.clone(), settlement_tester.security_commitments.clone());
+        commits::set(deps.as_mut().storage, &commitment).unwrap();
+        remaining_securities::set(
+            deps.as_mut().storage,
+            settlement_tester.security_commitments[0].name.clone(),
+            100,
+        )
+        .unwrap();
+        counter_proposals::set(
+            deps.as_mut().storage,
+            lp.clone(),
+            &settlement_tester.security_commitments,
+        )
+        .unwrap();
+
+        let mut accepted_commitment = AcceptedCommitment {
+            lp: lp.clone(),
+            securities: settlement_tester.security_commitments.clone(),
+        };
+        accepted_commitment.securities[0].amount = Uint128::new(4);
+        accept_commitment(deps.as_mut().storage, accepted_commitment.clone()).unwrap();
+
+        let added_commitment = commits::get(&deps.storage, lp.clone()).unwrap();
+        assert_eq!(CommitmentState::ACCEPTED, added_commitment.state);
+        assert_eq!(accepted_commitment.securities, added_commitment.commitments);
+        assert_eq!(
+            96,
+            remaining_securities::get(
+                &deps.storage,
+                settlement_tester.security_commitments[0].name.clone()
+            )
+            .unwrap()
+        );
+        assert_eq!(None, counter_proposals::get(&deps.storage, lp.clone()));
+
+        let paid_capital = paid_in_capital::get(&deps.storage, lp);
+        assert_eq!(1, paid_capital.len());
+        assert_eq!(Uint128::zero(), paid_capital[0].amount);
+    }
+
     #[test]
     fn test_handle_succeeds_with_multiple_commits() {
         let gp = Addr::unchecked("gp");
@@ -335,6 +474,7 @@ mod tests {
         settlement_tester.setup_test_state(deps.as_mut().storage);
 
         settlement_tester.create_security_commitments(2);
+        settlement_tester.setup_test_securities(deps.as_mut().storage);
 
         // Add these to the supported types
         let commitment1 = Commitment::new(
@@ -379,10 +519,18 @@ mod tests {
         );
         assert_eq!(Attribute::new("gp", gp), res.attributes[1]);
         assert_eq!(res.events.len(), 2);
-        assert_eq!(res.events[0].attributes.len(), 1);
-        assert_eq!(Attribute::new("lp", "lp1"), res.events[0].attributes[0]);
-        assert_eq!(res.events[1].attributes.len(), 1);
-        assert_eq!(Attribute::new("lp", "lp2"), res.events[1].attributes[0]);
+        assert_eq!(res.events[0].attributes.len(), 4);
+        assert_eq!(Attribute::new("lp", "lp1"), res.events[0].attributes[1]);
+        assert_eq!(
+            Attribute::new("state", "accepted"),
+            res.events[0].attributes[3]
+        );
+        assert_eq!(res.events[1].attributes.len(), 4);
+        assert_eq!(Attribute::new("lp", "lp2"), res.events[1].attributes[1]);
+        assert_eq!(
+            Attribute::new("state", "accepted"),
+            res.events[1].attributes[3]
+        );
     }
 
     #[test]
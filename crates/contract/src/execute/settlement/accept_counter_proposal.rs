@@ -0,0 +1,150 @@
+use cosmwasm_std::{Addr, Env, Response};
+
+use crate::{
+    core::{
+        aliases::{ProvDepsMut, ProvTxResponse},
+        error::ContractError,
+        event::{ContractEvent, EventType},
+    },
+    storage::{
+        commitment_history,
+        commits::{self},
+        counter_proposals,
+        state::{self},
+    },
+    util::{fees, settlement::timestamp_is_expired},
+};
+
+use super::{accept_commitments, commitment::CommitmentState};
+
+pub fn handle(deps: ProvDepsMut, env: Env, sender: Addr) -> ProvTxResponse {
+    if timestamp_is_expired(deps.storage, &env.block.time)? {
+        return Err(ContractError::SettlmentExpired {});
+    }
+
+    let commitment = commits::get(deps.storage, sender.clone())?;
+    if commitment.state != CommitmentState::PENDING {
+        return Err(ContractError::InvalidCommitmentState {});
+    }
+
+    let securities = counter_proposals::get(deps.storage, sender.clone())
+        .ok_or(ContractError::MissingCounterProposal {})?;
+    accept_commitments::accept(deps.storage, commitment, securities.clone())?;
+    commitment_history::record(
+        deps.storage,
+        &env,
+        &sender,
+        &sender,
+        "accepted",
+        &securities,
+    )?;
+    fees::accrue_management_fee(deps.storage, sender.clone(), env.block.time)?;
+
+    Ok(Response::new()
+        .add_event(
+            ContractEvent::new(EventType::Accepted, &state::get(deps.storage)?.gp)
+                .lp(&sender)
+                .securities(&securities)
+                .state(&CommitmentState::ACCEPTED),
+        )
+        .add_attribute("action", "accept_counter_proposal")
+        .add_attribute("lp", sender))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{testing::mock_env, Addr, Attribute, Event, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        core::error::ContractError,
+        execute::settlement::commitment::CommitmentState,
+        storage::{commits, counter_proposals, paid_in_capital, remaining_securities},
+        util::testing::{
+            create_test_state, create_testing_commitments, instantiate_contract,
+            test_security_commitments,
+        },
+    };
+
+    use super::handle;
+
+    #[test]
+    fn test_handle_fails_when_settlement_expired() {
+        let mut deps = mock_provenance_dependencies();
+        let mut env = mock_env();
+        create_test_state(&mut deps, &env, true);
+        env.block.time = env.block.time.plus_seconds(86401);
+
+        let error = handle(deps.as_mut(), env, Addr::unchecked("lp1")).unwrap_err();
+        assert_eq!(
+            ContractError::SettlmentExpired {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_fails_when_not_pending() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+
+        let error = handle(deps.as_mut(), mock_env(), Addr::unchecked("lp1")).unwrap_err();
+        assert_eq!(
+            ContractError::InvalidCommitmentState {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_fails_without_counter_proposal() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+
+        let error = handle(deps.as_mut(), mock_env(), Addr::unchecked("lp4")).unwrap_err();
+        assert_eq!(
+            ContractError::MissingCounterProposal {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_succeeds() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        let lp = Addr::unchecked("lp4");
+        let mut securities = test_security_commitments();
+        securities[0].amount = Uint128::new(50);
+        counter_proposals::set(deps.as_mut().storage, lp.clone(), &securities).unwrap();
+        let remaining =
+            remaining_securities::get(&deps.storage, securities[0].name.clone()).unwrap();
+
+        let res = handle(deps.as_mut(), mock_env(), lp.clone()).unwrap();
+        assert_eq!(
+            vec![
+                Attribute::new("action", "accept_counter_proposal"),
+                Attribute::new("lp", lp.clone())
+            ],
+            res.attributes
+        );
+        assert_eq!(
+            vec![Event::new("accepted")
+                .add_attribute("gp", "gp")
+                .add_attribute("lp", lp.clone())
+                .add_attribute("securities", "Security1:50,Security2:100")
+                .add_attribute("state", "accepted")],
+            res.events
+        );
+
+        let commitment = commits::get(&deps.storage, lp.clone()).unwrap();
+        assert_eq!(CommitmentState::ACCEPTED, commitment.state);
+        assert_eq!(securities, commitment.commitments);
+        assert_eq!(
+            remaining - 50,
+            remaining_securities::get(&deps.storage, securities[0].name.clone()).unwrap()
+        );
+        assert_eq!(None, counter_proposals::get(&deps.storage, lp.clone()));
+        assert_eq!(2, paid_in_capital::get(&deps.storage, lp).len());
+    }
+}
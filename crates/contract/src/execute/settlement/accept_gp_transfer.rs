@@ -0,0 +1,103 @@
+use cosmwasm_std::{Addr, Env, Response};
+
+use crate::{
+    core::{
+        aliases::{ProvDepsMut, ProvTxResponse},
+        error::ContractError,
+        event::{ContractEvent, EventType},
+    },
+    storage::{roles, state},
+};
+
+// Completes a GP transfer. Only the proposed account can accept, and it stops being an operator
+// since the GP already holds every role.
+pub fn handle(deps: ProvDepsMut, _env: Env, sender: Addr) -> ProvTxResponse {
+    if roles::get_pending_gp(deps.storage) != Some(sender.clone()) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut state = state::get(deps.storage)?;
+    let previous_gp = state.gp;
+    state.gp = sender.clone();
+    state::set(deps.storage, &state)?;
+    roles::remove_pending_gp(deps.storage);
+    roles::remove_operator(deps.storage, sender.clone());
+
+    Ok(Response::new()
+        .add_event(
+            ContractEvent::new(EventType::GpTransferred, &sender)
+                .attribute("previous_gp", previous_gp),
+        )
+        .add_attribute("action", "accept_gp_transfer")
+        .add_attribute("gp", sender))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{testing::mock_env, Addr, Attribute, Event};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        core::error::ContractError,
+        storage::{roles, state},
+        util::testing::{add_operator_test, instantiate_contract, propose_gp_transfer_test},
+    };
+
+    use super::handle;
+
+    #[test]
+    fn test_handle_fails_without_proposal() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+
+        let error = handle(deps.as_mut(), mock_env(), Addr::unchecked("gp2")).unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_must_be_pending_gp() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        propose_gp_transfer_test(deps.as_mut(), mock_env(), "gp", "gp2").unwrap();
+
+        for sender in ["gp", "gp3"] {
+            let error = handle(deps.as_mut(), mock_env(), Addr::unchecked(sender)).unwrap_err();
+            assert_eq!(
+                ContractError::Unauthorized {}.to_string(),
+                error.to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn test_handle_transfers_gp() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        add_operator_test(deps.as_mut(), mock_env(), "gp", "gp2").unwrap();
+        propose_gp_transfer_test(deps.as_mut(), mock_env(), "gp", "gp2").unwrap();
+
+        let res = handle(deps.as_mut(), mock_env(), Addr::unchecked("gp2")).unwrap();
+        assert_eq!(
+            vec![
+                Attribute::new("action", "accept_gp_transfer"),
+                Attribute::new("gp", "gp2")
+            ],
+            res.attributes
+        );
+        assert_eq!(
+            vec![Event::new("gp_transferred")
+                .add_attribute("gp", "gp2")
+                .add_attribute("previous_gp", "gp")],
+            res.events
+        );
+        assert_eq!(
+            Addr::unchecked("gp2"),
+            state::get(&deps.storage).unwrap().gp
+        );
+        assert_eq!(None, roles::get_pending_gp(&deps.storage));
+        assert!(!roles::is_operator(&deps.storage, Addr::unchecked("gp2")));
+    }
+}
@@ -1,6 +1,6 @@
 use cosmwasm_std::OverflowOperation::Add;
 use cosmwasm_std::{
-    to_json_binary, Addr, CosmosMsg, DepsMut, Env, Event, MessageInfo, Response, Uint128,
+    to_json_binary, Addr, Coin, CosmosMsg, DepsMut, Env, MessageInfo, Response, Uint128,
 };
 use provwasm_std::types::provenance::marker::v1::Access::{Admin, Withdraw};
 use provwasm_std::types::provenance::marker::v1::{AccessGrant, MarkerAccount, MarkerQuerier};
@@ -11,10 +11,12 @@ use crate::core::collateral::{LoanPoolAdditionData, LoanPoolMarkerCollateral, Lo
 use crate::core::{
     aliases::{ProvDepsMut, ProvTxResponse},
     error::ContractError,
+    event::{ContractEvent, EventType},
     security::ContributeLoanPools,
 };
 use crate::execute::settlement::marker_loan_pool_validation::validate_marker_for_loan_pool_add_remove;
 use crate::storage::loan_pool_collateral::set;
+use crate::storage::state;
 use crate::storage::whitelist_contributors_store::get_whitelist_contributors;
 use crate::util::provenance_utilities::{
     get_marker, get_marker_address, get_single_marker_coin_holding, query_total_supply,
@@ -61,6 +63,8 @@ pub fn handle(
         return Err(ContractError::NotInWhitelist {});
     }
 
+    let gp = state::get(deps.storage)?.gp;
+
     // create empty response object
     let mut response = Response::new();
 
@@ -73,18 +77,21 @@ pub fn handle(
         } = create_marker_pool_collateral(&deps, &info, &env, pool.clone())?;
         //insert the collateral
         set(deps.storage, &collateral)?;
-        collaterals.push(collateral);
         // Add messages and event in a chained manner
         response = response.add_messages(messages).add_event(
-            Event::new("loan_pool_added").add_attribute("marker_address", pool.to_string()),
+            ContractEvent::new(EventType::LoanPoolAdded, &gp)
+                .amount(Coin::new(collateral.share_count, &collateral.marker_denom))
+                .attribute("marker_address", collateral.marker_address.clone())
+                .attribute("contributor", info.sender.clone()),
         );
+        collaterals.push(collateral);
     }
 
     // Add added_by attribute only if loan_pool_added event is added
     if response
         .events
         .iter()
-        .any(|event| event.ty == "loan_pool_added")
+        .any(|event| event.ty == EventType::LoanPoolAdded.as_str())
     {
         response = response.add_attribute("loan_pool_added_by", info.sender);
         response = response.add_attribute("action", "loan_pool_added");
@@ -389,6 +396,24 @@ mod tests {
                 let mut found_event = false;
 
                 assert_eq!(response.events.len(), 1);
+                assert_eq!(
+                    Event::new("loan_pool_added")
+                        .add_attribute("gp", "gp")
+                        .add_attribute(
+                            "amount",
+                            format!(
+                                "{}{}",
+                                expected_collaterals[0].share_count,
+                                expected_collaterals[0].marker_denom
+                            )
+                        )
+                        .add_attribute(
+                            "marker_address",
+                            expected_collaterals[0].marker_address.clone()
+                        )
+                        .add_attribute("contributor", "contributor"),
+                    response.events[0]
+                );
                 assert_eq!(response.attributes.len(), 2);
                 for event in response.events.iter() {
                     if event.ty == "loan_pool_added" {
@@ -0,0 +1,105 @@
+use cosmwasm_std::{Addr, Env, Response};
+
+use crate::{
+    core::{
+        aliases::{ProvDepsMut, ProvTxResponse},
+        error::ContractError,
+        event::{ContractEvent, EventType},
+    },
+    storage::roles,
+    util::auth::{authorize, Role},
+};
+
+// Lets the operator accept commitments and withdraw capital on behalf of the GP
+pub fn handle(deps: ProvDepsMut, _env: Env, sender: Addr, operator: Addr) -> ProvTxResponse {
+    let state = authorize(deps.storage, &sender, Role::Gp)?;
+
+    if operator == state.gp {
+        return Err(ContractError::InvalidOperator {});
+    }
+    roles::add_operator(deps.storage, operator.clone())?;
+
+    Ok(Response::new()
+        .add_event(
+            ContractEvent::new(EventType::OperatorAdded, &sender).attribute("operator", operator),
+        )
+        .add_attribute("action", "add_operator")
+        .add_attribute("gp", sender))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{testing::mock_env, Addr, Attribute, Event};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{core::error::ContractError, storage::roles, util::testing::instantiate_contract};
+
+    use super::handle;
+
+    #[test]
+    fn test_handle_must_be_gp() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+
+        let error = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("lp1"),
+            Addr::unchecked("lp1"),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_fails_for_gp() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+
+        let error = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            Addr::unchecked("gp"),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidOperator {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_adds_operator() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+
+        let res = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            Addr::unchecked("operator"),
+        )
+        .unwrap();
+        assert_eq!(
+            vec![
+                Attribute::new("action", "add_operator"),
+                Attribute::new("gp", "gp")
+            ],
+            res.attributes
+        );
+        assert_eq!(
+            vec![Event::new("operator_added")
+                .add_attribute("gp", "gp")
+                .add_attribute("operator", "operator")],
+            res.events
+        );
+        assert!(roles::is_operator(
+            &deps.storage,
+            Addr::unchecked("operator")
+        ));
+    }
+}
@@ -0,0 +1,254 @@
+use std::mem::discriminant;
+
+use cosmwasm_std::{Addr, Env, Response, Storage, Uint128};
+
+use crate::{
+    core::{
+        aliases::{ProvDepsMut, ProvTxResponse},
+        error::ContractError,
+        event::{ContractEvent, EventType},
+        security::{Security, SecurityCommitment, SecurityType},
+    },
+    instantiate::handler::new_active_marker,
+    storage::{
+        remaining_securities,
+        securities::{self},
+    },
+    util::{
+        auth::{authorize, Role},
+        to,
+    },
+};
+
+pub fn handle(deps: ProvDepsMut, env: Env, sender: Addr, security: Security) -> ProvTxResponse {
+    let state = authorize(deps.storage, &sender, Role::Gp)?;
+
+    if security.price_per_unit.denom != state.capital_denom {
+        return Err(ContractError::InvalidSecurityPriceDenom {});
+    }
+
+    if !fits_offering(deps.storage, &security)? {
+        return Err(ContractError::InvalidSecurityList {});
+    }
+
+    let investment_name = to::security_to_investment_name(&security.name, &env.contract.address);
+    let messages = new_active_marker(
+        env.contract.address.clone(),
+        &investment_name,
+        Uint128::zero(),
+    )?;
+    securities::set(deps.storage, &security)?;
+    remaining_securities::set(deps.storage, security.name.clone(), security.amount.u128())?;
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_event(
+            ContractEvent::new(EventType::SecurityAdded, &sender).securities(&[
+                SecurityCommitment {
+                    name: security.name,
+                    amount: security.amount,
+                },
+            ]),
+        )
+        .add_attribute("action", "add_security")
+        .add_attribute("gp", sender))
+}
+
+// A new security needs a unique name and the same type as the rest of the offering,
+// and a new tranche can't share a seniority with an existing tranche
+fn fits_offering(storage: &dyn Storage, security: &Security) -> Result<bool, ContractError> {
+    for name in securities::get_security_types(storage) {
+        let existing = securities::get(storage, name)?;
+        if existing.name == security.name
+            || discriminant(&existing.security_type) != discriminant(&security.security_type)
+        {
+            return Ok(false);
+        }
+        if let (SecurityType::Tranche(existing), SecurityType::Tranche(new)) =
+            (&existing.security_type, &security.security_type)
+        {
+            if existing.seniority == new.seniority {
+                return Ok(false);
+            }
+        }
+    }
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{testing::mock_env, Addr, Attribute, Coin, Event, SubMsg, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        core::{
+            error::ContractError,
+            security::{FundSecurity, Security, SecurityType, TrancheSecurity},
+        },
+        instantiate::handler::new_active_marker,
+        storage::{remaining_securities, securities},
+        util::{testing::instantiate_contract, to},
+    };
+
+    use super::handle;
+
+    fn test_security(name: &str) -> Security {
+        Security {
+            name: name.to_string(),
+            amount: Uint128::new(500),
+            security_type: SecurityType::Fund(FundSecurity::default()),
+            minimum_amount: Uint128::new(10),
+            price_per_unit: Coin::new(Uint128::new(100), "denom"),
+            maximum_amount: None,
+            maximum_percentage: None,
+        }
+    }
+
+    #[test]
+    fn test_handle_must_be_gp() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+
+        let error = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("lp"),
+            test_security("Security3"),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_fails_for_wrong_denom() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        let mut security = test_security("Security3");
+        security.price_per_unit = Coin::new(Uint128::new(100), "other");
+
+        let error = handle(deps.as_mut(), mock_env(), Addr::unchecked("gp"), security).unwrap_err();
+        assert_eq!(
+            ContractError::InvalidSecurityPriceDenom {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_fails_for_existing_name() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+
+        let error = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            test_security("Security1"),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidSecurityList {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_fails_for_different_type() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        let mut security = test_security("Security3");
+        security.security_type = SecurityType::Tranche(TrancheSecurity::default());
+
+        let error = handle(deps.as_mut(), mock_env(), Addr::unchecked("gp"), security).unwrap_err();
+        assert_eq!(
+            ContractError::InvalidSecurityList {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_fails_for_duplicate_seniority() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        for name in ["Security1", "Security2"] {
+            let mut security = securities::get(&deps.storage, name.to_string()).unwrap();
+            security.security_type = SecurityType::Tranche(TrancheSecurity {
+                seniority: if name == "Security1" { 0 } else { 1 },
+                ..Default::default()
+            });
+            securities::set(deps.as_mut().storage, &security).unwrap();
+        }
+        let mut security = test_security("Security3");
+        security.security_type = SecurityType::Tranche(TrancheSecurity {
+            seniority: 1,
+            ..Default::default()
+        });
+
+        let error = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            security.clone(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidSecurityList {}.to_string(),
+            error.to_string()
+        );
+
+        security.security_type = SecurityType::Tranche(TrancheSecurity {
+            seniority: 2,
+            ..Default::default()
+        });
+        handle(deps.as_mut(), mock_env(), Addr::unchecked("gp"), security).unwrap();
+    }
+
+    #[test]
+    fn test_handle_adds_security() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        let env = mock_env();
+        let security = test_security("Security3");
+
+        let res = handle(
+            deps.as_mut(),
+            env.clone(),
+            Addr::unchecked("gp"),
+            security.clone(),
+        )
+        .unwrap();
+        let investment_name =
+            to::security_to_investment_name(&security.name, &env.contract.address);
+        let expected: Vec<SubMsg> =
+            new_active_marker(env.contract.address, &investment_name, Uint128::zero())
+                .unwrap()
+                .into_iter()
+                .map(SubMsg::new)
+                .collect();
+        assert_eq!(expected, res.messages);
+        assert_eq!(
+            vec![
+                Attribute::new("action", "add_security"),
+                Attribute::new("gp", "gp")
+            ],
+            res.attributes
+        );
+        assert_eq!(
+            vec![Event::new("security_added")
+                .add_attribute("gp", "gp")
+                .add_attribute("securities", "Security3:500")],
+            res.events
+        );
+
+        assert_eq!(
+            security,
+            securities::get(&deps.storage, "Security3".to_string()).unwrap()
+        );
+        assert_eq!(
+            500,
+            remaining_securities::get(&deps.storage, "Security3".to_string()).unwrap()
+        );
+    }
+}
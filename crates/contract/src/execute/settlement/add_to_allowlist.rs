@@ -0,0 +1,92 @@
+use cosmwasm_std::{Addr, Env, Response};
+
+use crate::{
+    core::{
+        aliases::{ProvDepsMut, ProvTxResponse},
+        event::{ContractEvent, EventType},
+    },
+    storage::eligibility::{self},
+    util::auth::{authorize, Role},
+};
+
+// Lets the lps propose commitments to any security without holding its required attributes
+pub fn handle(deps: ProvDepsMut, _env: Env, sender: Addr, lps: Vec<Addr>) -> ProvTxResponse {
+    authorize(deps.storage, &sender, Role::Gp)?;
+
+    for lp in &lps {
+        eligibility::allow(deps.storage, lp.clone())?;
+    }
+
+    let lps: Vec<String> = lps.iter().map(|lp| lp.to_string()).collect();
+    Ok(Response::new()
+        .add_event(
+            ContractEvent::new(EventType::AllowlistAdded, &sender).attribute("lps", lps.join(",")),
+        )
+        .add_attribute("action", "add_to_allowlist")
+        .add_attribute("gp", sender))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{testing::mock_env, Addr, Attribute, Event};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        core::error::ContractError, storage::eligibility, util::testing::instantiate_contract,
+    };
+
+    use super::handle;
+
+    #[test]
+    fn test_handle_must_be_gp() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+
+        let error = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("lp1"),
+            vec![Addr::unchecked("lp1")],
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_updates_allowlist() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+
+        let res = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            vec![Addr::unchecked("lp1"), Addr::unchecked("lp2")],
+        )
+        .unwrap();
+        assert_eq!(
+            vec![
+                Attribute::new("action", "add_to_allowlist"),
+                Attribute::new("gp", "gp")
+            ],
+            res.attributes
+        );
+        assert_eq!(
+            vec![Event::new("allowlist_added")
+                .add_attribute("gp", "gp")
+                .add_attribute("lps", "lp1,lp2")],
+            res.events
+        );
+        assert!(eligibility::is_allowlisted(
+            &deps.storage,
+            Addr::unchecked("lp1")
+        ));
+        assert!(eligibility::is_allowlisted(
+            &deps.storage,
+            Addr::unchecked("lp2")
+        ));
+    }
+}
@@ -1,78 +1,148 @@
-use cosmwasm_std::{Addr, BankMsg, Env, Response};
+use cosmwasm_std::{Addr, Coin, Env, Response, Storage};
 
 use crate::{
     core::{
         aliases::{ProvDepsMut, ProvMsg, ProvTxResponse},
         error::ContractError,
+        event::{ContractEvent, EventType},
+    },
+    storage::{
+        available_capital, capital_calls, commitment_history, commits, counter_proposals,
+        paid_in_capital, remaining_securities,
+        state::{self, DepositMode},
+    },
+    util::{
+        auth::{authorize, Role},
+        capital::send_capital,
+        settlement::is_expired,
     },
-    storage::{available_capital, commits, paid_in_capital, remaining_securities, state},
 };
 
 use super::commitment::CommitmentState;
 
-pub fn handle(deps: ProvDepsMut, _env: Env, sender: Addr, commitment_lp: Addr) -> ProvTxResponse {
-    let state = state::get(deps.storage)?;
+pub fn handle(deps: ProvDepsMut, env: Env, sender: Addr, commitment_lp: Addr) -> ProvTxResponse {
     let mut response = Response::default();
-    if sender != state.gp && sender != commitment_lp {
-        return Err(ContractError::Unauthorized {});
-    }
+    let state = match sender == commitment_lp {
+        true => state::get(deps.storage)?,
+        false => authorize(deps.storage, &sender, Role::Gp)?,
+    };
 
     // It cannot be in settled
-    let commit = commits::get(deps.storage, commitment_lp.clone())?;
+    let mut commit = commits::get(deps.storage, commitment_lp.clone())?;
     if commit.state == CommitmentState::SETTLED {
         return Err(ContractError::AlreadySettled {});
     }
+    if commit.state.is_terminated() {
+        return Err(ContractError::InvalidCommitmentState {});
+    }
 
-    let refund_messages = refund_lp(deps, commitment_lp.clone())?;
+    let (refund_messages, refunded) = refund_lp(
+        deps.storage,
+        &state.deposit_mode,
+        &env.contract.address,
+        commitment_lp.clone(),
+    )?;
     if !refund_messages.is_empty() {
         response = response.add_messages(refund_messages);
     }
 
+    commit.state = match is_expired(&env, &commit) {
+        true => CommitmentState::EXPIRED,
+        false => CommitmentState::CANCELED,
+    };
+    commits::set(deps.storage, &commit)?;
+    commitment_history::record(
+        deps.storage,
+        &env,
+        &commitment_lp,
+        &sender,
+        "canceled",
+        &commit.commitments,
+    )?;
+
+    let mut event = ContractEvent::new(EventType::Canceled, &state.gp)
+        .lp(&commitment_lp)
+        .securities(&commit.commitments)
+        .state(&commit.state);
+    if let Some(refunded) = refunded {
+        event = event.amount(refunded);
+    }
+
     Ok(response
+        .add_event(event)
         .add_attribute("action", "cancel_commitment")
         .add_attribute("sender", sender)
         .add_attribute("canceled_lp", commitment_lp))
 }
 
-fn refund_lp(deps: ProvDepsMut, commitment_lp: Addr) -> Result<Vec<ProvMsg>, ContractError> {
+// Returns the lp's available capital through the same path it was deposited with, and releases
+// the securities that were reserved when their commitment was accepted. The commitment itself is
+// kept, and it is up to the caller to move it into a terminated state once it has been refunded.
+// The refunded capital is returned along with the messages that send it.
+pub fn refund_lp(
+    storage: &mut dyn Storage,
+    deposit_mode: &DepositMode,
+    contract: &Addr,
+    commitment_lp: Addr,
+) -> Result<(Vec<ProvMsg>, Option<Coin>), ContractError> {
     let mut messages = vec![];
+    let mut refunded = None;
+    let commitment = commits::get(storage, commitment_lp.clone())?;
 
-    commits::remove(deps.storage, commitment_lp.clone());
-
-    let paid_in_capital = paid_in_capital::get(deps.storage, commitment_lp.clone());
-    paid_in_capital::remove(deps.storage, commitment_lp.clone());
-    if !paid_in_capital.is_empty() && available_capital::has_lp(deps.storage, commitment_lp.clone())
+    // The capital the lp paid in is no longer held by the contract, so it can't be refunded
+    if capital_calls::get(storage, commitment_lp.clone())
+        .is_some_and(|capital_call| capital_call.released)
     {
+        return Err(ContractError::CapitalAlreadyReleased {});
+    }
+
+    capital_calls::remove(storage, commitment_lp.clone());
+    counter_proposals::remove(storage, commitment_lp.clone());
+    paid_in_capital::remove(storage, commitment_lp.clone())?;
+    if available_capital::has_lp(storage, commitment_lp.clone()) {
         // This is what we end up sending back to the lp
-        let removed_capital =
-            available_capital::remove_capital(deps.storage, commitment_lp.clone())?;
+        let removed_capital = available_capital::remove_capital(storage, commitment_lp.clone())?;
 
         if !removed_capital.amount.is_zero() {
-            messages.push(ProvMsg::Bank(BankMsg::Send {
-                to_address: commitment_lp.to_string(),
-                amount: vec![removed_capital],
-            }));
+            messages.push(send_capital(
+                deposit_mode,
+                removed_capital.clone(),
+                &commitment_lp,
+                contract,
+            )?);
+            refunded = Some(removed_capital);
         }
     }
 
-    for security in paid_in_capital {
-        remaining_securities::add(deps.storage, security.name, security.amount.u128())?;
+    // A pending commitment has not reserved any securities yet
+    if commitment.state == CommitmentState::ACCEPTED {
+        for security in commitment.commitments {
+            remaining_securities::add(storage, security.name, security.amount.u128())?;
+        }
     }
 
-    Ok(messages)
+    Ok((messages, refunded))
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
         core::{aliases::ProvMsg, error::ContractError},
+        execute::settlement::commitment::{Commitment, CommitmentState},
         storage::{
             available_capital, commits, paid_in_capital, remaining_securities,
-            state::{self, State},
+            state::{self, DepositMode, State},
+        },
+        util::{
+            provenance_utilities::transfer_marker_coins,
+            testing::{
+                create_testing_commitments, instantiate_contract, test_security_commitments,
+            },
         },
-        util::testing::{create_testing_commitments, instantiate_contract},
     };
-    use cosmwasm_std::{testing::mock_env, Addr, Attribute, BankMsg, Coin, SubMsg, Uint128};
+    use cosmwasm_std::{
+        testing::mock_env, Addr, Attribute, BankMsg, Coin, Event, SubMsg, Uint128, Uint64,
+    };
     use provwasm_mocks::mock_provenance_dependencies;
 
     #[test]
@@ -124,7 +194,15 @@ mod tests {
         create_testing_commitments(&mut deps);
 
         let res = super::handle(deps.as_mut(), env, sender.clone(), commitment_lp.clone()).unwrap();
-        assert_eq!(0, res.events.len());
+        assert_eq!(
+            vec![Event::new("canceled")
+                .add_attribute("gp", "gp")
+                .add_attribute("lp", "lp2")
+                .add_attribute("securities", "Security1:100,Security2:100")
+                .add_attribute("amount", "10000denom")
+                .add_attribute("state", "canceled")],
+            res.events
+        );
         assert_eq!(
             vec![
                 Attribute::new("action", "cancel_commitment"),
@@ -134,24 +212,100 @@ mod tests {
             res.attributes
         );
         assert_eq!(
-            vec![SubMsg::new(ProvMsg::Bank(BankMsg::Send {
-                to_address: commitment_lp.to_string(),
-                amount: vec![removed_capital],
-            }))],
+            vec![SubMsg::new(
+                transfer_marker_coins(
+                    removed_capital.amount.u128(),
+                    removed_capital.denom,
+                    commitment_lp,
+                    mock_env().contract.address,
+                    mock_env().contract.address,
+                )
+                .unwrap()
+            )],
             res.messages
         );
     }
 
     #[test]
-    fn test_refund_should_ignore_invalid_lp() {
+    fn test_handle_should_mark_commitment_canceled() {
+        let commitment_lp = Addr::unchecked("lp4");
+        let mut deps = mock_provenance_dependencies();
+
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+
+        super::handle(
+            deps.as_mut(),
+            mock_env(),
+            commitment_lp.clone(),
+            commitment_lp.clone(),
+        )
+        .unwrap();
+        let commitment = commits::get(&deps.storage, commitment_lp.clone()).unwrap();
+        assert_eq!(CommitmentState::CANCELED, commitment.state);
+        assert_eq!(test_security_commitments(), commitment.commitments);
+
+        // A terminated commitment can't be canceled again
+        let error = super::handle(
+            deps.as_mut(),
+            mock_env(),
+            commitment_lp.clone(),
+            commitment_lp,
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidCommitmentState {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_should_mark_commitment_expired() {
+        let commitment_lp = Addr::unchecked("lp3");
+        let mut deps = mock_provenance_dependencies();
+
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        let mut commitment = commits::get(&deps.storage, commitment_lp.clone()).unwrap();
+        commitment.settlment_date = Some(Uint64::new(mock_env().block.time.seconds() - 1));
+        commits::set(deps.as_mut().storage, &commitment).unwrap();
+
+        super::handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            commitment_lp.clone(),
+        )
+        .unwrap();
+        let commitment = commits::get(&deps.storage, commitment_lp).unwrap();
+        assert_eq!(CommitmentState::EXPIRED, commitment.state);
+    }
+
+    #[test]
+    fn test_refund_should_ignore_lp_without_capital() {
         let commitment_lp = Addr::unchecked("lp10");
         let mut deps = mock_provenance_dependencies();
 
         instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
         create_testing_commitments(&mut deps);
+        commits::set(
+            deps.as_mut().storage,
+            &Commitment::new(commitment_lp.clone(), test_security_commitments()),
+        )
+        .unwrap();
 
-        let res = super::refund_lp(deps.as_mut(), commitment_lp.clone()).unwrap();
+        let (res, _) = super::refund_lp(
+            deps.as_mut().storage,
+            &DepositMode::Marker,
+            &mock_env().contract.address,
+            commitment_lp,
+        )
+        .unwrap();
         assert_eq!(0, res.len());
+        assert_eq!(
+            600,
+            remaining_securities::get(&deps.storage, "Security1".to_string()).unwrap()
+        );
     }
 
     #[test]
@@ -162,9 +316,16 @@ mod tests {
         instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
         create_testing_commitments(&mut deps);
 
-        let res = super::refund_lp(deps.as_mut(), commitment_lp.clone()).unwrap();
+        let (res, _) = super::refund_lp(
+            deps.as_mut().storage,
+            &DepositMode::Marker,
+            &mock_env().contract.address,
+            commitment_lp.clone(),
+        )
+        .unwrap();
         assert_eq!(0, res.len());
-        assert_eq!(false, commits::exists(deps.as_ref().storage, commitment_lp))
+        // The commitment is kept for history
+        assert_eq!(true, commits::exists(deps.as_ref().storage, commitment_lp))
     }
 
     #[test]
@@ -175,9 +336,15 @@ mod tests {
         instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
         create_testing_commitments(&mut deps);
 
-        let refund_messages = super::refund_lp(deps.as_mut(), commitment_lp.clone()).unwrap();
+        let (refund_messages, refunded) = super::refund_lp(
+            deps.as_mut().storage,
+            &DepositMode::Marker,
+            &mock_env().contract.address,
+            commitment_lp.clone(),
+        )
+        .unwrap();
         assert_eq!(
-            false,
+            true,
             commits::exists(deps.as_ref().storage, commitment_lp.clone())
         );
         assert_eq!(
@@ -189,13 +356,39 @@ mod tests {
             paid_in_capital::has_lp(deps.as_ref().storage, commitment_lp.clone())
         );
         assert_eq!(
-            650,
+            700,
             remaining_securities::get(&deps.storage, "Security1".to_string()).unwrap()
         );
         assert_eq!(
-            650,
+            700,
             remaining_securities::get(&deps.storage, "Security2".to_string()).unwrap()
         );
+        let contract = mock_env().contract.address;
+        assert_eq!(
+            vec![
+                transfer_marker_coins(10000, "denom", commitment_lp, contract.clone(), contract)
+                    .unwrap()
+            ],
+            refund_messages
+        );
+        assert_eq!(Some(Coin::new(Uint128::new(10000), "denom")), refunded);
+    }
+
+    #[test]
+    fn test_refund_should_send_attached_funds_back() {
+        let commitment_lp = Addr::unchecked("lp2");
+        let mut deps = mock_provenance_dependencies();
+
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+
+        let (refund_messages, refunded) = super::refund_lp(
+            deps.as_mut().storage,
+            &DepositMode::Funds,
+            &mock_env().contract.address,
+            commitment_lp.clone(),
+        )
+        .unwrap();
         assert_eq!(
             vec![ProvMsg::Bank(BankMsg::Send {
                 to_address: commitment_lp.to_string(),
@@ -203,6 +396,7 @@ mod tests {
             })],
             refund_messages
         );
+        assert_eq!(Some(Coin::new(Uint128::new(10000), "denom")), refunded);
     }
 
     #[test]
@@ -213,9 +407,15 @@ mod tests {
         instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
         create_testing_commitments(&mut deps);
 
-        let refund_messages = super::refund_lp(deps.as_mut(), commitment_lp.clone()).unwrap();
+        let (refund_messages, refunded) = super::refund_lp(
+            deps.as_mut().storage,
+            &DepositMode::Marker,
+            &mock_env().contract.address,
+            commitment_lp.clone(),
+        )
+        .unwrap();
         assert_eq!(
-            false,
+            true,
             commits::exists(deps.as_ref().storage, commitment_lp.clone())
         );
         assert_eq!(
@@ -227,13 +427,14 @@ mod tests {
             paid_in_capital::has_lp(deps.as_ref().storage, commitment_lp.clone())
         );
         assert_eq!(
-            600,
+            700,
             remaining_securities::get(&deps.storage, "Security1".to_string()).unwrap()
         );
         assert_eq!(
-            600,
+            700,
             remaining_securities::get(&deps.storage, "Security2".to_string()).unwrap()
         );
         assert_eq!(0, refund_messages.len());
+        assert_eq!(None, refunded);
     }
 }
@@ -0,0 +1,228 @@
+use cosmwasm_std::{Addr, Coin, Env, Response};
+
+use crate::{
+    core::{
+        aliases::{ProvDepsMut, ProvTxResponse},
+        error::ContractError,
+        event::{ContractEvent, EventType},
+    },
+    storage::{
+        distributions::{self},
+        state::{self},
+    },
+    util::{capital::send_capital, fees},
+};
+
+pub fn handle(deps: ProvDepsMut, env: Env, sender: Addr) -> ProvTxResponse {
+    let state = state::get(deps.storage)?;
+    let claimed = distributions::claim(deps.storage, sender.clone())?;
+    if claimed.is_zero() {
+        return Err(ContractError::NoClaimableDistributions {});
+    }
+
+    // Outstanding fees are paid out of the claim before the lp receives the rest
+    fees::accrue_management_fee(deps.storage, sender.clone(), env.block.time)?;
+    fees::accrue_carry(deps.storage, sender.clone(), claimed)?;
+    let mut messages = vec![];
+    let mut remaining = claimed;
+    if let Some((recipient, fee)) = fees::deduct(deps.storage, sender.clone(), claimed)? {
+        remaining -= fee;
+        messages.push(send_capital(
+            &state.deposit_mode,
+            Coin::new(fee, state.capital_denom.clone()),
+            &recipient,
+            &env.contract.address,
+        )?);
+    }
+    if !remaining.is_zero() {
+        messages.push(send_capital(
+            &state.deposit_mode,
+            Coin::new(remaining, state.capital_denom.clone()),
+            &sender,
+            &env.contract.address,
+        )?);
+    }
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_event(
+            ContractEvent::new(EventType::DistributionsClaimed, &state.gp)
+                .lp(&sender)
+                .amount(Coin::new(claimed, state.capital_denom)),
+        )
+        .add_attribute("action", "claim_distributions")
+        .add_attribute("lp", sender))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{
+        testing::mock_env, Addr, Attribute, BankMsg, Coin, Decimal, Event, SubMsg, Uint128,
+    };
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        core::{
+            aliases::ProvMsg,
+            error::ContractError,
+            fee::{FeeBasis, FeeSchedule},
+        },
+        storage::{
+            distributions,
+            fees::{self, FeeAccrual},
+            state::DepositMode,
+        },
+        util::{
+            provenance_utilities::transfer_marker_coins,
+            testing::{create_testing_commitments, instantiate_contract, set_deposit_mode},
+        },
+    };
+
+    use super::handle;
+
+    #[test]
+    fn test_handle_fails_with_nothing_to_claim() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+
+        let error = handle(deps.as_mut(), mock_env(), Addr::unchecked("lp7")).unwrap_err();
+        assert_eq!(
+            ContractError::NoClaimableDistributions {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_claims_distributions() {
+        let mut deps = mock_provenance_dependencies();
+        let lp = Addr::unchecked("lp7");
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        distributions::distribute(
+            deps.as_mut().storage,
+            "Security1".to_string(),
+            Uint128::new(300),
+        )
+        .unwrap();
+        distributions::distribute(
+            deps.as_mut().storage,
+            "Security2".to_string(),
+            Uint128::new(200),
+        )
+        .unwrap();
+
+        let env = mock_env();
+        let res = handle(deps.as_mut(), env.clone(), lp.clone()).unwrap();
+        assert_eq!(
+            vec![SubMsg::new(
+                transfer_marker_coins(
+                    500,
+                    "denom",
+                    lp.clone(),
+                    env.contract.address.clone(),
+                    env.contract.address
+                )
+                .unwrap()
+            )],
+            res.messages
+        );
+        assert_eq!(
+            vec![
+                Attribute::new("action", "claim_distributions"),
+                Attribute::new("lp", "lp7")
+            ],
+            res.attributes
+        );
+        assert_eq!(
+            vec![Event::new("distributions_claimed")
+                .add_attribute("gp", "gp")
+                .add_attribute("lp", "lp7")
+                .add_attribute("amount", "500denom")],
+            res.events
+        );
+        assert_eq!(
+            Uint128::zero(),
+            distributions::claimable(&deps.storage, lp).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_handle_pays_attached_funds_with_a_bank_send() {
+        let mut deps = mock_provenance_dependencies();
+        let lp = Addr::unchecked("lp7");
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        set_deposit_mode(deps.as_mut().storage, DepositMode::Funds);
+        distributions::distribute(
+            deps.as_mut().storage,
+            "Security1".to_string(),
+            Uint128::new(300),
+        )
+        .unwrap();
+
+        let res = handle(deps.as_mut(), mock_env(), lp.clone()).unwrap();
+        assert_eq!(
+            vec![SubMsg::new(ProvMsg::Bank(BankMsg::Send {
+                to_address: lp.to_string(),
+                amount: vec![Coin::new(Uint128::new(300), "denom")],
+            }))],
+            res.messages
+        );
+    }
+
+    #[test]
+    fn test_handle_deducts_outstanding_fees() {
+        let mut deps = mock_provenance_dependencies();
+        let lp = Addr::unchecked("lp7");
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        set_deposit_mode(deps.as_mut().storage, DepositMode::Funds);
+        fees::set_schedule(
+            deps.as_mut().storage,
+            &FeeSchedule {
+                recipient: Addr::unchecked("manager"),
+                management_fee_rate: Decimal::percent(2),
+                management_fee_basis: FeeBasis::Committed,
+                carry_rate: Decimal::percent(20),
+                hurdle: Decimal::percent(8),
+            },
+        )
+        .unwrap();
+        fees::set_accrual(
+            deps.as_mut().storage,
+            lp.clone(),
+            &FeeAccrual {
+                management_fee: Uint128::new(100),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        distributions::distribute(
+            deps.as_mut().storage,
+            "Security1".to_string(),
+            Uint128::new(500),
+        )
+        .unwrap();
+
+        let res = handle(deps.as_mut(), mock_env(), lp.clone()).unwrap();
+        assert_eq!(
+            vec![
+                SubMsg::new(ProvMsg::Bank(BankMsg::Send {
+                    to_address: "manager".to_string(),
+                    amount: vec![Coin::new(Uint128::new(100), "denom")],
+                })),
+                SubMsg::new(ProvMsg::Bank(BankMsg::Send {
+                    to_address: lp.to_string(),
+                    amount: vec![Coin::new(Uint128::new(400), "denom")],
+                }))
+            ],
+            res.messages
+        );
+
+        let accrual = fees::get_accrual(&deps.storage, lp);
+        assert_eq!(Uint128::new(100), accrual.paid);
+        assert_eq!(Uint128::new(500), accrual.distributed);
+        assert_eq!(Uint128::zero(), accrual.outstanding());
+    }
+}
@@ -0,0 +1,225 @@
+use cosmwasm_std::{Addr, Env, Response};
+
+use crate::{
+    core::{
+        aliases::{ProvDepsMut, ProvTxResponse},
+        error::ContractError,
+        event::{ContractEvent, EventType},
+    },
+    storage::{
+        commitment_history,
+        commits::{self},
+        state::{self},
+    },
+    util::settlement::{minimum_raise_met, timestamp_is_expired},
+};
+
+use super::{cancel_commitment::refund_lp, commitment::CommitmentState};
+
+// Anyone can trigger the refund of an accepted commitment once the settlement time has
+// passed without the offering reaching its minimum raise
+pub fn handle(deps: ProvDepsMut, env: Env, sender: Addr, lp: Addr) -> ProvTxResponse {
+    let state = state::get(deps.storage)?;
+    if state.minimum_raise.is_none()
+        || !timestamp_is_expired(deps.storage, &env.block.time)?
+        || minimum_raise_met(deps.storage, &state.minimum_raise)?
+    {
+        return Err(ContractError::RefundUnavailable {});
+    }
+
+    let mut commitment = commits::get(deps.storage, lp.clone())?;
+    if commitment.state != CommitmentState::ACCEPTED {
+        return Err(ContractError::InvalidCommitmentState {});
+    }
+
+    let (refund_messages, refunded) = refund_lp(
+        deps.storage,
+        &state.deposit_mode,
+        &env.contract.address,
+        lp.clone(),
+    )?;
+
+    commitment.state = CommitmentState::EXPIRED;
+    commitment.reason = Some("minimum raise not met".to_string());
+    commits::set(deps.storage, &commitment)?;
+    commitment_history::record(
+        deps.storage,
+        &env,
+        &lp,
+        &sender,
+        "refunded",
+        &commitment.commitments,
+    )?;
+
+    let mut event = ContractEvent::new(EventType::Refunded, &state.gp)
+        .lp(&lp)
+        .securities(&commitment.commitments)
+        .state(&commitment.state);
+    if let Some(refunded) = refunded {
+        event = event.amount(refunded);
+    }
+
+    Ok(Response::new()
+        .add_messages(refund_messages)
+        .add_event(event)
+        .add_attribute("action", "claim_refund")
+        .add_attribute("sender", sender))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{testing::mock_env, Addr, Attribute, Env, Event, SubMsg, Uint128, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        core::{error::ContractError, security::MinimumRaise},
+        execute::settlement::commitment::CommitmentState,
+        storage::{available_capital, commits, paid_in_capital, state},
+        util::{
+            provenance_utilities::transfer_marker_coins,
+            testing::{create_testing_commitments, instantiate_contract, MockDeps},
+        },
+    };
+
+    use super::handle;
+
+    fn set_minimum_raise(deps: &mut MockDeps, minimum_raise: Option<MinimumRaise>) -> Env {
+        let mut env = mock_env();
+        let mut state = state::get(&deps.storage).unwrap();
+        state.minimum_raise = minimum_raise;
+        state.settlement_time = Some(Uint64::new(env.block.time.seconds()));
+        state::set(deps.as_mut().storage, &state).unwrap();
+        env.block.time = env.block.time.plus_seconds(1);
+        env
+    }
+
+    #[test]
+    fn test_handle_fails_without_minimum_raise() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        let env = set_minimum_raise(&mut deps, None);
+
+        let error = handle(
+            deps.as_mut(),
+            env,
+            Addr::unchecked("anyone"),
+            Addr::unchecked("lp2"),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::RefundUnavailable {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_fails_before_settlement_time() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        set_minimum_raise(
+            &mut deps,
+            Some(MinimumRaise::Offering(Uint128::new(1000000))),
+        );
+
+        let error = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("anyone"),
+            Addr::unchecked("lp2"),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::RefundUnavailable {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_fails_when_minimum_raise_met() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        let env = set_minimum_raise(&mut deps, Some(MinimumRaise::Offering(Uint128::new(50000))));
+
+        let error = handle(
+            deps.as_mut(),
+            env,
+            Addr::unchecked("anyone"),
+            Addr::unchecked("lp2"),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::RefundUnavailable {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_fails_when_not_accepted() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        let env = set_minimum_raise(
+            &mut deps,
+            Some(MinimumRaise::Offering(Uint128::new(1000000))),
+        );
+
+        let error = handle(
+            deps.as_mut(),
+            env,
+            Addr::unchecked("anyone"),
+            Addr::unchecked("lp4"),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidCommitmentState {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_refunds_accepted_commitment() {
+        let mut deps = mock_provenance_dependencies();
+        let lp = Addr::unchecked("lp2");
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        let env = set_minimum_raise(
+            &mut deps,
+            Some(MinimumRaise::Offering(Uint128::new(1000000))),
+        );
+
+        let res = handle(deps.as_mut(), env, Addr::unchecked("anyone"), lp.clone()).unwrap();
+        let contract = mock_env().contract.address;
+        assert_eq!(
+            vec![SubMsg::new(
+                transfer_marker_coins(10000, "denom", lp.clone(), contract.clone(), contract)
+                    .unwrap()
+            )],
+            res.messages
+        );
+        assert_eq!(
+            vec![
+                Attribute::new("action", "claim_refund"),
+                Attribute::new("sender", "anyone")
+            ],
+            res.attributes
+        );
+        assert_eq!(
+            vec![Event::new("refunded")
+                .add_attribute("gp", "gp")
+                .add_attribute("lp", lp.clone())
+                .add_attribute("securities", "Security1:100,Security2:100")
+                .add_attribute("amount", "10000denom")
+                .add_attribute("state", "expired")],
+            res.events
+        );
+        assert!(!available_capital::has_lp(&deps.storage, lp.clone()));
+        assert!(!paid_in_capital::has_lp(&deps.storage, lp.clone()));
+
+        let commitment = commits::get(&deps.storage, lp).unwrap();
+        assert_eq!(CommitmentState::EXPIRED, commitment.state);
+        assert_eq!(Some("minimum raise not met".to_string()), commitment.reason);
+    }
+}
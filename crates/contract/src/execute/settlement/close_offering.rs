@@ -0,0 +1,83 @@
+use cosmwasm_std::{Addr, Env, Response};
+
+use crate::{
+    core::{
+        aliases::{ProvDepsMut, ProvTxResponse},
+        error::ContractError,
+        event::{ContractEvent, EventType},
+    },
+    storage::state::{self, OfferingStatus},
+    util::auth::{authorize, Role},
+};
+
+// Stops lps from proposing new commitments. Commitments that are already in flight can still be
+// accepted, funded and settled until the offering is finalized.
+pub fn handle(deps: ProvDepsMut, _env: Env, sender: Addr) -> ProvTxResponse {
+    let mut state = authorize(deps.storage, &sender, Role::Gp)?;
+
+    if state.status != OfferingStatus::Open {
+        return Err(ContractError::InvalidOfferingStatus {});
+    }
+    state.status = OfferingStatus::Closed;
+    state::set(deps.storage, &state)?;
+
+    Ok(Response::new()
+        .add_event(ContractEvent::new(EventType::OfferingClosed, &state.gp))
+        .add_attribute("action", "close_offering")
+        .add_attribute("gp", sender))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{testing::mock_env, Addr, Attribute, Event};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        core::error::ContractError,
+        storage::state::{self, OfferingStatus},
+        util::testing::instantiate_contract,
+    };
+
+    use super::handle;
+
+    #[test]
+    fn test_handle_must_be_gp() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+
+        let error = handle(deps.as_mut(), mock_env(), Addr::unchecked("lp")).unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_closes_offering() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+
+        let res = handle(deps.as_mut(), mock_env(), Addr::unchecked("gp")).unwrap();
+        assert_eq!(
+            vec![
+                Attribute::new("action", "close_offering"),
+                Attribute::new("gp", "gp")
+            ],
+            res.attributes
+        );
+        assert_eq!(
+            vec![Event::new("offering_closed").add_attribute("gp", "gp")],
+            res.events
+        );
+        assert_eq!(
+            OfferingStatus::Closed,
+            state::get(&deps.storage).unwrap().status
+        );
+
+        let error = handle(deps.as_mut(), mock_env(), Addr::unchecked("gp")).unwrap_err();
+        assert_eq!(
+            ContractError::InvalidOfferingStatus {}.to_string(),
+            error.to_string()
+        );
+    }
+}
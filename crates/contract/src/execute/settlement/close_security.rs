@@ -0,0 +1,116 @@
+use cosmwasm_std::{Addr, Env, Response};
+
+use crate::{
+    core::{
+        aliases::{ProvDepsMut, ProvTxResponse},
+        error::ContractError,
+        event::{ContractEvent, EventType},
+    },
+    storage::securities::{self},
+    util::auth::{authorize, Role},
+};
+
+// Stops lps from proposing new commitments to the security. Existing commitments are unaffected.
+pub fn handle(deps: ProvDepsMut, _env: Env, sender: Addr, security: String) -> ProvTxResponse {
+    authorize(deps.storage, &sender, Role::Gp)?;
+
+    securities::get(deps.storage, security.clone())?;
+    if securities::is_closed(deps.storage, security.clone()) {
+        return Err(ContractError::SecurityClosed {});
+    }
+    securities::close(deps.storage, security.clone())?;
+
+    Ok(Response::new()
+        .add_event(
+            ContractEvent::new(EventType::SecurityClosed, &sender).attribute("security", security),
+        )
+        .add_attribute("action", "close_security")
+        .add_attribute("gp", sender))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{testing::mock_env, Addr, Attribute, Event};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        core::error::ContractError, storage::securities, util::testing::instantiate_contract,
+    };
+
+    use super::handle;
+
+    #[test]
+    fn test_handle_must_be_gp() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+
+        let error = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("lp"),
+            "Security1".to_string(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_fails_for_invalid_security() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+
+        handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            "Security3".to_string(),
+        )
+        .unwrap_err();
+    }
+
+    #[test]
+    fn test_handle_closes_security() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+
+        let res = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            "Security1".to_string(),
+        )
+        .unwrap();
+        assert_eq!(
+            vec![
+                Attribute::new("action", "close_security"),
+                Attribute::new("gp", "gp")
+            ],
+            res.attributes
+        );
+        assert_eq!(
+            vec![Event::new("security_closed")
+                .add_attribute("gp", "gp")
+                .add_attribute("security", "Security1")],
+            res.events
+        );
+        assert!(securities::is_closed(
+            &deps.storage,
+            "Security1".to_string()
+        ));
+
+        let error = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            "Security1".to_string(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::SecurityClosed {}.to_string(),
+            error.to_string()
+        );
+    }
+}
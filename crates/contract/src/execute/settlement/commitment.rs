@@ -9,6 +9,7 @@ pub struct Commitment {
     pub commitments: Vec<SecurityCommitment>,
     pub state: CommitmentState,
     pub settlment_date: Option<Uint64>,
+    pub reason: Option<String>,
 }
 
 impl Commitment {
@@ -18,6 +19,7 @@ impl Commitment {
             commitments,
             state: CommitmentState::PENDING,
             settlment_date: None,
+            reason: None,
         }
     }
 
@@ -33,6 +35,19 @@ pub enum CommitmentState {
     PENDING,
     ACCEPTED,
     SETTLED,
+    REJECTED,
+    EXPIRED,
+    CANCELED,
+}
+
+impl CommitmentState {
+    // A terminated commitment is kept for history, but can no longer be acted on
+    pub fn is_terminated(&self) -> bool {
+        matches!(
+            self,
+            CommitmentState::REJECTED | CommitmentState::EXPIRED | CommitmentState::CANCELED
+        )
+    }
 }
 
 #[cfg(test)]
@@ -74,5 +89,16 @@ mod tests {
         assert_eq!(CommitmentState::PENDING, commitment.state);
         assert_eq!(securities, commitment.commitments);
         assert_eq!(None, commitment.settlment_date);
+        assert_eq!(None, commitment.reason);
+    }
+
+    #[test]
+    fn test_is_terminated() {
+        assert_eq!(false, CommitmentState::PENDING.is_terminated());
+        assert_eq!(false, CommitmentState::ACCEPTED.is_terminated());
+        assert_eq!(false, CommitmentState::SETTLED.is_terminated());
+        assert_eq!(true, CommitmentState::REJECTED.is_terminated());
+        assert_eq!(true, CommitmentState::EXPIRED.is_terminated());
+        assert_eq!(true, CommitmentState::CANCELED.is_terminated());
     }
 }
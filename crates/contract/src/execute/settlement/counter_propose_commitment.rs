@@ -0,0 +1,340 @@
+use cosmwasm_std::{Addr, Env, Response};
+
+use crate::{
+    core::{
+        aliases::{ProvDepsMut, ProvTxResponse},
+        error::ContractError,
+        event::{ContractEvent, EventType},
+        security::SecurityCommitment,
+    },
+    storage::{
+        commitment_history,
+        commits::{self},
+        counter_proposals,
+    },
+    util::{
+        auth::{authorize, Role},
+        settlement::timestamp_is_expired,
+    },
+};
+
+use super::{commitment::CommitmentState, propose_commitment};
+
+pub fn handle(
+    deps: ProvDepsMut,
+    env: Env,
+    sender: Addr,
+    lp: Addr,
+    securities: Vec<SecurityCommitment>,
+) -> ProvTxResponse {
+    let state = authorize(deps.storage, &sender, Role::Gp)?;
+
+    if timestamp_is_expired(deps.storage, &env.block.time)? {
+        return Err(ContractError::SettlmentExpired {});
+    }
+
+    let commitment = commits::get(deps.storage, lp.clone())?;
+    if commitment.state != CommitmentState::PENDING {
+        return Err(ContractError::InvalidCommitmentState {});
+    }
+
+    // The GP can only counter with securities that the lp proposed
+    if !securities.iter().all(|security| {
+        commitment
+            .commitments
+            .iter()
+            .any(|proposed| proposed.name == security.name)
+    }) {
+        return Err(ContractError::InvalidSecurityCommitment {});
+    }
+    propose_commitment::validate_securities(&deps.as_ref(), &lp, &securities, &securities)?;
+
+    counter_proposals::set(deps.storage, lp.clone(), &securities)?;
+    commitment_history::record(
+        deps.storage,
+        &env,
+        &lp,
+        &sender,
+        "counter_proposed",
+        &securities,
+    )?;
+
+    Ok(Response::new()
+        .add_event(
+            ContractEvent::new(EventType::CounterProposed, &state.gp)
+                .lp(&lp)
+                .securities(&securities)
+                .state(&commitment.state),
+        )
+        .add_attribute("action", "counter_propose_commitment")
+        .add_attribute("gp", state.gp))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{testing::mock_env, Addr, Attribute, Event, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        core::error::ContractError,
+        execute::settlement::commitment::{Commitment, CommitmentState},
+        storage::{commits, counter_proposals, eligibility, securities},
+        util::testing::{
+            create_test_state, create_testing_commitments, instantiate_contract,
+            mock_lp_attributes, test_security_commitments,
+        },
+    };
+
+    use super::handle;
+
+    #[test]
+    fn test_handle_must_be_triggered_by_gp() {
+        let mut deps = mock_provenance_dependencies();
+        let env = mock_env();
+        create_test_state(&mut deps, &env, false);
+
+        let error = handle(
+            deps.as_mut(),
+            env,
+            Addr::unchecked("lp1"),
+            Addr::unchecked("lp1"),
+            test_security_commitments(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_fails_when_settlement_expired() {
+        let mut deps = mock_provenance_dependencies();
+        let mut env = mock_env();
+        create_test_state(&mut deps, &env, true);
+        env.block.time = env.block.time.plus_seconds(86401);
+
+        let error = handle(
+            deps.as_mut(),
+            env,
+            Addr::unchecked("gp"),
+            Addr::unchecked("lp1"),
+            test_security_commitments(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::SettlmentExpired {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_fails_when_not_pending() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+
+        let error = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            Addr::unchecked("lp1"),
+            test_security_commitments(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidCommitmentState {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_fails_below_minimum() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+
+        let mut securities = test_security_commitments();
+        securities[0].amount = Uint128::new(1);
+        let error = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            Addr::unchecked("lp4"),
+            securities,
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidSecurityCommitmentAmount {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_fails_when_exceeding_remaining() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+
+        let mut securities = test_security_commitments();
+        securities[0].amount = Uint128::new(100000);
+        let error = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            Addr::unchecked("lp4"),
+            securities,
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::CommitmentExceedsRemainingSecurityAmount {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_fails_for_securities_the_lp_did_not_propose() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        let lp = Addr::unchecked("lp8");
+        commits::set(
+            deps.as_mut().storage,
+            &Commitment::new(lp.clone(), vec![test_security_commitments()[0].clone()]),
+        )
+        .unwrap();
+
+        let error = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            lp,
+            test_security_commitments(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidSecurityCommitment {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_fails_for_duplicate_securities() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+
+        let mut securities = test_security_commitments();
+        securities[1] = securities[0].clone();
+        let error = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            Addr::unchecked("lp4"),
+            securities,
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidSecurityCommitment {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_fails_when_lp_is_no_longer_eligible() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        eligibility::set_required_attributes(
+            deps.as_mut().storage,
+            "Security2".to_string(),
+            &vec!["accredited.investor.pb".to_string()],
+        )
+        .unwrap();
+        mock_lp_attributes(&mut deps, &[("lp1", "accredited.investor.pb")]);
+
+        let error = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            Addr::unchecked("lp4"),
+            test_security_commitments(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::IneligibleLp {
+                security: "Security2".to_string()
+            }
+            .to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_fails_when_exceeding_allocation_limits() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        let mut security = securities::get(&deps.storage, "Security1".to_string()).unwrap();
+        security.maximum_amount = Some(Uint128::new(50));
+        securities::set(deps.as_mut().storage, &security).unwrap();
+
+        let mut securities = test_security_commitments();
+        securities[0].amount = Uint128::new(60);
+        let error = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            Addr::unchecked("lp4"),
+            securities,
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::AllocationLimitExceeded {
+                security: "Security1".to_string(),
+                limit: "maximum amount".to_string()
+            }
+            .to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_succeeds() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        let lp = Addr::unchecked("lp4");
+
+        let mut securities = test_security_commitments();
+        securities[0].amount = Uint128::new(50);
+        let res = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            lp.clone(),
+            securities.clone(),
+        )
+        .unwrap();
+        assert_eq!(
+            vec![
+                Attribute::new("action", "counter_propose_commitment"),
+                Attribute::new("gp", "gp")
+            ],
+            res.attributes
+        );
+        assert_eq!(
+            vec![Event::new("counter_proposed")
+                .add_attribute("gp", "gp")
+                .add_attribute("lp", lp.clone())
+                .add_attribute("securities", "Security1:50,Security2:100")
+                .add_attribute("state", "pending")],
+            res.events
+        );
+
+        // The commitment is unchanged until the lp accepts the counter proposal
+        let commitment: Commitment = commits::get(&deps.storage, lp.clone()).unwrap();
+        assert_eq!(CommitmentState::PENDING, commitment.state);
+        assert_eq!(test_security_commitments(), commitment.commitments);
+        assert_eq!(Some(securities), counter_proposals::get(&deps.storage, lp));
+    }
+}
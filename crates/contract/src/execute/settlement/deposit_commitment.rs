@@ -1,17 +1,22 @@
-use cosmwasm_std::{Addr, Coin, Env, Event, Response, Uint128};
+use cosmwasm_std::{Addr, Coin, Env, Response, Uint128};
 
 use super::commitment::CommitmentState;
-use crate::storage::{securities, state};
+use crate::storage::state::DepositMode;
+use crate::storage::{nav, securities, state};
+use crate::util::capital::funds_match;
 use crate::util::provenance_utilities::transfer_marker_coins;
 use crate::{
     core::{
-        aliases::{ProvDepsMut, ProvMsg, ProvTxResponse},
+        aliases::{ProvDeps, ProvDepsMut, ProvMsg, ProvTxResponse},
         error::ContractError,
+        event::{ContractEvent, EventType},
         security::SecurityCommitment,
     },
     storage::{
         self,
         available_capital::{self},
+        capital_calls::{self},
+        commitment_history,
         commits::{self},
         paid_in_capital::{self},
         // securities::{self},
@@ -23,56 +28,95 @@ pub fn handle(
     deps: ProvDepsMut,
     env: Env,
     sender: Addr,
+    attached: Vec<Coin>,
     deposit: Vec<SecurityCommitment>,
 ) -> ProvTxResponse {
+    let funds = validate(&deps.as_ref(), &env, &sender, &deposit)?;
+    let state = state::get(deps.storage)?;
+
+    // Marker capital is pulled from the lp, and funds capital must be attached in full
+    let deposit_message = match state.deposit_mode {
+        DepositMode::Marker => {
+            if !attached.is_empty() {
+                return Err(ContractError::UnexpectedFunds {});
+            }
+            process_deposit(sender.clone(), env.contract.address.clone(), funds.clone())?
+        }
+        DepositMode::Funds => {
+            if !funds_match(&attached, &funds) {
+                return Err(ContractError::FundMismatch {});
+            }
+            vec![]
+        }
+    };
+
+    // Fees on called capital accrue on what was paid in before this deposit
+    util::fees::accrue_management_fee(deps.storage, sender.clone(), env.block.time)?;
+    commitment_history::record(deps.storage, &env, &sender, &sender, "deposited", &deposit)?;
+    update_depositer_capital(deps, sender.clone(), funds.clone(), deposit.clone())?;
+
+    Ok(Response::new()
+        .add_messages(deposit_message)
+        .add_event(
+            ContractEvent::new(EventType::Deposited, &state.gp)
+                .lp(&sender)
+                .securities(&deposit)
+                .amount(funds[0].clone())
+                .state(&CommitmentState::ACCEPTED),
+        )
+        .add_attribute("action", "deposit_commitment")
+        .add_attribute("sender", sender)
+        .add_attribute("recipient", env.contract.address)
+        .add_attribute("funds_amount", funds[0].amount.clone()))
+}
+
+// Runs every check that the deposit must pass without changing any state, and returns the
+// funds that will be transferred from the lp
+pub fn validate(
+    deps: &ProvDeps,
+    env: &Env,
+    sender: &Addr,
+    deposit: &[SecurityCommitment],
+) -> Result<Vec<Coin>, ContractError> {
     let state = state::get(deps.storage)?;
     let commitment = storage::commits::get(deps.storage, sender.clone())?;
-    if util::settlement::is_expired(&env, &commitment) {
+    if util::settlement::is_expired(env, &commitment) {
         return Err(crate::core::error::ContractError::SettlmentExpired {});
     }
 
-    if !is_accepted(&deps, &sender)? {
+    if !is_accepted(deps, sender)? {
         return Err(crate::core::error::ContractError::InvalidCommitmentState {});
     }
 
-    if !securities_match(&deps, &deposit, sender.clone())? {
+    if !securities_match(deps, deposit, sender.clone())? {
         return Err(crate::core::error::ContractError::InvalidSecurityCommitment {});
     }
 
-    /*
-    if !funds_match_deposit(&deps, &funds, &deposit, &state.capital_denom)? {
-        return Err(crate::core::error::ContractError::FundMismatch {});
+    if deposit_exceeds_commitment(deps, sender.clone(), deposit)? {
+        return Err(crate::core::error::ContractError::ExcessiveDeposit {});
     }
-    */
 
-    if deposit_exceeds_commitment(&deps, sender.clone(), &deposit)? {
-        return Err(crate::core::error::ContractError::ExcessiveDeposit {});
+    if capital_call_is_overdue(deps, env, sender.clone()) {
+        return Err(crate::core::error::ContractError::CapitalCallOverdue {});
+    }
+
+    if deposit_exceeds_capital_call(deps, sender.clone(), deposit) {
+        return Err(crate::core::error::ContractError::DepositExceedsCapitalCall {});
     }
 
     // convert the security commitment into actual fund coin
     // assumes for now that the deposit == commitment
-    let funds = calculate_funds(&deps, &deposit, &state.capital_denom)?;
+    let funds = calculate_funds(deps, deposit, &state.capital_denom)?;
     if funds.is_empty() {
         return Err(crate::core::error::ContractError::EmptyDeposit {});
     }
-
-    let deposit_message =
-        process_deposit(sender.clone(), env.contract.address.clone(), funds.clone())?;
-    update_depositer_capital(deps, sender.clone(), funds.clone(), deposit)?;
-
-    Ok(Response::new()
-        .add_messages(deposit_message)
-        .add_event(Event::new("deposited").add_attribute("lp", sender.clone()))
-        .add_attribute("action", "deposit_commitment")
-        .add_attribute("sender", sender)
-        .add_attribute("recipient", env.contract.address)
-        .add_attribute("funds_amount", funds[0].amount.clone()))
+    Ok(funds)
 }
 
 // Check if they have a commitment - Shouldn't really matter
 // What if they have no paid_capital?
 fn deposit_exceeds_commitment(
-    deps: &ProvDepsMut,
+    deps: &ProvDeps,
     lp: Addr,
     deposit: &[SecurityCommitment],
 ) -> Result<bool, ContractError> {
@@ -109,6 +153,40 @@ fn deposit_exceeds_commitment(
     Ok(!can_deposit)
 }
 
+// Once the GP has issued a capital call the lp can only deposit what has been called.
+// Without a capital call the full commitment can be deposited.
+fn deposit_exceeds_capital_call(deps: &ProvDeps, lp: Addr, deposit: &[SecurityCommitment]) -> bool {
+    let capital_call = match capital_calls::get(deps.storage, lp.clone()) {
+        None => return false,
+        Some(capital_call) => capital_call,
+    };
+    let paid_capital = paid_in_capital::get(deps.storage, lp);
+
+    !deposit.iter().all(|deposit_element| {
+        let paid_amount = paid_capital
+            .iter()
+            .find(|capital_element| capital_element.name == deposit_element.name)
+            .map_or(Uint128::zero(), |element| element.amount);
+
+        match capital_call
+            .securities
+            .iter()
+            .find(|called_element| called_element.name == deposit_element.name)
+        {
+            None => false,
+            Some(called_element) => paid_amount + deposit_element.amount <= called_element.amount,
+        }
+    })
+}
+
+// Called capital can only be paid in until the call's due date. A new call sets a new due date.
+fn capital_call_is_overdue(deps: &ProvDeps, env: &Env, lp: Addr) -> bool {
+    match capital_calls::get(deps.storage, lp).and_then(|capital_call| capital_call.due_date) {
+        Some(due_date) => env.block.time.seconds() > due_date.u64(),
+        None => false,
+    }
+}
+
 fn process_deposit(
     sender: Addr,
     contract: Addr,
@@ -147,7 +225,7 @@ fn update_depositer_capital(
 // We check to make sure that every security commitment in the drawdown was specified at instantiation
 // We also make sure our initial drawdown has the minimum for each of these security commitments
 fn securities_match(
-    deps: &ProvDepsMut,
+    deps: &ProvDeps,
     deposit_securities: &[SecurityCommitment],
     lp: Addr,
 ) -> Result<bool, ContractError> {
@@ -161,21 +239,9 @@ fn securities_match(
         .iter()
         .all(|deposit_security| commitment_securities.contains(&deposit_security.name)))
 }
-/*
-fn funds_match_deposit(
-    deps: &ProvDepsMut,
-    funds: &Vec<Coin>,
-    deposit: &[SecurityCommitment],
-    capital_denom: &String,
-) -> Result<bool, ContractError> {
-    let expected_funds = calculate_funds(deps, deposit, capital_denom)?;
-    let has_funds = expected_funds.iter().all(|coin| funds.contains(coin));
-    Ok(expected_funds.len() == funds.len() && has_funds)
-}
-*/
 // We are strict that all capital must be in the same denom
 fn calculate_funds(
-    deps: &ProvDepsMut,
+    deps: &ProvDeps,
     deposit: &[SecurityCommitment],
     capital_denom: &String,
 ) -> Result<Vec<Coin>, ContractError> {
@@ -184,45 +250,54 @@ fn calculate_funds(
     for security_commitment in deposit {
         let security = securities::get(deps.storage, security_commitment.name.clone())?;
 
-        let cost = security_commitment.amount * security.price_per_unit.amount;
+        let cost = security_commitment.amount * nav::current_price(deps.storage, &security)?.amount;
         sum.amount += cost;
     }
 
     Ok(vec![sum])
 }
 
-fn is_accepted(deps: &ProvDepsMut, sender: &Addr) -> Result<bool, ContractError> {
+fn is_accepted(deps: &ProvDeps, sender: &Addr) -> Result<bool, ContractError> {
     let commitment = commits::get(deps.storage, sender.clone())?;
     Ok(commitment.state == CommitmentState::ACCEPTED)
 }
 
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::{testing::mock_env, Addr, Attribute, Coin, Uint128, Uint64};
+    use cosmwasm_std::{testing::mock_env, Addr, Attribute, Coin, Event, Uint128, Uint64};
     use provwasm_mocks::mock_provenance_dependencies;
 
     use crate::{
         core::security::{FundSecurity, Security, SecurityCommitment, TrancheSecurity},
         execute::settlement::{
             commitment::{Commitment, CommitmentState},
-            deposit_commitment::{deposit_exceeds_commitment, update_depositer_capital},
+            deposit_commitment::{
+                deposit_exceeds_capital_call, deposit_exceeds_commitment, update_depositer_capital,
+            },
         },
         storage::{
             available_capital::{self},
+            capital_calls::{self, CapitalCall},
             commits::{self},
+            nav::{self},
             paid_in_capital::{self},
             securities::{self},
+            state::DepositMode,
+        },
+        util::testing::{
+            accept_test_commitment, create_test_securities, deposit_funds_test, deposit_test,
+            instantiate_contract, propose_test_commitment, set_deposit_mode,
+            test_security_commitments, SettlementTester,
         },
-        util::testing::SettlementTester,
     };
 
     use super::{calculate_funds, handle, is_accepted, securities_match};
 
     #[test]
     fn test_is_accepted_throws_error_on_invalid_lp() {
-        let mut deps = mock_provenance_dependencies();
+        let deps = mock_provenance_dependencies();
         let sender = Addr::unchecked("lp");
-        is_accepted(&deps.as_mut(), &sender).unwrap_err();
+        is_accepted(&deps.as_ref(), &sender).unwrap_err();
     }
 
     #[test]
@@ -231,7 +306,7 @@ mod tests {
         let sender = Addr::unchecked("lp");
         let commitment = Commitment::new(sender.clone(), vec![]);
         commits::set(deps.as_mut().storage, &commitment).unwrap();
-        let res = is_accepted(&deps.as_mut(), &sender).unwrap();
+        let res = is_accepted(&deps.as_ref(), &sender).unwrap();
         assert_eq!(false, res);
     }
 
@@ -242,30 +317,30 @@ mod tests {
         let mut commitment = Commitment::new(sender.clone(), vec![]);
         commitment.state = CommitmentState::ACCEPTED;
         commits::set(deps.as_mut().storage, &commitment).unwrap();
-        let res = is_accepted(&deps.as_mut(), &sender).unwrap();
+        let res = is_accepted(&deps.as_ref(), &sender).unwrap();
         assert_eq!(true, res);
     }
 
     #[test]
     fn test_calculate_funds_should_throw_error_with_invalid_security() {
-        let mut deps = mock_provenance_dependencies();
+        let deps = mock_provenance_dependencies();
         let capital_denom = "denom".to_string();
         let securities = vec![SecurityCommitment {
             name: "Security1".to_string(),
             amount: Uint128::new(5),
         }];
 
-        calculate_funds(&deps.as_mut(), &securities, &capital_denom)
+        calculate_funds(&deps.as_ref(), &securities, &capital_denom)
             .expect_err("should throw error");
     }
 
     #[test]
     fn test_calculate_funds_should_work_with_empty() {
-        let mut deps = mock_provenance_dependencies();
+        let deps = mock_provenance_dependencies();
         let capital_denom = "denom".to_string();
         let securities = vec![];
 
-        let funds = calculate_funds(&deps.as_mut(), &securities, &capital_denom).unwrap();
+        let funds = calculate_funds(&deps.as_ref(), &securities, &capital_denom).unwrap();
         assert_eq!(vec![Coin::new(Uint128::new(0), capital_denom)], funds);
     }
 
@@ -277,16 +352,20 @@ mod tests {
             Security {
                 name: "Security1".to_string(),
                 amount: Uint128::new(10),
-                security_type: crate::core::security::SecurityType::Fund(FundSecurity {}),
+                security_type: crate::core::security::SecurityType::Fund(FundSecurity::default()),
                 minimum_amount: Uint128::new(1),
                 price_per_unit: Coin::new(Uint128::new(10), capital_denom.clone()),
+                maximum_amount: None,
+                maximum_percentage: None,
             },
             Security {
                 name: "Security2".to_string(),
                 amount: Uint128::new(10),
-                security_type: crate::core::security::SecurityType::Fund(FundSecurity {}),
+                security_type: crate::core::security::SecurityType::Fund(FundSecurity::default()),
                 minimum_amount: Uint128::new(1),
                 price_per_unit: Coin::new(Uint128::new(5), capital_denom.clone()),
+                maximum_amount: None,
+                maximum_percentage: None,
             },
         ];
         let commitments = vec![
@@ -302,97 +381,30 @@ mod tests {
         securities::set(deps.as_mut().storage, &securities[0]).unwrap();
         securities::set(deps.as_mut().storage, &securities[1]).unwrap();
 
-        let funds = calculate_funds(&deps.as_mut(), &commitments, &capital_denom).unwrap();
+        let funds = calculate_funds(&deps.as_ref(), &commitments, &capital_denom).unwrap();
         assert_eq!(vec![Coin::new(Uint128::new(85), capital_denom)], funds);
     }
 
-    /*
-    #[test]
-    fn test_funds_match_deposit() {
-        let mut deps = mock_provenance_dependencies();
-        let capital_denom = "denom".to_string();
-        let funds = vec![Coin::new(Uint128::new(10)0, &capital_denom)];
-        let deposit = vec![
-            SecurityCommitment {
-                name: "Security1".to_string(),
-                amount: Uint128::new(1),
-            },
-            SecurityCommitment {
-                name: "Security2".to_string(),
-                amount: Uint128::new(2),
-            },
-        ];
-
-        securities::set(
-            deps.as_mut().storage,
-            &Security {
-                name: deposit[0].name.clone(),
-                amount: Uint128::new(10),
-                security_type: crate::core::security::SecurityType::Fund(FundSecurity {}),
-                minimum_amount: Uint128::new(1),
-                price_per_unit: Coin::new(Uint128::new(50), &capital_denom),
-            },
-        )
-        .unwrap();
-        securities::set(
-            deps.as_mut().storage,
-            &Security {
-                name: deposit[1].name.clone(),
-                amount: Uint128::new(10),
-                security_type: crate::core::security::SecurityType::Fund(FundSecurity {}),
-                minimum_amount: Uint128::new(1),
-                price_per_unit: Coin::new(25, &capital_denom),
-            },
-        )
-        .unwrap();
-
-        let res = funds_match_deposit(&deps.as_mut(), &funds, &deposit, &capital_denom).unwrap();
-        assert_eq!(true, res);
-    }
-
     #[test]
-    fn test_funds_match_deposit_should_fail_with_fund_amount_mismatch() {
+    fn test_calculate_funds_uses_latest_nav() {
         let mut deps = mock_provenance_dependencies();
         let capital_denom = "denom".to_string();
-        let funds = vec![Coin::new(Uint128::new(120), &capital_denom)];
-        let deposit = vec![
-            SecurityCommitment {
-                name: "Security1".to_string(),
-                amount: Uint128::new(1),
-            },
-            SecurityCommitment {
-                name: "Security2".to_string(),
-                amount: Uint128::new(2),
-            },
-        ];
-
-        securities::set(
-            deps.as_mut().storage,
-            &Security {
-                name: deposit[0].name.clone(),
-                amount: Uint128::new(10),
-                security_type: crate::core::security::SecurityType::Fund(FundSecurity {}),
-                minimum_amount: Uint128::new(1),
-                price_per_unit: Coin::new(Uint128::new(50), &capital_denom),
-            },
-        )
-        .unwrap();
-        securities::set(
+        securities::set(deps.as_mut().storage, &create_test_securities()[0]).unwrap();
+        nav::add(
             deps.as_mut().storage,
-            &Security {
-                name: deposit[1].name.clone(),
-                amount: Uint128::new(10),
-                security_type: crate::core::security::SecurityType::Fund(FundSecurity {}),
-                minimum_amount: Uint128::new(1),
-                price_per_unit: Coin::new(25, &capital_denom),
-            },
+            &mock_env(),
+            "Security1".to_string(),
+            Coin::new(Uint128::new(120), capital_denom.clone()),
         )
         .unwrap();
+        let commitments = vec![SecurityCommitment {
+            name: "Security1".to_string(),
+            amount: Uint128::new(5),
+        }];
 
-        let res = funds_match_deposit(&deps.as_mut(), &funds, &deposit, &capital_denom).unwrap();
-        assert_eq!(false, res);
+        let funds = calculate_funds(&deps.as_ref(), &commitments, &capital_denom).unwrap();
+        assert_eq!(vec![Coin::new(Uint128::new(600), capital_denom)], funds);
     }
-    */
 
     #[test]
     fn test_securities_match_can_handle_empty() {
@@ -409,10 +421,11 @@ mod tests {
                 }],
                 state: CommitmentState::ACCEPTED,
                 settlment_date: None,
+                reason: None,
             },
         )
         .unwrap();
-        let res = securities_match(&deps.as_mut(), &initial_drawdown, lp).unwrap();
+        let res = securities_match(&deps.as_ref(), &initial_drawdown, lp).unwrap();
         assert_eq!(true, res);
     }
 
@@ -440,10 +453,11 @@ mod tests {
                 ],
                 state: CommitmentState::ACCEPTED,
                 settlment_date: None,
+                reason: None,
             },
         )
         .unwrap();
-        let res = securities_match(&deps.as_mut(), &commitment, lp).unwrap();
+        let res = securities_match(&deps.as_ref(), &commitment, lp).unwrap();
         assert_eq!(true, res);
     }
 
@@ -477,10 +491,11 @@ mod tests {
                 ],
                 state: CommitmentState::ACCEPTED,
                 settlment_date: None,
+                reason: None,
             },
         )
         .unwrap();
-        let res = securities_match(&deps.as_mut(), &commitment, lp).unwrap();
+        let res = securities_match(&deps.as_ref(), &commitment, lp).unwrap();
         assert_eq!(false, res);
     }
 
@@ -502,10 +517,11 @@ mod tests {
                 }],
                 state: CommitmentState::ACCEPTED,
                 settlment_date: None,
+                reason: None,
             },
         )
         .unwrap();
-        let res = securities_match(&deps.as_mut(), &commitment, lp).unwrap();
+        let res = securities_match(&deps.as_ref(), &commitment, lp).unwrap();
         assert_eq!(true, res);
     }
 
@@ -573,8 +589,8 @@ mod tests {
         let commitment = Commitment::new(sender.clone(), vec![]);
         commits::set(deps.as_mut().storage, &commitment).unwrap();
 
-        let error =
-            handle(deps.as_mut(), mock_env(), sender, deposit).expect_err("should throw error");
+        let error = handle(deps.as_mut(), mock_env(), sender, vec![], deposit)
+            .expect_err("should throw error");
         assert_eq!(
             crate::core::error::ContractError::InvalidCommitmentState {}.to_string(),
             error.to_string()
@@ -606,15 +622,17 @@ mod tests {
             &Security {
                 name: settlement_tester.security_commitments[0].name.clone(),
                 amount: Uint128::new(1000),
-                security_type: crate::core::security::SecurityType::Tranche(TrancheSecurity {}),
+                security_type: crate::core::security::SecurityType::Tranche(TrancheSecurity::default()),
                 minimum_amount: Uint128::new(1),
                 price_per_unit: Coin::new(Uint128::new(1), "denom".to_string()),
+                maximum_amount: None,
+                maximum_percentage: None,
             },
         )
         .unwrap();
 
         let error =
-            handle(deps.as_mut(), mock_env(), sender, deposit).expect_err("should throw error");
+            handle(deps.as_mut(), mock_env(), sender, vec![], deposit).expect_err("should throw error");
         assert_eq!(
             crate::core::error::ContractError::ExcessiveDeposit {}.to_string(),
             error.to_string()
@@ -650,15 +668,19 @@ mod tests {
             &Security {
                 name: settlement_tester.security_commitments[0].name.clone(),
                 amount: Uint128::new(1000),
-                security_type: crate::core::security::SecurityType::Tranche(TrancheSecurity {}),
+                security_type: crate::core::security::SecurityType::Tranche(
+                    TrancheSecurity::default(),
+                ),
                 minimum_amount: Uint128::new(1),
                 price_per_unit: Coin::new(Uint128::new(1), "denom".to_string()),
+                maximum_amount: None,
+                maximum_percentage: None,
             },
         )
         .unwrap();
 
-        let error =
-            handle(deps.as_mut(), mock_env(), sender, deposit).expect_err("should throw error");
+        let error = handle(deps.as_mut(), mock_env(), sender, vec![], deposit)
+            .expect_err("should throw error");
         assert_eq!(
             crate::core::error::ContractError::SettlmentExpired {}.to_string(),
             error.to_string()
@@ -679,23 +701,23 @@ mod tests {
         settlement_tester.setup_test_state(deps.as_mut().storage);
         commits::set(deps.as_mut().storage, &commitment).unwrap();
 
-        let error =
-            handle(deps.as_mut(), mock_env(), sender, deposit).expect_err("should throw error");
+        let error = handle(deps.as_mut(), mock_env(), sender, vec![], deposit)
+            .expect_err("should throw error");
         assert_eq!(
             crate::core::error::ContractError::InvalidSecurityCommitment {}.to_string(),
             error.to_string()
         );
     }
 
-    /* Taken out because we don't check if funds match - because SC is pulling funds committed
     #[test]
     fn test_handle_should_throw_error_when_funds_mismatch() {
         let mut deps = mock_provenance_dependencies();
         let sender = Addr::unchecked("lp");
-        let funds = vec![Coin::new(Uint128::new(10), "capital_denom".to_string())];
+        let funds = vec![Coin::new(Uint128::new(10), "denom".to_string())];
         let mut settlement_tester = SettlementTester::new();
         settlement_tester.create_security_commitments(1);
         settlement_tester.setup_test_state(deps.as_mut().storage);
+        set_deposit_mode(deps.as_mut().storage, DepositMode::Funds);
         let deposit = settlement_tester.security_commitments.clone();
         let mut commitment = Commitment::new(
             sender.clone(),
@@ -710,21 +732,89 @@ mod tests {
             &Security {
                 name: settlement_tester.security_commitments[0].name.clone(),
                 amount: Uint128::new(100),
-                security_type: crate::core::security::SecurityType::Tranche(TrancheSecurity {}),
+                security_type: crate::core::security::SecurityType::Tranche(
+                    TrancheSecurity::default(),
+                ),
                 minimum_amount: Uint128::new(1),
-                price_per_unit: Coin::new(Uint128::new(10), "capital_denom".to_string()),
+                price_per_unit: Coin::new(Uint128::new(10), "denom".to_string()),
+                maximum_amount: None,
+                maximum_percentage: None,
             },
         )
         .unwrap();
 
-        let error =
-            handle(deps.as_mut(), mock_env(), sender, deposit).expect_err("should throw error");
+        let error = handle(deps.as_mut(), mock_env(), sender, funds, deposit)
+            .expect_err("should throw error");
         assert_eq!(
             crate::core::error::ContractError::FundMismatch {}.to_string(),
             error.to_string()
         );
     }
-    */
+
+    #[test]
+    fn test_handle_should_throw_error_on_attached_funds_with_marker_deposits() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).unwrap();
+        propose_test_commitment(deps.as_mut(), mock_env(), "lp").unwrap();
+        accept_test_commitment(deps.as_mut(), mock_env(), "gp", &["lp"]).unwrap();
+
+        let error = deposit_funds_test(
+            deps.as_mut(),
+            mock_env(),
+            "lp",
+            &test_security_commitments(),
+            &[Coin::new(20000u128, "denom")],
+        )
+        .unwrap_err();
+        assert_eq!(
+            crate::core::error::ContractError::UnexpectedFunds {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_should_work_with_attached_funds() {
+        let mut deps = mock_provenance_dependencies();
+        let lp = Addr::unchecked("lp");
+        instantiate_contract(deps.as_mut()).unwrap();
+        set_deposit_mode(deps.as_mut().storage, DepositMode::Funds);
+        propose_test_commitment(deps.as_mut(), mock_env(), "lp").unwrap();
+        accept_test_commitment(deps.as_mut(), mock_env(), "gp", &["lp"]).unwrap();
+
+        let error = deposit_test(
+            deps.as_mut(),
+            mock_env(),
+            "lp",
+            &test_security_commitments(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            crate::core::error::ContractError::FundMismatch {}.to_string(),
+            error.to_string()
+        );
+
+        let response = deposit_funds_test(
+            deps.as_mut(),
+            mock_env(),
+            "lp",
+            &test_security_commitments(),
+            &[Coin::new(20000u128, "denom")],
+        )
+        .unwrap();
+        assert!(response.messages.is_empty());
+        assert_eq!(
+            Attribute::new("funds_amount", "20000"),
+            response.attributes[3]
+        );
+        assert_eq!(
+            vec![Coin::new(20000u128, "denom")],
+            available_capital::get_capital(deps.as_mut().storage, lp.clone()).unwrap()
+        );
+        assert_eq!(
+            test_security_commitments(),
+            paid_in_capital::get(&deps.storage, lp)
+        );
+    }
 
     #[test]
     fn test_handle_should_work() {
@@ -749,15 +839,25 @@ mod tests {
             &Security {
                 name: settlement_tester.security_commitments[0].name.clone(),
                 amount: Uint128::new(1000),
-                security_type: crate::core::security::SecurityType::Tranche(TrancheSecurity {}),
+                security_type: crate::core::security::SecurityType::Tranche(
+                    TrancheSecurity::default(),
+                ),
                 minimum_amount: Uint128::new(1),
                 price_per_unit: Coin::new(Uint128::new(1), "denom".to_string()),
+                maximum_amount: None,
+                maximum_percentage: None,
             },
         )
         .unwrap();
 
-        let response = handle(deps.as_mut(), mock_env.clone(), sender.clone(), deposit)
-            .expect("Should not throw error");
+        let response = handle(
+            deps.as_mut(),
+            mock_env.clone(),
+            sender.clone(),
+            vec![],
+            deposit,
+        )
+        .expect("Should not throw error");
         assert_eq!(1, response.messages.len());
         assert_eq!(4, response.attributes.len());
         assert_eq!(
@@ -770,6 +870,15 @@ mod tests {
             response.attributes[2]
         );
         assert_eq!(Attribute::new("funds_amount", "11"), response.attributes[3]);
+        assert_eq!(
+            vec![Event::new("deposited")
+                .add_attribute("gp", "gp")
+                .add_attribute("lp", "sender")
+                .add_attribute("securities", "Security1:11")
+                .add_attribute("amount", "11denom")
+                .add_attribute("state", "accepted")],
+            response.events
+        );
     }
 
     #[test]
@@ -797,9 +906,13 @@ mod tests {
             &Security {
                 name: settlement_tester.security_commitments[0].name.clone(),
                 amount: Uint128::new(1000),
-                security_type: crate::core::security::SecurityType::Tranche(TrancheSecurity {}),
+                security_type: crate::core::security::SecurityType::Tranche(
+                    TrancheSecurity::default(),
+                ),
                 minimum_amount: Uint128::new(1),
                 price_per_unit: Coin::new(Uint128::new(1), "denom".to_string()),
+                maximum_amount: None,
+                maximum_percentage: None,
             },
         )
         .unwrap();
@@ -809,15 +922,25 @@ mod tests {
             &Security {
                 name: settlement_tester.security_commitments[1].name.clone(),
                 amount: Uint128::new(1000),
-                security_type: crate::core::security::SecurityType::Tranche(TrancheSecurity {}),
+                security_type: crate::core::security::SecurityType::Tranche(
+                    TrancheSecurity::default(),
+                ),
                 minimum_amount: Uint128::new(0),
                 price_per_unit: Coin::new(Uint128::new(1), "denom".to_string()),
+                maximum_amount: None,
+                maximum_percentage: None,
             },
         )
         .unwrap();
 
-        let response = handle(deps.as_mut(), mock_env.clone(), sender.clone(), deposit)
-            .expect("Should not throw error");
+        let response = handle(
+            deps.as_mut(),
+            mock_env.clone(),
+            sender.clone(),
+            vec![],
+            deposit,
+        )
+        .expect("Should not throw error");
         assert_eq!(1, response.messages.len());
         assert_eq!(4, response.attributes.len());
         assert_eq!(
@@ -860,9 +983,13 @@ mod tests {
             &Security {
                 name: settlement_tester.security_commitments[0].name.clone(),
                 amount: Uint128::new(1000),
-                security_type: crate::core::security::SecurityType::Tranche(TrancheSecurity {}),
+                security_type: crate::core::security::SecurityType::Tranche(
+                    TrancheSecurity::default(),
+                ),
                 minimum_amount: Uint128::new(1),
                 price_per_unit: Coin::new(Uint128::new(1), "denom".to_string()),
+                maximum_amount: None,
+                maximum_percentage: None,
             },
         )
         .unwrap();
@@ -872,15 +999,25 @@ mod tests {
             &Security {
                 name: settlement_tester.security_commitments[1].name.clone(),
                 amount: Uint128::new(1000),
-                security_type: crate::core::security::SecurityType::Tranche(TrancheSecurity {}),
+                security_type: crate::core::security::SecurityType::Tranche(
+                    TrancheSecurity::default(),
+                ),
                 minimum_amount: Uint128::new(0),
                 price_per_unit: Coin::new(Uint128::new(1), "denom".to_string()),
+                maximum_amount: None,
+                maximum_percentage: None,
             },
         )
         .unwrap();
 
-        let response = handle(deps.as_mut(), mock_env.clone(), sender.clone(), deposit)
-            .expect("Should not throw error");
+        let response = handle(
+            deps.as_mut(),
+            mock_env.clone(),
+            sender.clone(),
+            vec![],
+            deposit,
+        )
+        .expect("Should not throw error");
         assert_eq!(1, response.messages.len());
         assert_eq!(4, response.attributes.len());
         assert_eq!(
@@ -897,10 +1034,10 @@ mod tests {
 
     #[test]
     fn test_deposit_exceeds_commitment_throws_error_on_invalid_lp() {
-        let mut deps = mock_provenance_dependencies();
+        let deps = mock_provenance_dependencies();
         let lp = Addr::unchecked("bad address");
         let deposit = Vec::<SecurityCommitment>::new();
-        deposit_exceeds_commitment(&deps.as_mut(), lp, &deposit)
+        deposit_exceeds_commitment(&deps.as_ref(), lp, &deposit)
             .expect_err("should throw error on invalid lp");
     }
 
@@ -914,7 +1051,7 @@ mod tests {
         let commitment = Commitment::new(lp, settlement_tester.security_commitments.clone());
         commits::set(deps.as_mut().storage, &commitment).unwrap();
         let deposit = Vec::<SecurityCommitment>::new();
-        deposit_exceeds_commitment(&deps.as_mut(), commitment.lp, &deposit)
+        deposit_exceeds_commitment(&deps.as_ref(), commitment.lp, &deposit)
             .expect("should not throw an error");
     }
 
@@ -930,7 +1067,7 @@ mod tests {
 
         settlement_tester.create_security_commitments(1);
         let deposit = settlement_tester.security_commitments.clone();
-        let res = deposit_exceeds_commitment(&deps.as_mut(), commitment.lp, &deposit).unwrap();
+        let res = deposit_exceeds_commitment(&deps.as_ref(), commitment.lp, &deposit).unwrap();
         assert_eq!(true, res);
     }
 
@@ -946,7 +1083,7 @@ mod tests {
 
         let mut deposit = settlement_tester.security_commitments.clone();
         deposit[0].amount += Uint128::new(1);
-        let res = deposit_exceeds_commitment(&deps.as_mut(), commitment.lp, &deposit).unwrap();
+        let res = deposit_exceeds_commitment(&deps.as_ref(), commitment.lp, &deposit).unwrap();
         assert_eq!(true, res);
     }
 
@@ -969,7 +1106,7 @@ mod tests {
 
         let deposit = settlement_tester.security_commitments.clone();
         let res =
-            deposit_exceeds_commitment(&deps.as_mut(), commitment.lp.clone(), &deposit).unwrap();
+            deposit_exceeds_commitment(&deps.as_ref(), commitment.lp.clone(), &deposit).unwrap();
         assert_eq!(true, res);
     }
 
@@ -985,7 +1122,7 @@ mod tests {
 
         let deposit = settlement_tester.security_commitments.clone();
         let res =
-            deposit_exceeds_commitment(&deps.as_mut(), commitment.lp.clone(), &deposit).unwrap();
+            deposit_exceeds_commitment(&deps.as_ref(), commitment.lp.clone(), &deposit).unwrap();
         assert_eq!(false, res);
     }
 
@@ -1004,8 +1141,156 @@ mod tests {
 
         paid_in_capital::set(deps.as_mut().storage, commitment.lp.clone(), &small_deposit).unwrap();
 
-        let res = deposit_exceeds_commitment(&deps.as_mut(), commitment.lp.clone(), &small_deposit)
+        let res = deposit_exceeds_commitment(&deps.as_ref(), commitment.lp.clone(), &small_deposit)
             .unwrap();
         assert_eq!(false, res);
     }
+
+    #[test]
+    fn test_deposit_exceeds_capital_call_should_succeed_without_capital_call() {
+        let deps = mock_provenance_dependencies();
+        let mut settlement_tester = SettlementTester::new();
+        settlement_tester.create_security_commitments(1);
+        let lp = Addr::unchecked("lp");
+
+        let deposit = settlement_tester.security_commitments.clone();
+        let res = deposit_exceeds_capital_call(&deps.as_ref(), lp, &deposit);
+        assert_eq!(false, res);
+    }
+
+    #[test]
+    fn test_deposit_exceeds_capital_call_should_succeed_on_called_amount() {
+        let mut deps = mock_provenance_dependencies();
+        let mut settlement_tester = SettlementTester::new();
+        settlement_tester.create_security_commitments(1);
+        let lp = Addr::unchecked("lp");
+
+        let mut called = settlement_tester.security_commitments.clone();
+        called[0].amount = Uint128::new(5);
+        capital_calls::set(
+            deps.as_mut().storage,
+            lp.clone(),
+            &CapitalCall {
+                securities: called.clone(),
+                due_date: None,
+                released: false,
+            },
+        )
+        .unwrap();
+
+        let res = deposit_exceeds_capital_call(&deps.as_ref(), lp, &called);
+        assert_eq!(false, res);
+    }
+
+    #[test]
+    fn test_deposit_exceeds_capital_call_should_fail_on_additional_deposit_that_exceeds() {
+        let mut deps = mock_provenance_dependencies();
+        let mut settlement_tester = SettlementTester::new();
+        settlement_tester.create_security_commitments(1);
+        let lp = Addr::unchecked("lp");
+
+        let mut called = settlement_tester.security_commitments.clone();
+        called[0].amount = Uint128::new(5);
+        capital_calls::set(
+            deps.as_mut().storage,
+            lp.clone(),
+            &CapitalCall {
+                securities: called.clone(),
+                due_date: None,
+                released: false,
+            },
+        )
+        .unwrap();
+        let mut paid = called.clone();
+        paid[0].amount = Uint128::new(4);
+        paid_in_capital::set(deps.as_mut().storage, lp.clone(), &paid).unwrap();
+
+        let mut deposit = called.clone();
+        deposit[0].amount = Uint128::new(2);
+        let res = deposit_exceeds_capital_call(&deps.as_ref(), lp, &deposit);
+        assert_eq!(true, res);
+    }
+
+    #[test]
+    fn test_handle_should_throw_error_when_deposit_exceeds_capital_call() {
+        let mut deps = mock_provenance_dependencies();
+        let sender = Addr::unchecked("sender");
+        let mut settlement_tester = SettlementTester::new();
+        settlement_tester.setup_test_state(deps.as_mut().storage);
+        settlement_tester.create_security_commitments(1);
+
+        let mut commitment = Commitment::new(
+            sender.clone(),
+            settlement_tester.security_commitments.clone(),
+        );
+        commitment.state = CommitmentState::ACCEPTED;
+        commits::set(deps.as_mut().storage, &commitment).unwrap();
+
+        let mut called = settlement_tester.security_commitments.clone();
+        called[0].amount = Uint128::new(5);
+        capital_calls::set(
+            deps.as_mut().storage,
+            sender.clone(),
+            &CapitalCall {
+                securities: called,
+                due_date: None,
+                released: false,
+            },
+        )
+        .unwrap();
+
+        let error = handle(
+            deps.as_mut(),
+            mock_env(),
+            sender,
+            vec![],
+            settlement_tester.security_commitments.clone(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            crate::core::error::ContractError::DepositExceedsCapitalCall {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_should_throw_error_when_capital_call_is_overdue() {
+        let mut deps = mock_provenance_dependencies();
+        let sender = Addr::unchecked("sender");
+        let mut settlement_tester = SettlementTester::new();
+        settlement_tester.setup_test_state(deps.as_mut().storage);
+        settlement_tester.create_security_commitments(1);
+
+        let mut commitment = Commitment::new(
+            sender.clone(),
+            settlement_tester.security_commitments.clone(),
+        );
+        commitment.state = CommitmentState::ACCEPTED;
+        commits::set(deps.as_mut().storage, &commitment).unwrap();
+
+        let env = mock_env();
+        capital_calls::set(
+            deps.as_mut().storage,
+            sender.clone(),
+            &CapitalCall {
+                securities: settlement_tester.security_commitments.clone(),
+                due_date: Some(Uint64::new(env.block.time.seconds() - 1)),
+                released: false,
+            },
+        )
+        .unwrap();
+
+        let error = handle(
+            deps.as_mut(),
+            env,
+            sender,
+            vec![],
+            settlement_tester.security_commitments.clone(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            crate::core::error::ContractError::CapitalCallOverdue {}.to_string(),
+            error.to_string()
+        );
+    }
 }
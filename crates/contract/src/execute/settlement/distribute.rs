@@ -0,0 +1,243 @@
+use cosmwasm_std::{Addr, Coin, Env, Response, Uint128};
+
+use crate::{
+    core::{
+        aliases::{ProvDepsMut, ProvTxResponse},
+        event::{ContractEvent, EventType},
+    },
+    storage::{
+        distributions::{self},
+        securities::{self},
+    },
+    util::{
+        auth::{authorize, Role},
+        capital::receive_capital,
+    },
+};
+
+pub fn handle(
+    deps: ProvDepsMut,
+    env: Env,
+    sender: Addr,
+    funds: Vec<Coin>,
+    security: String,
+    amount: Uint128,
+) -> ProvTxResponse {
+    let state = authorize(deps.storage, &sender, Role::Gp)?;
+
+    // The distribution is paid into the contract through the deposit mode
+    let messages = receive_capital(
+        &state.deposit_mode,
+        &funds,
+        Coin::new(amount, &state.capital_denom),
+        &sender,
+        &env.contract.address,
+    )?;
+
+    securities::get(deps.storage, security.clone())?;
+    distributions::distribute(deps.storage, security.clone(), amount)?;
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_event(
+            ContractEvent::new(EventType::Distributed, &state.gp)
+                .amount(Coin::new(amount, state.capital_denom))
+                .attribute("security", security),
+        )
+        .add_attribute("action", "distribute")
+        .add_attribute("gp", sender))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{testing::mock_env, Addr, Attribute, Coin, Event, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        core::{aliases::ProvMsg, error::ContractError},
+        storage::{distributions, state::DepositMode},
+        util::{
+            provenance_utilities::transfer_marker_coins,
+            testing::{create_testing_commitments, instantiate_contract, set_deposit_mode},
+        },
+    };
+
+    use super::handle;
+
+    #[test]
+    fn test_handle_must_be_gp() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+
+        let error = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("lp1"),
+            vec![Coin::new(Uint128::new(100), "denom")],
+            "Security1".to_string(),
+            Uint128::new(100),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_funds_must_match_amount() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        set_deposit_mode(deps.as_mut().storage, DepositMode::Funds);
+
+        for funds in [
+            vec![Coin::new(Uint128::new(99), "denom")],
+            vec![Coin::new(Uint128::new(100), "otherdenom")],
+            vec![
+                Coin::new(Uint128::new(100), "denom"),
+                Coin::new(Uint128::new(100), "otherdenom"),
+            ],
+        ] {
+            let error = handle(
+                deps.as_mut(),
+                mock_env(),
+                Addr::unchecked("gp"),
+                funds,
+                "Security1".to_string(),
+                Uint128::new(100),
+            )
+            .unwrap_err();
+            assert_eq!(
+                ContractError::FundMismatch {}.to_string(),
+                error.to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn test_handle_fails_on_invalid_security() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        set_deposit_mode(deps.as_mut().storage, DepositMode::Funds);
+
+        handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            vec![Coin::new(Uint128::new(100), "denom")],
+            "Invalid".to_string(),
+            Uint128::new(100),
+        )
+        .unwrap_err();
+    }
+
+    #[test]
+    fn test_handle_fails_without_holders() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        set_deposit_mode(deps.as_mut().storage, DepositMode::Funds);
+
+        let error = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            vec![Coin::new(Uint128::new(100), "denom")],
+            "Security1".to_string(),
+            Uint128::new(100),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::NoDistributionHolders {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_distributes_to_settled_lps() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        set_deposit_mode(deps.as_mut().storage, DepositMode::Funds);
+
+        let res = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            vec![Coin::new(Uint128::new(500), "denom")],
+            "Security1".to_string(),
+            Uint128::new(500),
+        )
+        .unwrap();
+        assert_eq!(
+            vec![
+                Attribute::new("action", "distribute"),
+                Attribute::new("gp", "gp")
+            ],
+            res.attributes
+        );
+        assert_eq!(
+            vec![Event::new("distributed")
+                .add_attribute("gp", "gp")
+                .add_attribute("amount", "500denom")
+                .add_attribute("security", "Security1")],
+            res.events
+        );
+
+        // lp7 is the only settled lp
+        assert_eq!(
+            Uint128::new(500),
+            distributions::claimable(&deps.storage, Addr::unchecked("lp7")).unwrap()
+        );
+        assert_eq!(
+            Uint128::zero(),
+            distributions::claimable(&deps.storage, Addr::unchecked("lp1")).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_handle_pulls_marker_capital() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        let env = mock_env();
+        let gp = Addr::unchecked("gp");
+
+        let error = handle(
+            deps.as_mut(),
+            env.clone(),
+            gp.clone(),
+            vec![Coin::new(Uint128::new(500), "denom")],
+            "Security1".to_string(),
+            Uint128::new(500),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::UnexpectedFunds {}.to_string(),
+            error.to_string()
+        );
+
+        let res = handle(
+            deps.as_mut(),
+            env.clone(),
+            gp.clone(),
+            vec![],
+            "Security1".to_string(),
+            Uint128::new(500),
+        )
+        .unwrap();
+        let expected: ProvMsg = transfer_marker_coins(
+            500,
+            "denom",
+            env.contract.address.clone(),
+            gp,
+            env.contract.address,
+        )
+        .unwrap();
+        assert_eq!(1, res.messages.len());
+        assert_eq!(expected, res.messages[0].msg);
+        assert_eq!(
+            Uint128::new(500),
+            distributions::claimable(&deps.storage, Addr::unchecked("lp7")).unwrap()
+        );
+    }
+}
@@ -0,0 +1,387 @@
+use cosmwasm_std::{Addr, Coin, Env, Response, Storage, Uint128};
+
+use crate::{
+    core::{
+        aliases::{ProvDepsMut, ProvTxResponse},
+        error::ContractError,
+        event::{ContractEvent, EventType},
+        security::{Security, SecurityType},
+    },
+    storage::{
+        distributions::{self},
+        nav::{self},
+        securities::{self},
+    },
+    util::{
+        auth::{authorize, Role},
+        capital::receive_capital,
+    },
+};
+
+pub fn handle(
+    deps: ProvDepsMut,
+    env: Env,
+    sender: Addr,
+    funds: Vec<Coin>,
+    amount: Uint128,
+) -> ProvTxResponse {
+    let state = authorize(deps.storage, &sender, Role::Gp)?;
+
+    // The distribution is paid into the contract through the deposit mode
+    let messages = receive_capital(
+        &state.deposit_mode,
+        &funds,
+        Coin::new(amount, &state.capital_denom),
+        &sender,
+        &env.contract.address,
+    )?;
+
+    let payments = waterfall(deps.storage, amount)?;
+    let mut response = Response::new()
+        .add_messages(messages)
+        .add_attribute("action", "distribute_waterfall")
+        .add_attribute("gp", sender);
+    for (security, payment) in payments {
+        distributions::distribute(deps.storage, security.clone(), payment)?;
+        response = response.add_event(
+            ContractEvent::new(EventType::Distributed, &state.gp)
+                .amount(Coin::new(payment, state.capital_denom.clone()))
+                .attribute("security", security),
+        );
+    }
+
+    Ok(response)
+}
+
+// Pays each tranche up to its target in order of seniority. Whatever remains once every
+// tranche has reached its target goes to the most junior tranche.
+fn waterfall(
+    storage: &dyn Storage,
+    amount: Uint128,
+) -> Result<Vec<(String, Uint128)>, ContractError> {
+    let mut tranches = vec![];
+    for name in securities::get_security_types(storage) {
+        let security = securities::get(storage, name)?;
+        if let SecurityType::Tranche(tranche) = &security.security_type {
+            tranches.push((tranche.seniority, security));
+        }
+    }
+    tranches.sort_by_key(|(seniority, _)| *seniority);
+
+    let mut remaining = amount;
+    let mut payments: Vec<(String, Uint128)> = vec![];
+    for (_, security) in &tranches {
+        let index = distributions::get_index(storage, security.name.clone());
+        if index.total_units.is_zero() {
+            continue;
+        }
+
+        let payment = remaining
+            .min(target(storage, security, index.total_units)?.saturating_sub(index.distributed));
+        remaining -= payment;
+        payments.push((security.name.clone(), payment));
+    }
+
+    match payments.last_mut() {
+        None => return Err(ContractError::NoDistributionHolders {}),
+        Some((_, payment)) => *payment += remaining,
+    }
+
+    Ok(payments
+        .into_iter()
+        .filter(|(_, payment)| !payment.is_zero())
+        .collect())
+}
+
+// The principal owed to the tranche's holders, limited by the principal cap, along with its target return
+fn target(
+    storage: &dyn Storage,
+    security: &Security,
+    units: Uint128,
+) -> Result<Uint128, ContractError> {
+    let tranche = match &security.security_type {
+        SecurityType::Tranche(tranche) => tranche,
+        _ => return Err(ContractError::InvalidSecurityList {}),
+    };
+
+    let mut principal = units * nav::current_price(storage, security)?.amount;
+    if let Some(cap) = tranche.principal_cap {
+        principal = principal.min(cap);
+    }
+    Ok(principal + principal.mul_floor(tranche.target_return))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{testing::mock_env, Addr, Attribute, Coin, Decimal, Event, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        core::{
+            aliases::ProvMsg,
+            error::ContractError,
+            security::{Security, SecurityType, TrancheSecurity},
+        },
+        storage::{distributions, securities, state::DepositMode},
+        util::{
+            provenance_utilities::transfer_marker_coins,
+            testing::{set_deposit_mode, MockDeps},
+        },
+    };
+
+    use super::{handle, target, waterfall};
+
+    fn tranche(name: &str, seniority: u32, principal_cap: Option<Uint128>) -> Security {
+        Security {
+            name: name.to_string(),
+            amount: Uint128::new(1000),
+            security_type: SecurityType::Tranche(TrancheSecurity {
+                seniority,
+                target_return: Decimal::percent(10),
+                principal_cap,
+            }),
+            minimum_amount: Uint128::new(1),
+            price_per_unit: Coin::new(Uint128::new(10), "denom"),
+            maximum_amount: None,
+            maximum_percentage: None,
+        }
+    }
+
+    // Senior has 10 units with a target of 110, and junior has 20 units with a target of 220
+    fn setup_tranches(deps: &mut MockDeps) {
+        let settlement_tester = crate::util::testing::SettlementTester::new();
+        settlement_tester.setup_test_state(deps.as_mut().storage);
+        for (security, units) in [
+            (tranche("Junior", 1, None), 20),
+            (tranche("Senior", 0, None), 10),
+            (tranche("Unsold", 2, None), 0),
+        ] {
+            securities::set(deps.as_mut().storage, &security).unwrap();
+            if units > 0 {
+                distributions::add_units(
+                    deps.as_mut().storage,
+                    Addr::unchecked("lp"),
+                    security.name,
+                    Uint128::new(units),
+                )
+                .unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn test_target_includes_return() {
+        let deps = mock_provenance_dependencies();
+        assert_eq!(
+            Uint128::new(110),
+            target(&deps.storage, &tranche("Senior", 0, None), Uint128::new(10)).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_target_respects_principal_cap() {
+        let deps = mock_provenance_dependencies();
+        assert_eq!(
+            Uint128::new(55),
+            target(
+                &deps.storage,
+                &tranche("Senior", 0, Some(Uint128::new(50))),
+                Uint128::new(10)
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_waterfall_pays_senior_first() {
+        let mut deps = mock_provenance_dependencies();
+        setup_tranches(&mut deps);
+
+        assert_eq!(
+            vec![("Senior".to_string(), Uint128::new(100))],
+            waterfall(&deps.storage, Uint128::new(100)).unwrap()
+        );
+        assert_eq!(
+            vec![
+                ("Senior".to_string(), Uint128::new(110)),
+                ("Junior".to_string(), Uint128::new(40))
+            ],
+            waterfall(&deps.storage, Uint128::new(150)).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_waterfall_pays_residual_to_most_junior() {
+        let mut deps = mock_provenance_dependencies();
+        setup_tranches(&mut deps);
+
+        assert_eq!(
+            vec![
+                ("Senior".to_string(), Uint128::new(110)),
+                ("Junior".to_string(), Uint128::new(290))
+            ],
+            waterfall(&deps.storage, Uint128::new(400)).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_waterfall_accounts_for_previous_distributions() {
+        let mut deps = mock_provenance_dependencies();
+        setup_tranches(&mut deps);
+        distributions::distribute(
+            deps.as_mut().storage,
+            "Senior".to_string(),
+            Uint128::new(100),
+        )
+        .unwrap();
+
+        assert_eq!(
+            vec![
+                ("Senior".to_string(), Uint128::new(10)),
+                ("Junior".to_string(), Uint128::new(40))
+            ],
+            waterfall(&deps.storage, Uint128::new(50)).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_waterfall_fails_without_holders() {
+        let mut deps = mock_provenance_dependencies();
+        let settlement_tester = crate::util::testing::SettlementTester::new();
+        settlement_tester.setup_test_state(deps.as_mut().storage);
+        securities::set(deps.as_mut().storage, &tranche("Senior", 0, None)).unwrap();
+
+        let error = waterfall(&deps.storage, Uint128::new(100)).unwrap_err();
+        assert_eq!(
+            ContractError::NoDistributionHolders {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_must_be_gp() {
+        let mut deps = mock_provenance_dependencies();
+        setup_tranches(&mut deps);
+
+        let error = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("lp"),
+            vec![Coin::new(Uint128::new(100), "denom")],
+            Uint128::new(100),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_funds_must_match_amount() {
+        let mut deps = mock_provenance_dependencies();
+        setup_tranches(&mut deps);
+        set_deposit_mode(deps.as_mut().storage, DepositMode::Funds);
+
+        let error = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            vec![Coin::new(Uint128::new(99), "denom")],
+            Uint128::new(100),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::FundMismatch {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_distributes_through_waterfall() {
+        let mut deps = mock_provenance_dependencies();
+        setup_tranches(&mut deps);
+        set_deposit_mode(deps.as_mut().storage, DepositMode::Funds);
+
+        let res = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            vec![Coin::new(Uint128::new(150), "denom")],
+            Uint128::new(150),
+        )
+        .unwrap();
+        assert_eq!(
+            vec![
+                Attribute::new("action", "distribute_waterfall"),
+                Attribute::new("gp", "gp")
+            ],
+            res.attributes
+        );
+        assert_eq!(
+            vec![
+                Event::new("distributed")
+                    .add_attribute("gp", "gp")
+                    .add_attribute("amount", "110denom")
+                    .add_attribute("security", "Senior"),
+                Event::new("distributed")
+                    .add_attribute("gp", "gp")
+                    .add_attribute("amount", "40denom")
+                    .add_attribute("security", "Junior")
+            ],
+            res.events
+        );
+        assert_eq!(
+            Uint128::new(110),
+            distributions::get_index(&deps.storage, "Senior".to_string()).distributed
+        );
+        assert_eq!(
+            Uint128::new(150),
+            distributions::claimable(&deps.storage, Addr::unchecked("lp")).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_handle_pulls_marker_capital() {
+        let mut deps = mock_provenance_dependencies();
+        setup_tranches(&mut deps);
+        let env = mock_env();
+        let gp = Addr::unchecked("gp");
+
+        let error = handle(
+            deps.as_mut(),
+            env.clone(),
+            gp.clone(),
+            vec![Coin::new(Uint128::new(150), "denom")],
+            Uint128::new(150),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::UnexpectedFunds {}.to_string(),
+            error.to_string()
+        );
+
+        let res = handle(
+            deps.as_mut(),
+            env.clone(),
+            gp.clone(),
+            vec![],
+            Uint128::new(150),
+        )
+        .unwrap();
+        let expected: ProvMsg = transfer_marker_coins(
+            150,
+            "denom",
+            env.contract.address.clone(),
+            gp,
+            env.contract.address,
+        )
+        .unwrap();
+        assert_eq!(1, res.messages.len());
+        assert_eq!(expected, res.messages[0].msg);
+        assert_eq!(
+            Uint128::new(150),
+            distributions::claimable(&deps.storage, Addr::unchecked("lp")).unwrap()
+        );
+    }
+}
@@ -0,0 +1,281 @@
+use cosmwasm_std::{Addr, Env, Response};
+
+use crate::{
+    core::{
+        aliases::{ProvDepsMut, ProvTxResponse},
+        error::ContractError,
+        event::{ContractEvent, EventType},
+    },
+    storage::{
+        capital_calls, commitment_history, commits, paid_in_capital,
+        state::{self, OfferingStatus},
+    },
+    util::{
+        self,
+        auth::{authorize, Role},
+    },
+};
+
+use super::{cancel_commitment::refund_lp, commitment::CommitmentState, withdraw_commitment};
+
+// Ends a closed offering. Accepted commitments that have been fully paid are settled, and lps that
+// have answered a capital call are settled for the capital they paid in. Every other commitment that
+// is still in flight is canceled and its deposited capital refunded.
+pub fn handle(mut deps: ProvDepsMut, env: Env, sender: Addr) -> ProvTxResponse {
+    let mut state = authorize(deps.storage, &sender, Role::Gp)?;
+
+    if state.status != OfferingStatus::Closed {
+        return Err(ContractError::InvalidOfferingStatus {});
+    }
+
+    if !util::settlement::minimum_raise_met(deps.storage, &state.minimum_raise)? {
+        return Err(ContractError::MinimumRaiseNotMet {});
+    }
+
+    let mut response = Response::new();
+    for commitment in commits::get_with_state(deps.storage, CommitmentState::ACCEPTED) {
+        if util::settlement::is_settling(deps.storage, &commitment)
+            && !util::settlement::is_expired(&env, &commitment)
+        {
+            let withdraw = withdraw_commitment::withdraw_commitment(
+                &mut deps,
+                &env,
+                &sender,
+                sender.clone(),
+                commitment.lp,
+            )?;
+            response = response
+                .add_submessages(withdraw.messages)
+                .add_events(withdraw.events);
+        } else if capital_calls::has_lp(deps.storage, commitment.lp.clone())
+            && paid_in_capital::get(deps.storage, commitment.lp.clone())
+                .iter()
+                .any(|security| !security.amount.is_zero())
+        {
+            // Called capital may already have been released to the GP, so it can't be refunded
+            let settle = withdraw_commitment::settle_paid_in_capital(
+                &mut deps,
+                &env,
+                &sender,
+                sender.clone(),
+                commitment.lp,
+            )?;
+            response = response
+                .add_submessages(settle.messages)
+                .add_events(settle.events);
+        }
+    }
+
+    let mut unsettled = commits::get_with_state(deps.storage, CommitmentState::PENDING);
+    unsettled.extend(commits::get_with_state(
+        deps.storage,
+        CommitmentState::ACCEPTED,
+    ));
+    for mut commitment in unsettled {
+        let (refund_messages, refunded) = refund_lp(
+            deps.storage,
+            &state.deposit_mode,
+            &env.contract.address,
+            commitment.lp.clone(),
+        )?;
+        commitment.state = match util::settlement::is_expired(&env, &commitment) {
+            true => CommitmentState::EXPIRED,
+            false => CommitmentState::CANCELED,
+        };
+        commitment.reason = Some("offering finalized".to_string());
+        commits::set(deps.storage, &commitment)?;
+        commitment_history::record(
+            deps.storage,
+            &env,
+            &commitment.lp,
+            &sender,
+            "canceled",
+            &commitment.commitments,
+        )?;
+
+        let mut event = ContractEvent::new(EventType::Canceled, &state.gp)
+            .lp(&commitment.lp)
+            .securities(&commitment.commitments)
+            .state(&commitment.state);
+        if let Some(refunded) = refunded {
+            event = event.amount(refunded);
+        }
+        response = response
+            .add_messages(refund_messages)
+            .add_event(event.attribute("reason", "offering finalized"));
+    }
+
+    state.status = OfferingStatus::Finalized;
+    state::set(deps.storage, &state)?;
+
+    Ok(response
+        .add_event(ContractEvent::new(EventType::OfferingFinalized, &state.gp))
+        .add_attribute("action", "finalize_offering")
+        .add_attribute("gp", sender))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{testing::mock_env, Addr, Attribute, Decimal, Event, SubMsg, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        core::{error::ContractError, security::SecurityCommitment},
+        execute::settlement::commitment::CommitmentState,
+        storage::{
+            available_capital, commits, distributions, remaining_securities,
+            state::{self, OfferingStatus},
+        },
+        util::{
+            provenance_utilities::transfer_marker_coins,
+            testing::{
+                close_offering_test, create_testing_commitments, deposit_test,
+                instantiate_contract, issue_capital_call_test, withdraw_test,
+            },
+        },
+    };
+
+    use super::handle;
+
+    #[test]
+    fn test_handle_must_be_gp() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        close_offering_test(deps.as_mut(), mock_env(), "gp").unwrap();
+
+        let error = handle(deps.as_mut(), mock_env(), Addr::unchecked("lp1")).unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_offering_must_be_closed() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+
+        let error = handle(deps.as_mut(), mock_env(), Addr::unchecked("gp")).unwrap_err();
+        assert_eq!(
+            ContractError::InvalidOfferingStatus {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_settles_and_refunds_commitments() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        close_offering_test(deps.as_mut(), mock_env(), "gp").unwrap();
+
+        let res = handle(deps.as_mut(), mock_env(), Addr::unchecked("gp")).unwrap();
+        assert_eq!(
+            vec![
+                Attribute::new("action", "finalize_offering"),
+                Attribute::new("gp", "gp")
+            ],
+            res.attributes
+        );
+        assert!(res.events.contains(
+            &Event::new("settled")
+                .add_attribute("gp", "gp")
+                .add_attribute("lp", "lp1")
+                .add_attribute("securities", "Security1:100,Security2:100")
+                .add_attribute("amount", "20000denom")
+                .add_attribute("state", "settled")
+        ));
+        assert!(res.events.contains(
+            &Event::new("canceled")
+                .add_attribute("gp", "gp")
+                .add_attribute("lp", "lp2")
+                .add_attribute("securities", "Security1:100,Security2:100")
+                .add_attribute("amount", "10000denom")
+                .add_attribute("state", "canceled")
+                .add_attribute("reason", "offering finalized")
+        ));
+        assert_eq!(
+            Some(&Event::new("offering_finalized").add_attribute("gp", "gp")),
+            res.events.last()
+        );
+        let contract = mock_env().contract.address;
+        assert!(res.messages.contains(&SubMsg::new(
+            transfer_marker_coins(
+                10000,
+                "denom",
+                Addr::unchecked("lp2"),
+                contract.clone(),
+                contract
+            )
+            .unwrap()
+        )));
+
+        assert_eq!(
+            CommitmentState::SETTLED,
+            commits::get(&deps.storage, Addr::unchecked("lp1"))
+                .unwrap()
+                .state
+        );
+        for lp in ["lp2", "lp3", "lp4", "lp5", "lp6"] {
+            let commitment = commits::get(&deps.storage, Addr::unchecked(lp)).unwrap();
+            assert_eq!(CommitmentState::CANCELED, commitment.state);
+            assert_eq!(Some("offering finalized".to_string()), commitment.reason);
+        }
+        assert!(!available_capital::has_lp(
+            &deps.storage,
+            Addr::unchecked("lp2")
+        ));
+        assert_eq!(
+            OfferingStatus::Finalized,
+            state::get(&deps.storage).unwrap().status
+        );
+    }
+
+    #[test]
+    fn test_handle_settles_called_capital_pro_rata() {
+        let mut deps = mock_provenance_dependencies();
+        let lp = Addr::unchecked("lp3");
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        issue_capital_call_test(deps.as_mut(), mock_env(), "gp", Decimal::percent(25)).unwrap();
+        let called = vec![
+            SecurityCommitment {
+                name: "Security1".to_string(),
+                amount: Uint128::new(25),
+            },
+            SecurityCommitment {
+                name: "Security2".to_string(),
+                amount: Uint128::new(25),
+            },
+        ];
+        deposit_test(deps.as_mut(), mock_env(), lp.as_str(), &called).unwrap();
+        withdraw_test(deps.as_mut(), mock_env(), "gp", lp.as_str()).unwrap();
+        let remaining = remaining_securities::get(&deps.storage, "Security1".to_string()).unwrap();
+        close_offering_test(deps.as_mut(), mock_env(), "gp").unwrap();
+
+        let res = handle(deps.as_mut(), mock_env(), Addr::unchecked("gp")).unwrap();
+        assert!(res.events.contains(
+            &Event::new("settled")
+                .add_attribute("gp", "gp")
+                .add_attribute("lp", lp.clone())
+                .add_attribute("securities", "Security1:25,Security2:25")
+                .add_attribute("state", "settled")
+        ));
+
+        // The capital was already released to the GP, so lp3 keeps the units they paid for
+        let commitment = commits::get(&deps.storage, lp.clone()).unwrap();
+        assert_eq!(CommitmentState::SETTLED, commitment.state);
+        assert_eq!(called, commitment.commitments);
+        assert_eq!(
+            Uint128::new(25),
+            distributions::get_holding(&deps.storage, lp, "Security1".to_string())
+                .unwrap()
+                .units
+        );
+        // The 75 units lp3 never paid for, and the 50 units lp2 never paid for, are returned
+        assert_eq!(
+            remaining + 75 + 50,
+            remaining_securities::get(&deps.storage, "Security1".to_string()).unwrap()
+        );
+    }
+}
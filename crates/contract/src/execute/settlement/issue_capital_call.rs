@@ -0,0 +1,487 @@
+use cosmwasm_std::{Addr, Decimal, Env, Response, Storage, Uint128, Uint64};
+
+use crate::{
+    core::{
+        aliases::{ProvDepsMut, ProvTxResponse},
+        error::ContractError,
+        event::{ContractEvent, EventType},
+        security::{AcceptedCommitment, CapitalCallAmount, SecurityCommitment},
+    },
+    storage::{
+        capital_calls::{self, CapitalCall},
+        commitment_history,
+        commits::{self},
+    },
+    util::{
+        auth::{authorize, Role},
+        settlement::timestamp_is_expired,
+    },
+};
+
+use super::commitment::{Commitment, CommitmentState};
+
+pub fn handle(
+    deps: ProvDepsMut,
+    env: Env,
+    sender: Addr,
+    call: CapitalCallAmount,
+    due_date: Option<Uint64>,
+) -> ProvTxResponse {
+    let state = authorize(deps.storage, &sender, Role::Gp)?;
+
+    if timestamp_is_expired(deps.storage, &env.block.time)? {
+        return Err(ContractError::SettlmentExpired {});
+    }
+
+    let called_commitments = match call {
+        CapitalCallAmount::Percentage(percentage) => {
+            commits::get_with_state(deps.storage, CommitmentState::ACCEPTED)
+                .into_iter()
+                .filter_map(|commitment| call_percentage(deps.storage, commitment, percentage))
+                .collect()
+        }
+        CapitalCallAmount::Commitments(commitments) => commitments,
+    };
+
+    let mut response = Response::new()
+        .add_attribute("action", "issue_capital_call")
+        .add_attribute("gp", state.gp.clone());
+    for called_commitment in called_commitments {
+        call_capital(deps.storage, &called_commitment, due_date)?;
+        commitment_history::record(
+            deps.storage,
+            &env,
+            &called_commitment.lp,
+            &sender,
+            "capital_called",
+            &called_commitment.securities,
+        )?;
+        response = response.add_event(
+            ContractEvent::new(EventType::CapitalCalled, &state.gp)
+                .lp(&called_commitment.lp)
+                .securities(&called_commitment.securities)
+                .state(&CommitmentState::ACCEPTED),
+        );
+    }
+
+    Ok(response)
+}
+
+// Calls the percentage of each security, but never more than the lp has left uncalled. Lps that
+// have already been called in full are skipped.
+fn call_percentage(
+    storage: &dyn Storage,
+    commitment: Commitment,
+    percentage: Decimal,
+) -> Option<AcceptedCommitment> {
+    let capital_call = capital_calls::get(storage, commitment.lp.clone());
+    let securities: Vec<SecurityCommitment> = commitment
+        .commitments
+        .into_iter()
+        .map(|security| {
+            let called = capital_call
+                .as_ref()
+                .and_then(|capital_call| {
+                    capital_call
+                        .securities
+                        .iter()
+                        .find(|called| called.name == security.name)
+                })
+                .map_or(Uint128::zero(), |called| called.amount);
+            SecurityCommitment {
+                amount: security
+                    .amount
+                    .mul_floor(percentage)
+                    .min(security.amount.saturating_sub(called)),
+                name: security.name,
+            }
+        })
+        .collect();
+
+    if securities.iter().all(|security| security.amount.is_zero()) {
+        return None;
+    }
+    Some(AcceptedCommitment {
+        lp: commitment.lp,
+        securities,
+    })
+}
+
+fn call_capital(
+    storage: &mut dyn Storage,
+    called_commitment: &AcceptedCommitment,
+    due_date: Option<Uint64>,
+) -> Result<(), ContractError> {
+    let commitment = commits::get(storage, called_commitment.lp.clone())?;
+    if commitment.state != CommitmentState::ACCEPTED {
+        return Err(ContractError::InvalidCommitmentState {});
+    }
+
+    let mut capital_call = capital_calls::get(storage, commitment.lp.clone())
+        .unwrap_or_else(|| CapitalCall::new(&commitment));
+    add_called_capital(
+        &commitment,
+        &mut capital_call,
+        &called_commitment.securities,
+    )?;
+    capital_call.due_date = due_date;
+
+    capital_calls::set(storage, commitment.lp, &capital_call)
+}
+
+fn add_called_capital(
+    commitment: &Commitment,
+    capital_call: &mut CapitalCall,
+    called_securities: &[SecurityCommitment],
+) -> Result<(), ContractError> {
+    for called_security in called_securities {
+        let committed = commitment
+            .commitments
+            .iter()
+            .find(|security| security.name == called_security.name)
+            .ok_or(ContractError::InvalidSecurityCommitment {})?;
+        let called = capital_call
+            .securities
+            .iter_mut()
+            .find(|security| security.name == called_security.name)
+            .ok_or(ContractError::InvalidSecurityCommitment {})?;
+
+        called.amount += called_security.amount;
+        if called.amount > committed.amount {
+            return Err(ContractError::CapitalCallExceedsCommitment {});
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{testing::mock_env, Addr, Attribute, Decimal, Event, Uint128, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        core::{
+            error::ContractError,
+            security::{AcceptedCommitment, CapitalCallAmount, SecurityCommitment},
+        },
+        execute::settlement::commitment::{Commitment, CommitmentState},
+        storage::{capital_calls, commits},
+        util::testing::{create_test_state, SettlementTester},
+    };
+
+    use super::{call_capital, handle};
+
+    fn accepted_commitment(lp: &str, settlement_tester: &SettlementTester) -> Commitment {
+        let mut commitment = Commitment::new(
+            Addr::unchecked(lp),
+            settlement_tester.security_commitments.clone(),
+        );
+        commitment.state = CommitmentState::ACCEPTED;
+        commitment
+    }
+
+    #[test]
+    fn test_handle_must_be_triggered_by_gp() {
+        let mut deps = mock_provenance_dependencies();
+        let env = mock_env();
+        create_test_state(&mut deps, &env, false);
+
+        let error = handle(
+            deps.as_mut(),
+            env,
+            Addr::unchecked("lp1"),
+            CapitalCallAmount::Percentage(Decimal::percent(50)),
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_fails_when_settlement_expired() {
+        let mut deps = mock_provenance_dependencies();
+        let mut env = mock_env();
+        create_test_state(&mut deps, &env, true);
+        env.block.time = env.block.time.plus_seconds(86401);
+
+        let error = handle(
+            deps.as_mut(),
+            env,
+            Addr::unchecked("gp"),
+            CapitalCallAmount::Percentage(Decimal::percent(50)),
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::SettlmentExpired {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_percentage_calls_every_accepted_commitment() {
+        let mut deps = mock_provenance_dependencies();
+        let env = mock_env();
+        let mut settlement_tester = SettlementTester::new();
+        settlement_tester.create_security_commitments(2);
+        create_test_state(&mut deps, &env, false);
+        commits::set(
+            deps.as_mut().storage,
+            &accepted_commitment("lp1", &settlement_tester),
+        )
+        .unwrap();
+        commits::set(
+            deps.as_mut().storage,
+            &accepted_commitment("lp2", &settlement_tester),
+        )
+        .unwrap();
+        commits::set(
+            deps.as_mut().storage,
+            &Commitment::new(
+                Addr::unchecked("lp3"),
+                settlement_tester.security_commitments.clone(),
+            ),
+        )
+        .unwrap();
+
+        let res = handle(
+            deps.as_mut(),
+            env,
+            Addr::unchecked("gp"),
+            CapitalCallAmount::Percentage(Decimal::percent(50)),
+            Some(Uint64::new(100)),
+        )
+        .unwrap();
+        assert_eq!(
+            vec![
+                Attribute::new("action", "issue_capital_call"),
+                Attribute::new("gp", "gp")
+            ],
+            res.attributes
+        );
+        assert_eq!(2, res.events.len());
+        assert_eq!(
+            Event::new("capital_called")
+                .add_attribute("gp", "gp")
+                .add_attribute("lp", "lp1")
+                .add_attribute("securities", "Security1:5,Security2:6")
+                .add_attribute("state", "accepted"),
+            res.events[0]
+        );
+        assert_eq!(Attribute::new("lp", "lp2"), res.events[1].attributes[1]);
+
+        let capital_call = capital_calls::get(&deps.storage, Addr::unchecked("lp1")).unwrap();
+        assert_eq!(Some(Uint64::new(100)), capital_call.due_date);
+        assert_eq!(Uint128::new(5), capital_call.securities[0].amount);
+        assert_eq!(Uint128::new(6), capital_call.securities[1].amount);
+        assert_eq!(
+            None,
+            capital_calls::get(&deps.storage, Addr::unchecked("lp3"))
+        );
+    }
+
+    #[test]
+    fn test_handle_calls_are_cumulative() {
+        let mut deps = mock_provenance_dependencies();
+        let env = mock_env();
+        let mut settlement_tester = SettlementTester::new();
+        settlement_tester.create_security_commitments(1);
+        create_test_state(&mut deps, &env, false);
+        commits::set(
+            deps.as_mut().storage,
+            &accepted_commitment("lp1", &settlement_tester),
+        )
+        .unwrap();
+
+        for _ in 0..2 {
+            handle(
+                deps.as_mut(),
+                env.clone(),
+                Addr::unchecked("gp"),
+                CapitalCallAmount::Percentage(Decimal::percent(50)),
+                None,
+            )
+            .unwrap();
+        }
+
+        let capital_call = capital_calls::get(&deps.storage, Addr::unchecked("lp1")).unwrap();
+        assert_eq!(Uint128::new(10), capital_call.securities[0].amount);
+    }
+
+    #[test]
+    fn test_handle_percentage_is_limited_to_uncalled_capital() {
+        let mut deps = mock_provenance_dependencies();
+        let env = mock_env();
+        let mut settlement_tester = SettlementTester::new();
+        settlement_tester.create_security_commitments(1);
+        create_test_state(&mut deps, &env, false);
+        commits::set(
+            deps.as_mut().storage,
+            &accepted_commitment("lp1", &settlement_tester),
+        )
+        .unwrap();
+
+        for percentage in [60, 60] {
+            handle(
+                deps.as_mut(),
+                env.clone(),
+                Addr::unchecked("gp"),
+                CapitalCallAmount::Percentage(Decimal::percent(percentage)),
+                None,
+            )
+            .unwrap();
+        }
+
+        let capital_call = capital_calls::get(&deps.storage, Addr::unchecked("lp1")).unwrap();
+        assert_eq!(
+            settlement_tester.security_commitments[0].amount,
+            capital_call.securities[0].amount
+        );
+    }
+
+    #[test]
+    fn test_handle_percentage_skips_commitments_that_are_fully_called() {
+        let mut deps = mock_provenance_dependencies();
+        let env = mock_env();
+        let mut settlement_tester = SettlementTester::new();
+        settlement_tester.create_security_commitments(1);
+        create_test_state(&mut deps, &env, false);
+        commits::set(
+            deps.as_mut().storage,
+            &accepted_commitment("lp1", &settlement_tester),
+        )
+        .unwrap();
+        handle(
+            deps.as_mut(),
+            env.clone(),
+            Addr::unchecked("gp"),
+            CapitalCallAmount::Percentage(Decimal::percent(100)),
+            None,
+        )
+        .unwrap();
+        commits::set(
+            deps.as_mut().storage,
+            &accepted_commitment("lp2", &settlement_tester),
+        )
+        .unwrap();
+
+        let res = handle(
+            deps.as_mut(),
+            env,
+            Addr::unchecked("gp"),
+            CapitalCallAmount::Percentage(Decimal::percent(50)),
+            None,
+        )
+        .unwrap();
+        assert_eq!(1, res.events.len());
+        assert_eq!(Attribute::new("lp", "lp2"), res.events[0].attributes[1]);
+        assert_eq!(
+            settlement_tester.security_commitments[0].amount,
+            capital_calls::get(&deps.storage, Addr::unchecked("lp1"))
+                .unwrap()
+                .securities[0]
+                .amount
+        );
+    }
+
+    #[test]
+    fn test_call_capital_with_explicit_amounts() {
+        let mut deps = mock_provenance_dependencies();
+        let mut settlement_tester = SettlementTester::new();
+        settlement_tester.create_security_commitments(2);
+        commits::set(
+            deps.as_mut().storage,
+            &accepted_commitment("lp1", &settlement_tester),
+        )
+        .unwrap();
+
+        let called_commitment = AcceptedCommitment {
+            lp: Addr::unchecked("lp1"),
+            securities: vec![SecurityCommitment {
+                name: settlement_tester.security_commitments[1].name.clone(),
+                amount: Uint128::new(7),
+            }],
+        };
+        call_capital(deps.as_mut().storage, &called_commitment, None).unwrap();
+
+        let capital_call = capital_calls::get(&deps.storage, Addr::unchecked("lp1")).unwrap();
+        assert_eq!(Uint128::zero(), capital_call.securities[0].amount);
+        assert_eq!(Uint128::new(7), capital_call.securities[1].amount);
+    }
+
+    #[test]
+    fn test_call_capital_fails_on_unknown_security() {
+        let mut deps = mock_provenance_dependencies();
+        let mut settlement_tester = SettlementTester::new();
+        settlement_tester.create_security_commitments(1);
+        commits::set(
+            deps.as_mut().storage,
+            &accepted_commitment("lp1", &settlement_tester),
+        )
+        .unwrap();
+
+        let called_commitment = AcceptedCommitment {
+            lp: Addr::unchecked("lp1"),
+            securities: vec![SecurityCommitment {
+                name: "Invalid".to_string(),
+                amount: Uint128::new(1),
+            }],
+        };
+        let error = call_capital(deps.as_mut().storage, &called_commitment, None).unwrap_err();
+        assert_eq!(
+            ContractError::InvalidSecurityCommitment {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_call_capital_fails_when_exceeding_commitment() {
+        let mut deps = mock_provenance_dependencies();
+        let mut settlement_tester = SettlementTester::new();
+        settlement_tester.create_security_commitments(1);
+        commits::set(
+            deps.as_mut().storage,
+            &accepted_commitment("lp1", &settlement_tester),
+        )
+        .unwrap();
+
+        let mut called_commitment = AcceptedCommitment {
+            lp: Addr::unchecked("lp1"),
+            securities: settlement_tester.security_commitments.clone(),
+        };
+        called_commitment.securities[0].amount += Uint128::one();
+        let error = call_capital(deps.as_mut().storage, &called_commitment, None).unwrap_err();
+        assert_eq!(
+            ContractError::CapitalCallExceedsCommitment {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_call_capital_fails_when_not_accepted() {
+        let mut deps = mock_provenance_dependencies();
+        let mut settlement_tester = SettlementTester::new();
+        settlement_tester.create_security_commitments(1);
+        commits::set(
+            deps.as_mut().storage,
+            &Commitment::new(
+                Addr::unchecked("lp1"),
+                settlement_tester.security_commitments.clone(),
+            ),
+        )
+        .unwrap();
+
+        let called_commitment = AcceptedCommitment {
+            lp: Addr::unchecked("lp1"),
+            securities: settlement_tester.security_commitments.clone(),
+        };
+        let error = call_capital(deps.as_mut().storage, &called_commitment, None).unwrap_err();
+        assert_eq!(
+            ContractError::InvalidCommitmentState {}.to_string(),
+            error.to_string()
+        );
+    }
+}
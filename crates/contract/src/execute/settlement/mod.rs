@@ -1,11 +1,38 @@
 pub mod accept_commitments;
+pub mod accept_counter_proposal;
+pub mod accept_gp_transfer;
 pub mod add_loan_pool;
+pub mod add_operator;
+pub mod add_security;
+pub mod add_to_allowlist;
 pub mod cancel_commitment;
+pub mod claim_distributions;
+pub mod claim_refund;
+pub mod close_offering;
+pub mod close_security;
 pub mod commitment;
+pub mod counter_propose_commitment;
 pub mod deposit_commitment;
+pub mod distribute;
+pub mod distribute_waterfall;
+pub mod finalize_offering;
+pub mod issue_capital_call;
 pub mod marker_loan_pool_validation;
+pub mod open_redemption_window;
+pub mod pause;
+pub mod process_redemption_queue;
 pub mod propose_commitment;
+pub mod propose_gp_transfer;
+pub mod reclaim_expired_deposit;
+pub mod redeem;
+pub mod reject_commitment;
+pub mod remove_from_allowlist;
+pub mod remove_operator;
 pub mod remove_whitelist_loanpool_contributors;
+pub mod resize_security;
+pub mod transfer_investment;
+pub mod update_eligibility;
+pub mod update_nav;
 pub mod update_settlement_time;
 pub mod whitelist_loanpool_contributors;
 pub mod withdraw_all_commitments;
@@ -0,0 +1,345 @@
+use cosmwasm_std::{Addr, Coin, Env, Response, Uint128};
+
+use crate::{
+    core::{
+        aliases::{ProvDepsMut, ProvTxResponse},
+        error::ContractError,
+        event::{ContractEvent, EventType},
+        security::RedemptionTerms,
+    },
+    storage::{
+        distributions::{self},
+        redemptions::{self, RedemptionWindow},
+        securities::{self},
+    },
+    util::{
+        auth::{authorize, Role},
+        capital::{receive_capital, send_capital},
+    },
+};
+
+// Publishes the price a security can be redeemed at. The gate is applied to every unit that is
+// still outstanding, including the units waiting in the queue, and replaces any earlier window.
+// The GP funds the window with its capacity at the window's price through the deposit mode, and
+// whatever is left in the reserve of the window being replaced is returned to the GP.
+pub fn handle(
+    deps: ProvDepsMut,
+    env: Env,
+    sender: Addr,
+    funds: Vec<Coin>,
+    security: String,
+    terms: RedemptionTerms,
+) -> ProvTxResponse {
+    let state = authorize(deps.storage, &sender, Role::Gp)?;
+
+    if terms.end_time.u64() < env.block.time.seconds() {
+        return Err(ContractError::InvalidRedemptionWindow {});
+    }
+
+    securities::get(deps.storage, security.clone())?;
+    let outstanding = distributions::get_index(deps.storage, security.clone()).total_units
+        + redemptions::queued_units(deps.storage, security.clone());
+    let capacity = outstanding.mul_floor(terms.gate);
+    let reserve = capacity * terms.price_per_unit;
+    let mut messages = receive_capital(
+        &state.deposit_mode,
+        &funds,
+        Coin::new(reserve, &state.capital_denom),
+        &sender,
+        &env.contract.address,
+    )?;
+    if let Some(previous) = redemptions::get_window(deps.storage, security.clone()) {
+        if !previous.reserve.is_zero() {
+            messages.push(send_capital(
+                &state.deposit_mode,
+                Coin::new(previous.reserve, &state.capital_denom),
+                &state.gp,
+                &env.contract.address,
+            )?);
+        }
+    }
+
+    let window = RedemptionWindow {
+        price_per_unit: terms.price_per_unit,
+        start_time: terms.start_time,
+        end_time: terms.end_time,
+        gate: terms.gate,
+        capacity,
+        redeemed: Uint128::zero(),
+        reserve,
+    };
+    redemptions::set_window(deps.storage, security.clone(), &window)?;
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_event(
+            ContractEvent::new(EventType::RedemptionWindowOpened, &sender)
+                .attribute("security", security)
+                .attribute("price_per_unit", window.price_per_unit)
+                .attribute("capacity", window.capacity)
+                .attribute("reserve", window.reserve),
+        )
+        .add_attribute("action", "open_redemption_window")
+        .add_attribute("gp", sender))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{
+        testing::mock_env, Addr, Attribute, Coin, Decimal, Event, SubMsg, Uint128, Uint64,
+    };
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        core::{error::ContractError, security::RedemptionTerms},
+        storage::{
+            redemptions::{self, RedemptionRequest},
+            state::DepositMode,
+        },
+        util::{
+            provenance_utilities::transfer_marker_coins,
+            testing::{create_testing_commitments, instantiate_contract, set_deposit_mode},
+        },
+    };
+
+    use super::handle;
+
+    fn test_terms(end_time: u64) -> RedemptionTerms {
+        RedemptionTerms {
+            price_per_unit: Uint128::new(150),
+            start_time: Uint64::new(0),
+            end_time: Uint64::new(end_time),
+            gate: Decimal::percent(25),
+        }
+    }
+
+    #[test]
+    fn test_handle_must_be_gp() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        let env = mock_env();
+        let end_time = env.block.time.seconds();
+
+        let error = handle(
+            deps.as_mut(),
+            env,
+            Addr::unchecked("lp"),
+            vec![],
+            "Security1".to_string(),
+            test_terms(end_time),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_fails_when_window_has_ended() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        let env = mock_env();
+        let end_time = env.block.time.seconds() - 1;
+
+        let error = handle(
+            deps.as_mut(),
+            env,
+            Addr::unchecked("gp"),
+            vec![],
+            "Security1".to_string(),
+            test_terms(end_time),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidRedemptionWindow {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_fails_for_invalid_security() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        let env = mock_env();
+        let end_time = env.block.time.seconds();
+
+        handle(
+            deps.as_mut(),
+            env,
+            Addr::unchecked("gp"),
+            vec![],
+            "Security3".to_string(),
+            test_terms(end_time),
+        )
+        .unwrap_err();
+    }
+
+    #[test]
+    fn test_handle_gates_outstanding_units() {
+        let mut deps = mock_provenance_dependencies();
+        let security = "Security1".to_string();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        set_deposit_mode(deps.as_mut().storage, DepositMode::Funds);
+        redemptions::push_request(
+            deps.as_mut().storage,
+            security.clone(),
+            &RedemptionRequest {
+                lp: Addr::unchecked("lp1"),
+                amount: Uint128::new(20),
+            },
+        )
+        .unwrap();
+        let env = mock_env();
+        let end_time = env.block.time.seconds();
+
+        let res = handle(
+            deps.as_mut(),
+            env,
+            Addr::unchecked("gp"),
+            vec![Coin::new(4500u128, "denom")],
+            security.clone(),
+            test_terms(end_time),
+        )
+        .unwrap();
+        assert_eq!(
+            vec![
+                Attribute::new("action", "open_redemption_window"),
+                Attribute::new("gp", "gp")
+            ],
+            res.attributes
+        );
+        assert_eq!(
+            vec![Event::new("redemption_window_opened")
+                .add_attribute("gp", "gp")
+                .add_attribute("security", security.clone())
+                .add_attribute("price_per_unit", "150")
+                .add_attribute("capacity", "30")
+                .add_attribute("reserve", "4500")],
+            res.events
+        );
+
+        let window = redemptions::get_window(&deps.storage, security).unwrap();
+        assert_eq!(Uint128::new(30), window.capacity);
+        assert_eq!(Uint128::zero(), window.redeemed);
+        assert_eq!(Uint128::new(4500), window.reserve);
+    }
+
+    #[test]
+    fn test_handle_funds_must_match_reserve() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        set_deposit_mode(deps.as_mut().storage, DepositMode::Funds);
+        let env = mock_env();
+        let end_time = env.block.time.seconds();
+
+        for funds in [
+            vec![],
+            vec![Coin::new(4499u128, "denom")],
+            vec![Coin::new(4500u128, "otherdenom")],
+        ] {
+            let error = handle(
+                deps.as_mut(),
+                env.clone(),
+                Addr::unchecked("gp"),
+                funds,
+                "Security1".to_string(),
+                test_terms(end_time),
+            )
+            .unwrap_err();
+            assert_eq!(
+                ContractError::FundMismatch {}.to_string(),
+                error.to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn test_handle_returns_previous_reserve() {
+        let mut deps = mock_provenance_dependencies();
+        let security = "Security1".to_string();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        let env = mock_env();
+        let end_time = env.block.time.seconds();
+        handle(
+            deps.as_mut(),
+            env.clone(),
+            Addr::unchecked("gp"),
+            vec![],
+            security.clone(),
+            test_terms(end_time),
+        )
+        .unwrap();
+        let mut window = redemptions::get_window(&deps.storage, security.clone()).unwrap();
+        window.redeem(Uint128::new(10)).unwrap();
+        redemptions::set_window(deps.as_mut().storage, security.clone(), &window).unwrap();
+
+        let res = handle(
+            deps.as_mut(),
+            env.clone(),
+            Addr::unchecked("gp"),
+            vec![],
+            security.clone(),
+            test_terms(end_time),
+        )
+        .unwrap();
+        // The new reserve is pulled from the GP, and what's left of the previous one is returned
+        assert_eq!(
+            vec![
+                SubMsg::new(
+                    transfer_marker_coins(
+                        3750,
+                        "denom",
+                        env.contract.address.clone(),
+                        Addr::unchecked("gp"),
+                        env.contract.address.clone()
+                    )
+                    .unwrap()
+                ),
+                SubMsg::new(
+                    transfer_marker_coins(
+                        2250,
+                        "denom",
+                        Addr::unchecked("gp"),
+                        env.contract.address.clone(),
+                        env.contract.address
+                    )
+                    .unwrap()
+                )
+            ],
+            res.messages
+        );
+        assert_eq!(
+            Uint128::new(3750),
+            redemptions::get_window(&deps.storage, security)
+                .unwrap()
+                .reserve
+        );
+    }
+
+    #[test]
+    fn test_handle_rejects_funds_in_marker_mode() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        let env = mock_env();
+        let end_time = env.block.time.seconds();
+
+        let error = handle(
+            deps.as_mut(),
+            env,
+            Addr::unchecked("gp"),
+            vec![Coin::new(3750u128, "denom")],
+            "Security1".to_string(),
+            test_terms(end_time),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::UnexpectedFunds {}.to_string(),
+            error.to_string()
+        );
+    }
+}
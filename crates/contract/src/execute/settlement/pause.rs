@@ -0,0 +1,89 @@
+use cosmwasm_std::{Addr, Env, Response};
+
+use crate::{
+    core::{
+        aliases::{ProvDepsMut, ProvTxResponse},
+        event::{ContractEvent, EventType},
+    },
+    storage::state::{self},
+    util::auth::authorize_gp_or_admin,
+};
+
+// Pausing blocks every message outside of the safe set until the contract is unpaused. The GP and
+// the contract admin can both pause, so the offering can be halted even if the GP key is lost.
+pub fn handle(deps: ProvDepsMut, env: Env, sender: Addr, paused: bool) -> ProvTxResponse {
+    let mut state = authorize_gp_or_admin(&deps.as_ref(), &env, &sender)?;
+    state.paused = paused;
+    state::set(deps.storage, &state)?;
+
+    let (event, action) = match paused {
+        true => (EventType::ContractPaused, "pause"),
+        false => (EventType::ContractUnpaused, "unpause"),
+    };
+    Ok(Response::new()
+        .add_event(ContractEvent::new(event, &state.gp))
+        .add_attribute("action", action)
+        .add_attribute("sender", sender))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{testing::mock_env, Addr, Attribute, Event};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        core::error::ContractError,
+        storage::state,
+        util::testing::{instantiate_contract, mock_contract_admin},
+    };
+
+    use super::handle;
+
+    #[test]
+    fn test_handle_must_be_gp_or_admin() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        mock_contract_admin(&mut deps, "admin");
+
+        let error = handle(deps.as_mut(), mock_env(), Addr::unchecked("lp1"), true).unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_pauses_and_unpauses() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        mock_contract_admin(&mut deps, "admin");
+
+        let res = handle(deps.as_mut(), mock_env(), Addr::unchecked("admin"), true).unwrap();
+        assert_eq!(
+            vec![
+                Attribute::new("action", "pause"),
+                Attribute::new("sender", "admin")
+            ],
+            res.attributes
+        );
+        assert_eq!(
+            vec![Event::new("contract_paused").add_attribute("gp", "gp")],
+            res.events
+        );
+        assert!(state::get(&deps.storage).unwrap().paused);
+
+        let res = handle(deps.as_mut(), mock_env(), Addr::unchecked("gp"), false).unwrap();
+        assert_eq!(
+            vec![
+                Attribute::new("action", "unpause"),
+                Attribute::new("sender", "gp")
+            ],
+            res.attributes
+        );
+        assert_eq!(
+            vec![Event::new("contract_unpaused").add_attribute("gp", "gp")],
+            res.events
+        );
+        assert!(!state::get(&deps.storage).unwrap().paused);
+    }
+}
@@ -0,0 +1,356 @@
+use cosmwasm_std::{Addr, Env, Response};
+
+use crate::{
+    core::{
+        aliases::{ProvDepsMut, ProvTxResponse},
+        constants::{DEFAULT_QUERY_LIMIT, MAX_QUERY_LIMIT},
+        error::ContractError,
+        event::{ContractEvent, EventType},
+    },
+    storage::redemptions::{self},
+    util::{
+        auth::{authorize, Role},
+        to,
+    },
+};
+
+use super::redeem::{pay_redemption, redeemed_event};
+
+// Pays out queued redemptions in the order they were requested until the window's gate is reached.
+// At most `limit` requests are paid in a single call, and a request that only partly fits keeps its
+// place at the front of the queue.
+pub fn handle(
+    deps: ProvDepsMut,
+    env: Env,
+    sender: Addr,
+    security: String,
+    limit: Option<u32>,
+) -> ProvTxResponse {
+    let state = authorize(deps.storage, &sender, Role::Gp)?;
+
+    let mut window = redemptions::get_window(deps.storage, security.clone())
+        .filter(|window| window.is_open(&env.block.time))
+        .ok_or(ContractError::RedemptionWindowClosed {})?;
+
+    let investment_name = to::security_to_investment_name(&security, &env.contract.address);
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let mut messages = vec![];
+    let mut events = vec![];
+    for (id, request) in redemptions::get_requests(deps.storage, security.clone(), limit)? {
+        if window.remaining().is_zero() {
+            break;
+        }
+
+        let units = request.amount.min(window.remaining());
+        messages.extend(pay_redemption(
+            &mut window,
+            &request.lp,
+            &investment_name,
+            units,
+            &state.deposit_mode,
+            &state.capital_denom,
+            &env.contract.address,
+        )?);
+        events.push(redeemed_event(
+            &state.gp,
+            &request.lp,
+            &security,
+            units,
+            &window,
+            &state.capital_denom,
+        ));
+        redemptions::remove_units(deps.storage, security.clone(), id, units)?;
+    }
+
+    redemptions::set_window(deps.storage, security.clone(), &window)?;
+
+    let processed = ContractEvent::new(EventType::RedemptionQueueProcessed, &state.gp)
+        .attribute("security", security)
+        .attribute("redeemed", events.len().to_string())
+        .attribute("remaining", window.remaining());
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_events(events)
+        .add_event(processed)
+        .add_attribute("action", "process_redemption_queue")
+        .add_attribute("gp", sender))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{
+        testing::mock_env, Addr, Attribute, BankMsg, Coin, Decimal, Event, SubMsg, Uint128, Uint64,
+    };
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        core::{aliases::ProvMsg, error::ContractError},
+        storage::{
+            redemptions::{self, RedemptionRequest, RedemptionWindow},
+            state::DepositMode,
+        },
+        util::{
+            provenance_utilities::{burn_marker_supply, transfer_marker_coins},
+            testing::{instantiate_contract, set_deposit_mode, MockDeps},
+            to,
+        },
+    };
+
+    use super::handle;
+
+    fn queue_redemptions(deps: &mut MockDeps, capacity: u128) {
+        let env = mock_env();
+        redemptions::set_window(
+            deps.as_mut().storage,
+            "Security1".to_string(),
+            &RedemptionWindow {
+                price_per_unit: Uint128::new(2),
+                start_time: Uint64::new(env.block.time.seconds()),
+                end_time: Uint64::new(env.block.time.seconds()),
+                gate: Decimal::percent(10),
+                capacity: Uint128::new(capacity),
+                redeemed: Uint128::zero(),
+                reserve: Uint128::new(capacity * 2),
+            },
+        )
+        .unwrap();
+        for lp in ["lp1", "lp2"] {
+            redemptions::push_request(
+                deps.as_mut().storage,
+                "Security1".to_string(),
+                &RedemptionRequest {
+                    lp: Addr::unchecked(lp),
+                    amount: Uint128::new(10),
+                },
+            )
+            .unwrap();
+        }
+    }
+
+    #[test]
+    fn test_handle_must_be_gp() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        queue_redemptions(&mut deps, 15);
+
+        let error = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("lp1"),
+            "Security1".to_string(),
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_fails_when_window_is_closed() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        queue_redemptions(&mut deps, 15);
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(1);
+
+        let error = handle(
+            deps.as_mut(),
+            env,
+            Addr::unchecked("gp"),
+            "Security1".to_string(),
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::RedemptionWindowClosed {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_pays_queue_in_order() {
+        let mut deps = mock_provenance_dependencies();
+        let security = "Security1".to_string();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        queue_redemptions(&mut deps, 15);
+        let env = mock_env();
+        let investment_name = to::security_to_investment_name(&security, &env.contract.address);
+
+        let res = handle(
+            deps.as_mut(),
+            env.clone(),
+            Addr::unchecked("gp"),
+            security.clone(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            vec![
+                SubMsg::new(
+                    burn_marker_supply(10, &investment_name, env.contract.address.clone()).unwrap()
+                ),
+                SubMsg::new(
+                    transfer_marker_coins(
+                        20,
+                        "denom",
+                        Addr::unchecked("lp1"),
+                        env.contract.address.clone(),
+                        env.contract.address.clone(),
+                    )
+                    .unwrap()
+                ),
+                SubMsg::new(
+                    burn_marker_supply(5, &investment_name, env.contract.address.clone()).unwrap()
+                ),
+                SubMsg::new(
+                    transfer_marker_coins(
+                        10,
+                        "denom",
+                        Addr::unchecked("lp2"),
+                        env.contract.address.clone(),
+                        env.contract.address.clone(),
+                    )
+                    .unwrap()
+                ),
+            ],
+            res.messages
+        );
+        assert_eq!(
+            vec![
+                Event::new("redeemed")
+                    .add_attribute("gp", "gp")
+                    .add_attribute("lp", "lp1")
+                    .add_attribute("securities", format!("{}:10", security))
+                    .add_attribute("amount", "20denom"),
+                Event::new("redeemed")
+                    .add_attribute("gp", "gp")
+                    .add_attribute("lp", "lp2")
+                    .add_attribute("securities", format!("{}:5", security))
+                    .add_attribute("amount", "10denom"),
+                Event::new("redemption_queue_processed")
+                    .add_attribute("gp", "gp")
+                    .add_attribute("security", security.clone())
+                    .add_attribute("redeemed", "2")
+                    .add_attribute("remaining", "0"),
+            ],
+            res.events
+        );
+        assert_eq!(
+            vec![
+                Attribute::new("action", "process_redemption_queue"),
+                Attribute::new("gp", "gp")
+            ],
+            res.attributes
+        );
+
+        assert_eq!(
+            vec![(
+                1,
+                RedemptionRequest {
+                    lp: Addr::unchecked("lp2"),
+                    amount: Uint128::new(5),
+                }
+            )],
+            redemptions::get_requests(&deps.storage, security.clone(), 10).unwrap()
+        );
+        assert_eq!(
+            Uint128::new(5),
+            redemptions::queued_units(&deps.storage, security.clone())
+        );
+        let window = redemptions::get_window(&deps.storage, security).unwrap();
+        assert_eq!(Uint128::zero(), window.remaining());
+        assert_eq!(Uint128::zero(), window.reserve);
+    }
+
+    #[test]
+    fn test_handle_with_empty_queue() {
+        let mut deps = mock_provenance_dependencies();
+        let security = "Security1".to_string();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        queue_redemptions(&mut deps, 15);
+        for id in [0, 1] {
+            redemptions::remove_units(
+                deps.as_mut().storage,
+                security.clone(),
+                id,
+                Uint128::new(10),
+            )
+            .unwrap();
+        }
+
+        let res = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            security.clone(),
+            None,
+        )
+        .unwrap();
+        assert!(res.messages.is_empty());
+        assert_eq!(
+            vec![Event::new("redemption_queue_processed")
+                .add_attribute("gp", "gp")
+                .add_attribute("security", security)
+                .add_attribute("redeemed", "0")
+                .add_attribute("remaining", "15")],
+            res.events
+        );
+    }
+
+    #[test]
+    fn test_handle_pays_at_most_limit_requests() {
+        let mut deps = mock_provenance_dependencies();
+        let security = "Security1".to_string();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        queue_redemptions(&mut deps, 15);
+
+        let res = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            security.clone(),
+            Some(1),
+        )
+        .unwrap();
+        assert_eq!(2, res.messages.len());
+        assert_eq!(
+            vec![(
+                1,
+                RedemptionRequest {
+                    lp: Addr::unchecked("lp2"),
+                    amount: Uint128::new(10),
+                }
+            )],
+            redemptions::get_requests(&deps.storage, security.clone(), 10).unwrap()
+        );
+        let window = redemptions::get_window(&deps.storage, security).unwrap();
+        assert_eq!(Uint128::new(5), window.remaining());
+        assert_eq!(Uint128::new(10), window.reserve);
+    }
+
+    #[test]
+    fn test_handle_pays_attached_funds_with_a_bank_send() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        set_deposit_mode(deps.as_mut().storage, DepositMode::Funds);
+        queue_redemptions(&mut deps, 15);
+
+        let res = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            "Security1".to_string(),
+            Some(1),
+        )
+        .unwrap();
+        assert_eq!(
+            SubMsg::new(ProvMsg::Bank(BankMsg::Send {
+                to_address: "lp1".to_string(),
+                amount: vec![Coin::new(Uint128::new(20), "denom")],
+            })),
+            res.messages[1]
+        );
+    }
+}
@@ -2,16 +2,19 @@ use cosmwasm_std::{Addr, Env, Response};
 
 use crate::{
     core::{
-        aliases::{ProvDepsMut, ProvTxResponse},
+        aliases::{ProvDeps, ProvDepsMut, ProvTxResponse},
         error::ContractError,
+        event::{ContractEvent, EventType},
         security::SecurityCommitment,
     },
     storage::{
+        commitment_history,
         commits::{self},
-        remaining_securities,
+        counter_proposals, remaining_securities,
         securities::{self},
+        state::{self},
     },
-    util::settlement::timestamp_is_expired,
+    util::settlement::{check_allocation_limits, is_eligible, timestamp_is_expired},
 };
 
 use super::commitment::{Commitment, CommitmentState};
@@ -22,13 +25,46 @@ pub fn handle(
     lp: Addr,
     securities: Vec<SecurityCommitment>,
 ) -> ProvTxResponse {
-    let commitment = commits::get(deps.storage, lp.clone());
+    let new_commitment = validate(&deps.as_ref(), env, &lp, &securities)?;
+    let state = state::get(deps.storage)?;
+
+    // Any counter proposal was made against the previous securities
+    counter_proposals::remove(deps.storage, lp.clone());
+    commits::set(deps.storage, &new_commitment)?;
+    commitment_history::record(deps.storage, env, &lp, &lp, "proposed", &securities)?;
+
+    Ok(Response::new()
+        .add_event(
+            ContractEvent::new(EventType::Proposed, &state.gp)
+                .lp(&lp)
+                .securities(&securities)
+                .state(&new_commitment.state),
+        )
+        .add_attribute("action", "propose_commitment")
+        .add_attribute("lp", lp))
+}
+
+// Runs every check that the proposal must pass without changing any state, and returns the
+// lp's commitment with the proposal added to it
+pub fn validate(
+    deps: &ProvDeps,
+    env: &Env,
+    lp: &Addr,
+    securities: &[SecurityCommitment],
+) -> Result<Commitment, ContractError> {
+    // A terminated commitment is only kept for history, so the lp can propose a new one
+    let commitment = match commits::get(deps.storage, lp.clone()) {
+        Ok(commitment) if commitment.state.is_terminated() => {
+            Err(ContractError::InvalidCommitmentState {})
+        }
+        commitment => commitment,
+    };
 
     if is_approved(&commitment) {
         return Err(crate::core::error::ContractError::AlreadyAccepted {});
     }
 
-    if !is_new_securities(&commitment, &securities) {
+    if !is_new_securities(&commitment, securities) {
         return Err(crate::core::error::ContractError::InvalidSecurityCommitment {});
     }
 
@@ -36,8 +72,44 @@ pub fn handle(
         return Err(crate::core::error::ContractError::SettlmentExpired {});
     }
 
-    for security_commitment in &securities {
+    // The limits apply to everything the lp has proposed, not just this proposal
+    let mut proposed = commitment
+        .as_ref()
+        .map_or(vec![], |commitment| commitment.commitments.clone());
+    proposed.extend(securities.to_vec());
+    validate_securities(deps, lp, securities, &proposed)?;
+
+    let mut new_commitment = Commitment::new(lp.clone(), securities.to_vec());
+    if let Ok(mut commitment) = commitment {
+        new_commitment
+            .commitments
+            .append(&mut commitment.commitments)
+    }
+    Ok(new_commitment)
+}
+
+// Checks the securities that a lp is committing to, and that the lp's allocation limits hold
+// across every security in their whole commitment. Counter proposals are held to the same rules.
+pub fn validate_securities(
+    deps: &ProvDeps,
+    lp: &Addr,
+    securities: &[SecurityCommitment],
+    commitment: &[SecurityCommitment],
+) -> Result<(), ContractError> {
+    if has_duplicates(securities) {
+        return Err(ContractError::InvalidSecurityCommitment {});
+    }
+
+    for security_commitment in securities {
         let security = securities::get(deps.storage, security_commitment.name.clone())?;
+        if securities::is_closed(deps.storage, security.name.clone()) {
+            return Err(ContractError::SecurityClosed {});
+        }
+        if !is_eligible(deps, lp, security.name.clone())? {
+            return Err(ContractError::IneligibleLp {
+                security: security.name,
+            });
+        }
         if security_commitment.amount < security.minimum_amount {
             return Err(crate::core::error::ContractError::InvalidSecurityCommitmentAmount {});
         }
@@ -52,17 +124,16 @@ pub fn handle(
         }
     }
 
-    let mut new_commitment = Commitment::new(lp.clone(), securities);
-    if let Ok(mut commitment) = commitment {
-        new_commitment
-            .commitments
-            .append(&mut commitment.commitments)
-    }
-    commits::set(deps.storage, &new_commitment)?;
+    let state = state::get(deps.storage)?;
+    check_allocation_limits(deps.storage, &state.maximum_lp_commitment, commitment)
+}
 
-    Ok(Response::new()
-        .add_attribute("action", "propose_commitment")
-        .add_attribute("lp", lp))
+fn has_duplicates(securities: &[SecurityCommitment]) -> bool {
+    (1..securities.len()).any(|i| {
+        securities[i..]
+            .iter()
+            .any(|security| security.name == securities[i - 1].name)
+    })
 }
 
 fn is_approved(commitment: &Result<Commitment, ContractError>) -> bool {
@@ -94,7 +165,7 @@ fn is_new_securities(
 
 #[cfg(test)]
 mod test {
-    use cosmwasm_std::{testing::mock_env, Addr, Attribute, Coin, Uint128};
+    use cosmwasm_std::{testing::mock_env, Addr, Attribute, Coin, Event, Uint128};
     use provwasm_mocks::mock_provenance_dependencies;
 
     use crate::{
@@ -111,10 +182,14 @@ mod test {
         },
         storage::{
             commits::{self},
-            remaining_securities,
+            eligibility, remaining_securities,
             securities::{self},
+            state::{self},
+        },
+        util::testing::{
+            create_test_state, instantiate_contract, mock_lp_attributes, test_security_commitments,
+            SettlementTester,
         },
-        util::testing::{create_test_state, SettlementTester},
     };
 
     use super::is_new_securities;
@@ -215,9 +290,11 @@ mod test {
             &Security {
                 name: commitments[0].name.clone(),
                 amount: Uint128::new(10),
-                security_type: crate::core::security::SecurityType::Fund(FundSecurity {}),
+                security_type: crate::core::security::SecurityType::Fund(FundSecurity::default()),
                 minimum_amount: commitments[0].amount + Uint128::new(1),
                 price_per_unit: Coin::new(Uint128::new(5), "denom".to_string()),
+                maximum_amount: None,
+                maximum_percentage: None,
             },
         )
         .unwrap();
@@ -255,9 +332,11 @@ mod test {
             &Security {
                 name: commitments[0].name.clone(),
                 amount: Uint128::new(10),
-                security_type: crate::core::security::SecurityType::Fund(FundSecurity {}),
+                security_type: crate::core::security::SecurityType::Fund(FundSecurity::default()),
                 minimum_amount: commitments[0].amount,
                 price_per_unit: Coin::new(Uint128::new(5), "denom".to_string()),
+                maximum_amount: None,
+                maximum_percentage: None,
             },
         )
         .unwrap();
@@ -274,6 +353,162 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_fails_on_closed_security() {
+        let mut deps = mock_provenance_dependencies();
+        let lp = Addr::unchecked("address");
+        let mut settlement_tester = SettlementTester::new();
+        settlement_tester.create_security_commitments(1);
+        create_test_state(&mut deps, &mock_env(), false);
+        let commitments = settlement_tester.security_commitments.clone();
+        securities::set(
+            &mut deps.storage,
+            &Security {
+                name: commitments[0].name.clone(),
+                amount: Uint128::new(10),
+                security_type: crate::core::security::SecurityType::Fund(FundSecurity::default()),
+                minimum_amount: commitments[0].amount,
+                price_per_unit: Coin::new(Uint128::new(5), "denom".to_string()),
+                maximum_amount: None,
+                maximum_percentage: None,
+            },
+        )
+        .unwrap();
+        remaining_securities::set(
+            deps.as_mut().storage,
+            commitments[0].name.clone(),
+            commitments[0].amount.u128(),
+        )
+        .unwrap();
+        securities::close(deps.as_mut().storage, commitments[0].name.clone()).unwrap();
+
+        let err = handle(deps.as_mut(), &mock_env(), lp, commitments).unwrap_err();
+        assert_eq!(
+            ContractError::SecurityClosed {}.to_string(),
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_fails_when_lp_is_missing_required_attributes() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        eligibility::set_required_attributes(
+            deps.as_mut().storage,
+            "Security2".to_string(),
+            &vec![
+                "accredited.investor.pb".to_string(),
+                "kyc.investor.pb".to_string(),
+            ],
+        )
+        .unwrap();
+        mock_lp_attributes(&mut deps, &[("lp", "accredited.investor.pb")]);
+
+        let err = handle(
+            deps.as_mut(),
+            &mock_env(),
+            Addr::unchecked("lp"),
+            test_security_commitments(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::IneligibleLp {
+                security: "Security2".to_string()
+            }
+            .to_string(),
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_commit_is_added_when_lp_is_eligible() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        eligibility::set_required_attributes(
+            deps.as_mut().storage,
+            "Security1".to_string(),
+            &vec!["accredited.investor.pb".to_string()],
+        )
+        .unwrap();
+        mock_lp_attributes(&mut deps, &[("lp1", "accredited.investor.pb")]);
+        eligibility::allow(deps.as_mut().storage, Addr::unchecked("lp2")).unwrap();
+
+        handle(
+            deps.as_mut(),
+            &mock_env(),
+            Addr::unchecked("lp1"),
+            test_security_commitments(),
+        )
+        .expect("an lp with the attributes should be eligible");
+        handle(
+            deps.as_mut(),
+            &mock_env(),
+            Addr::unchecked("lp2"),
+            test_security_commitments(),
+        )
+        .expect("an allowlisted lp should be eligible");
+        assert!(commits::exists(&deps.storage, Addr::unchecked("lp1")));
+        assert!(commits::exists(&deps.storage, Addr::unchecked("lp2")));
+    }
+
+    #[test]
+    fn test_fails_when_commitment_exceeds_allocation_limits() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        let mut security = securities::get(&deps.storage, "Security2".to_string()).unwrap();
+        security.maximum_amount = Some(Uint128::new(150));
+        securities::set(deps.as_mut().storage, &security).unwrap();
+        let mut state = state::get(&deps.storage).unwrap();
+        state.maximum_lp_commitment = Some(Uint128::new(19999));
+        state::set(deps.as_mut().storage, &state).unwrap();
+        let commitment = |name: &str, amount: u128| {
+            vec![SecurityCommitment {
+                name: name.to_string(),
+                amount: Uint128::new(amount),
+            }]
+        };
+
+        let err = handle(
+            deps.as_mut(),
+            &mock_env(),
+            Addr::unchecked("lp"),
+            commitment("Security2", 151),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::AllocationLimitExceeded {
+                security: "Security2".to_string(),
+                limit: "maximum amount".to_string()
+            }
+            .to_string(),
+            err.to_string()
+        );
+
+        // The lp's pending commitment counts towards the offering wide limit
+        handle(
+            deps.as_mut(),
+            &mock_env(),
+            Addr::unchecked("lp"),
+            commitment("Security1", 100),
+        )
+        .unwrap();
+        let err = handle(
+            deps.as_mut(),
+            &mock_env(),
+            Addr::unchecked("lp"),
+            commitment("Security2", 100),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::AllocationLimitExceeded {
+                security: "Security2".to_string(),
+                limit: "maximum lp commitment".to_string()
+            }
+            .to_string(),
+            err.to_string()
+        );
+    }
+
     #[test]
     fn test_commit_is_added_on_success_with_unexpired_timestamp() {
         let mut deps = mock_provenance_dependencies();
@@ -287,9 +522,11 @@ mod test {
             &Security {
                 name: commitments[0].name.clone(),
                 amount: Uint128::new(10),
-                security_type: crate::core::security::SecurityType::Fund(FundSecurity {}),
+                security_type: crate::core::security::SecurityType::Fund(FundSecurity::default()),
                 minimum_amount: commitments[0].amount,
                 price_per_unit: Coin::new(Uint128::new(5), "denom".to_string()),
+                maximum_amount: None,
+                maximum_percentage: None,
             },
         )
         .unwrap();
@@ -326,9 +563,11 @@ mod test {
             &Security {
                 name: commitments[0].name.clone(),
                 amount: Uint128::new(10),
-                security_type: crate::core::security::SecurityType::Fund(FundSecurity {}),
+                security_type: crate::core::security::SecurityType::Fund(FundSecurity::default()),
                 minimum_amount: commitments[0].amount,
                 price_per_unit: Coin::new(Uint128::new(5), "denom".to_string()),
+                maximum_amount: None,
+                maximum_percentage: None,
             },
         )
         .unwrap();
@@ -349,7 +588,18 @@ mod test {
             Attribute::new("action", "propose_commitment"),
             res.attributes[0]
         );
-        assert_eq!(Attribute::new("lp", lp), res.attributes[1]);
+        assert_eq!(Attribute::new("lp", lp.clone()), res.attributes[1]);
+        assert_eq!(
+            vec![Event::new("proposed")
+                .add_attribute("gp", "gp")
+                .add_attribute("lp", lp)
+                .add_attribute(
+                    "securities",
+                    format!("{}:{}", commitments[0].name, commitments[0].amount)
+                )
+                .add_attribute("state", "pending")],
+            res.events
+        );
     }
 
     #[test]
@@ -365,9 +615,11 @@ mod test {
             &Security {
                 name: commitments[0].name.clone(),
                 amount: Uint128::new(10),
-                security_type: crate::core::security::SecurityType::Fund(FundSecurity {}),
+                security_type: crate::core::security::SecurityType::Fund(FundSecurity::default()),
                 minimum_amount: commitments[0].amount,
                 price_per_unit: Coin::new(Uint128::new(5), "denom".to_string()),
+                maximum_amount: None,
+                maximum_percentage: None,
             },
         )
         .unwrap();
@@ -379,6 +631,46 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_commit_replaces_terminated_commitment() {
+        let mut deps = mock_provenance_dependencies();
+        let lp = Addr::unchecked("address");
+        let mut settlement_tester = SettlementTester::new();
+        settlement_tester.create_security_commitments(1);
+        create_test_state(&mut deps, &mock_env(), false);
+        let commitments = settlement_tester.security_commitments.clone();
+        securities::set(
+            &mut deps.storage,
+            &Security {
+                name: commitments[0].name.clone(),
+                amount: Uint128::new(10),
+                security_type: crate::core::security::SecurityType::Fund(FundSecurity::default()),
+                minimum_amount: commitments[0].amount,
+                price_per_unit: Coin::new(Uint128::new(5), "denom".to_string()),
+                maximum_amount: None,
+                maximum_percentage: None,
+            },
+        )
+        .unwrap();
+        remaining_securities::set(
+            deps.as_mut().storage,
+            commitments[0].name.clone(),
+            commitments[0].amount.u128(),
+        )
+        .unwrap();
+        let mut rejected = Commitment::new(lp.clone(), commitments.clone());
+        rejected.state = CommitmentState::REJECTED;
+        rejected.reason = Some("reason".to_string());
+        commits::set(deps.as_mut().storage, &rejected).unwrap();
+
+        handle(deps.as_mut(), &mock_env(), lp.clone(), commitments.clone()).unwrap();
+
+        let commitment = commits::get(&deps.storage, lp).unwrap();
+        assert_eq!(commitments, commitment.commitments);
+        assert_eq!(CommitmentState::PENDING, commitment.state);
+        assert_eq!(None, commitment.reason);
+    }
+
     #[test]
     fn test_cannot_double_commit_same_security() {
         let mut deps = mock_provenance_dependencies();
@@ -392,9 +684,11 @@ mod test {
             &Security {
                 name: commitments[0].name.clone(),
                 amount: Uint128::new(10),
-                security_type: crate::core::security::SecurityType::Fund(FundSecurity {}),
+                security_type: crate::core::security::SecurityType::Fund(FundSecurity::default()),
                 minimum_amount: commitments[0].amount,
                 price_per_unit: Coin::new(Uint128::new(5), "denom".to_string()),
+                maximum_amount: None,
+                maximum_percentage: None,
             },
         )
         .unwrap();
@@ -426,9 +720,13 @@ mod test {
                 &Security {
                     name: security_commitment.name.clone(),
                     amount: Uint128::new(10),
-                    security_type: crate::core::security::SecurityType::Fund(FundSecurity {}),
+                    security_type: crate::core::security::SecurityType::Fund(
+                        FundSecurity::default(),
+                    ),
                     minimum_amount: Uint128::zero(),
                     price_per_unit: Coin::new(Uint128::new(5), "denom".to_string()),
+                    maximum_amount: None,
+                    maximum_percentage: None,
                 },
             )
             .unwrap();
@@ -469,9 +767,13 @@ mod test {
                 &Security {
                     name: security_commitment.name.clone(),
                     amount: Uint128::new(10),
-                    security_type: crate::core::security::SecurityType::Fund(FundSecurity {}),
+                    security_type: crate::core::security::SecurityType::Fund(
+                        FundSecurity::default(),
+                    ),
                     minimum_amount: Uint128::zero(),
                     price_per_unit: Coin::new(Uint128::new(5), "denom".to_string()),
+                    maximum_amount: None,
+                    maximum_percentage: None,
                 },
             )
             .unwrap();
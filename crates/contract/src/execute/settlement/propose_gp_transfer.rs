@@ -0,0 +1,110 @@
+use cosmwasm_std::{Addr, Env, Response};
+
+use crate::{
+    core::{
+        aliases::{ProvDepsMut, ProvTxResponse},
+        error::ContractError,
+        event::{ContractEvent, EventType},
+    },
+    storage::roles,
+    util::auth::{authorize, Role},
+};
+
+// Nominates the account that will take over as GP. Nothing changes until that account accepts,
+// and a later proposal replaces an earlier one.
+pub fn handle(deps: ProvDepsMut, env: Env, sender: Addr, gp: Addr) -> ProvTxResponse {
+    authorize(deps.storage, &sender, Role::Gp)?;
+
+    if gp == sender || gp == env.contract.address {
+        return Err(ContractError::InvalidGpTransfer {});
+    }
+    roles::set_pending_gp(deps.storage, &gp)?;
+
+    Ok(Response::new()
+        .add_event(
+            ContractEvent::new(EventType::GpTransferProposed, &sender).attribute("pending_gp", gp),
+        )
+        .add_attribute("action", "propose_gp_transfer")
+        .add_attribute("gp", sender))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{testing::mock_env, Addr, Attribute, Event};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        core::error::ContractError,
+        storage::roles,
+        util::testing::{add_operator_test, instantiate_contract},
+    };
+
+    use super::handle;
+
+    #[test]
+    fn test_handle_must_be_gp() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        add_operator_test(deps.as_mut(), mock_env(), "gp", "operator").unwrap();
+
+        for sender in ["lp1", "operator"] {
+            let error = handle(
+                deps.as_mut(),
+                mock_env(),
+                Addr::unchecked(sender),
+                Addr::unchecked("gp2"),
+            )
+            .unwrap_err();
+            assert_eq!(
+                ContractError::Unauthorized {}.to_string(),
+                error.to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn test_handle_fails_for_invalid_gp() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        let env = mock_env();
+
+        for gp in [Addr::unchecked("gp"), env.contract.address.clone()] {
+            let error = handle(deps.as_mut(), env.clone(), Addr::unchecked("gp"), gp).unwrap_err();
+            assert_eq!(
+                ContractError::InvalidGpTransfer {}.to_string(),
+                error.to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn test_handle_proposes_gp() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+
+        let res = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            Addr::unchecked("gp2"),
+        )
+        .unwrap();
+        assert_eq!(
+            vec![
+                Attribute::new("action", "propose_gp_transfer"),
+                Attribute::new("gp", "gp")
+            ],
+            res.attributes
+        );
+        assert_eq!(
+            vec![Event::new("gp_transfer_proposed")
+                .add_attribute("gp", "gp")
+                .add_attribute("pending_gp", "gp2")],
+            res.events
+        );
+        assert_eq!(
+            Some(Addr::unchecked("gp2")),
+            roles::get_pending_gp(&deps.storage)
+        );
+    }
+}
@@ -0,0 +1,216 @@
+use cosmwasm_std::{Addr, Env, Response};
+
+use crate::{
+    core::{
+        aliases::{ProvDepsMut, ProvTxResponse},
+        error::ContractError,
+        event::{ContractEvent, EventType},
+    },
+    storage::{
+        commitment_history,
+        commits::{self},
+        state::{self},
+    },
+    util::settlement::is_expired,
+};
+
+use super::{cancel_commitment::refund_lp, commitment::CommitmentState};
+
+pub fn handle(deps: ProvDepsMut, env: Env, sender: Addr) -> ProvTxResponse {
+    let mut commitment = commits::get(deps.storage, sender.clone())?;
+    if commitment.state != CommitmentState::ACCEPTED {
+        return Err(ContractError::InvalidCommitmentState {});
+    }
+
+    if !is_expired(&env, &commitment) {
+        return Err(ContractError::SettlementNotExpired {});
+    }
+
+    let state = state::get(deps.storage)?;
+    let (refund_messages, reclaimed) = refund_lp(
+        deps.storage,
+        &state.deposit_mode,
+        &env.contract.address,
+        sender.clone(),
+    )?;
+
+    commitment.state = CommitmentState::EXPIRED;
+    commits::set(deps.storage, &commitment)?;
+    commitment_history::record(
+        deps.storage,
+        &env,
+        &sender,
+        &sender,
+        "reclaimed",
+        &commitment.commitments,
+    )?;
+
+    let mut event = ContractEvent::new(EventType::Reclaimed, &state.gp)
+        .lp(&sender)
+        .securities(&commitment.commitments)
+        .state(&commitment.state);
+    if let Some(reclaimed) = reclaimed {
+        event = event.amount(reclaimed);
+    }
+
+    Ok(Response::new()
+        .add_messages(refund_messages)
+        .add_event(event)
+        .add_attribute("action", "reclaim_expired_deposit")
+        .add_attribute("lp", sender))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{
+        testing::mock_env, Addr, Attribute, BankMsg, Coin, Env, Event, SubMsg, Uint64,
+    };
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        core::error::ContractError,
+        execute::settlement::commitment::CommitmentState,
+        storage::{
+            available_capital, commits, paid_in_capital, remaining_securities, state::DepositMode,
+        },
+        util::{
+            provenance_utilities::transfer_marker_coins,
+            testing::{
+                create_testing_commitments, instantiate_contract, set_deposit_mode, MockDeps,
+            },
+        },
+    };
+
+    use super::handle;
+
+    fn expire_commitment(deps: &mut MockDeps, lp: &str) -> Env {
+        let mut env = mock_env();
+        let mut commitment = commits::get(&deps.storage, Addr::unchecked(lp)).unwrap();
+        commitment.settlment_date = Some(Uint64::new(env.block.time.seconds()));
+        commits::set(deps.as_mut().storage, &commitment).unwrap();
+        env.block.time = env.block.time.plus_seconds(1);
+        env
+    }
+
+    #[test]
+    fn test_handle_fails_when_not_expired() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+
+        let error = handle(deps.as_mut(), mock_env(), Addr::unchecked("lp2")).unwrap_err();
+        assert_eq!(
+            ContractError::SettlementNotExpired {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_fails_when_not_accepted() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        let env = expire_commitment(&mut deps, "lp7");
+
+        let error = handle(deps.as_mut(), env, Addr::unchecked("lp7")).unwrap_err();
+        assert_eq!(
+            ContractError::InvalidCommitmentState {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_fails_when_commitment_doesnt_exist() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+
+        handle(deps.as_mut(), mock_env(), Addr::unchecked("lp1")).unwrap_err();
+    }
+
+    #[test]
+    fn test_handle_reclaims_deposit() {
+        let mut deps = mock_provenance_dependencies();
+        let lp = Addr::unchecked("lp2");
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        let env = expire_commitment(&mut deps, "lp2");
+        let remaining = remaining_securities::get(&deps.storage, "Security1".to_string()).unwrap();
+
+        let res = handle(deps.as_mut(), env.clone(), lp.clone()).unwrap();
+        assert_eq!(
+            vec![SubMsg::new(
+                transfer_marker_coins(
+                    10000,
+                    "denom",
+                    lp.clone(),
+                    env.contract.address.clone(),
+                    env.contract.address.clone(),
+                )
+                .unwrap()
+            )],
+            res.messages
+        );
+        assert_eq!(
+            vec![
+                Attribute::new("action", "reclaim_expired_deposit"),
+                Attribute::new("lp", "lp2")
+            ],
+            res.attributes
+        );
+        assert_eq!(
+            vec![Event::new("reclaimed")
+                .add_attribute("gp", "gp")
+                .add_attribute("lp", lp.clone())
+                .add_attribute("securities", "Security1:100,Security2:100")
+                .add_attribute("amount", "10000denom")
+                .add_attribute("state", "expired")],
+            res.events
+        );
+
+        assert!(!available_capital::has_lp(&deps.storage, lp.clone()));
+        assert!(!paid_in_capital::has_lp(&deps.storage, lp.clone()));
+        assert_eq!(
+            remaining + 100,
+            remaining_securities::get(&deps.storage, "Security1".to_string()).unwrap()
+        );
+        assert_eq!(
+            CommitmentState::EXPIRED,
+            commits::get(&deps.storage, lp).unwrap().state
+        );
+    }
+
+    #[test]
+    fn test_handle_reclaims_attached_funds() {
+        let mut deps = mock_provenance_dependencies();
+        let lp = Addr::unchecked("lp2");
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        set_deposit_mode(deps.as_mut().storage, DepositMode::Funds);
+        let env = expire_commitment(&mut deps, "lp2");
+
+        let res = handle(deps.as_mut(), env, lp.clone()).unwrap();
+        assert_eq!(
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: lp.to_string(),
+                amount: vec![Coin::new(10000u128, "denom")],
+            })],
+            res.messages
+        );
+    }
+
+    #[test]
+    fn test_handle_reclaims_without_deposit() {
+        let mut deps = mock_provenance_dependencies();
+        let lp = Addr::unchecked("lp3");
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        let env = expire_commitment(&mut deps, "lp3");
+
+        let res = handle(deps.as_mut(), env, lp.clone()).unwrap();
+        assert_eq!(0, res.messages.len());
+        assert_eq!(
+            CommitmentState::EXPIRED,
+            commits::get(&deps.storage, lp).unwrap().state
+        );
+    }
+}
@@ -0,0 +1,442 @@
+use cosmwasm_std::{Addr, Coin, Env, Response, Uint128};
+use provwasm_std::types::provenance::marker::v1::MarkerQuerier;
+
+use crate::{
+    core::{
+        aliases::{ProvDepsMut, ProvMsg, ProvTxResponse},
+        error::ContractError,
+        event::{ContractEvent, EventType},
+        security::SecurityCommitment,
+    },
+    storage::{
+        distributions::{self},
+        redemptions::{self, RedemptionRequest, RedemptionWindow},
+        state::{self, DepositMode},
+    },
+    util::{
+        capital::send_capital,
+        provenance_utilities::{
+            burn_marker_supply, get_marker, get_marker_address, transfer_marker_coins,
+        },
+        to,
+    },
+};
+
+// The lp returns units of a security to the investment marker. The units are burned and paid out
+// at the window's price until the gate is reached, and anything over the gate is queued.
+pub fn handle(
+    deps: ProvDepsMut,
+    env: Env,
+    sender: Addr,
+    security: String,
+    amount: Uint128,
+) -> ProvTxResponse {
+    let state = state::get(deps.storage)?;
+    let mut window = redemptions::get_window(deps.storage, security.clone())
+        .filter(|window| window.is_open(&env.block.time))
+        .ok_or(ContractError::RedemptionWindowClosed {})?;
+
+    distributions::remove_units(deps.storage, sender.clone(), security.clone(), amount)?;
+
+    let investment_name = to::security_to_investment_name(&security, &env.contract.address);
+    let marker = get_marker(investment_name.clone(), &MarkerQuerier::new(&deps.querier))?;
+    let mut messages = vec![transfer_marker_coins(
+        amount.u128(),
+        &investment_name,
+        Addr::unchecked(get_marker_address(marker.base_account)?),
+        sender.clone(),
+        env.contract.address.clone(),
+    )?];
+    let mut events = vec![];
+
+    // Nobody can skip ahead of the lps that are already waiting in the queue
+    let redeemed = if redemptions::queued_units(deps.storage, security.clone()).is_zero() {
+        amount.min(window.remaining())
+    } else {
+        Uint128::zero()
+    };
+
+    if !redeemed.is_zero() {
+        messages.extend(pay_redemption(
+            &mut window,
+            &sender,
+            &investment_name,
+            redeemed,
+            &state.deposit_mode,
+            &state.capital_denom,
+            &env.contract.address,
+        )?);
+        events.push(redeemed_event(
+            &state.gp,
+            &sender,
+            &security,
+            redeemed,
+            &window,
+            &state.capital_denom,
+        ));
+        redemptions::set_window(deps.storage, security.clone(), &window)?;
+    }
+
+    let queued = amount - redeemed;
+    if !queued.is_zero() {
+        redemptions::push_request(
+            deps.storage,
+            security.clone(),
+            &RedemptionRequest {
+                lp: sender.clone(),
+                amount: queued,
+            },
+        )?;
+        events.push(
+            ContractEvent::new(EventType::RedemptionQueued, &state.gp)
+                .lp(&sender)
+                .securities(&[SecurityCommitment {
+                    name: security,
+                    amount: queued,
+                }]),
+        );
+    }
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_events(events)
+        .add_attribute("action", "redeem")
+        .add_attribute("lp", sender))
+}
+
+// Burns units that have been returned to the investment marker and pays the lp for them out of
+// the window's reserve
+pub fn pay_redemption(
+    window: &mut RedemptionWindow,
+    lp: &Addr,
+    investment_name: &str,
+    units: Uint128,
+    deposit_mode: &DepositMode,
+    capital_denom: &str,
+    contract: &Addr,
+) -> Result<Vec<ProvMsg>, ContractError> {
+    let payment = window.redeem(units)?;
+    Ok(vec![
+        burn_marker_supply(units.u128(), investment_name, contract.clone())?,
+        send_capital(
+            deposit_mode,
+            Coin::new(payment, capital_denom),
+            lp,
+            contract,
+        )?,
+    ])
+}
+
+pub fn redeemed_event(
+    gp: &Addr,
+    lp: &Addr,
+    security: &str,
+    units: Uint128,
+    window: &RedemptionWindow,
+    capital_denom: &str,
+) -> ContractEvent {
+    ContractEvent::new(EventType::Redeemed, gp)
+        .lp(lp)
+        .securities(&[SecurityCommitment {
+            name: security.to_string(),
+            amount: units,
+        }])
+        .amount(Coin::new(units * window.price_per_unit, capital_denom))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{
+        testing::mock_env, Addr, Attribute, BankMsg, Coin, Decimal, Env, Event, SubMsg, Uint128,
+        Uint64,
+    };
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        core::{aliases::ProvMsg, error::ContractError},
+        storage::{
+            distributions,
+            redemptions::{self, RedemptionRequest, RedemptionWindow},
+            state::DepositMode,
+        },
+        util::{
+            mock_marker::DEFAULT_MARKER_ADDRESS,
+            provenance_utilities::{burn_marker_supply, transfer_marker_coins},
+            testing::{
+                create_testing_commitments, instantiate_contract, mock_investment_markers,
+                set_deposit_mode, MockDeps,
+            },
+            to,
+        },
+    };
+
+    use super::handle;
+
+    fn open_window(deps: &mut MockDeps, env: &Env, capacity: u128) {
+        redemptions::set_window(
+            deps.as_mut().storage,
+            "Security1".to_string(),
+            &RedemptionWindow {
+                price_per_unit: Uint128::new(150),
+                start_time: Uint64::new(env.block.time.seconds()),
+                end_time: Uint64::new(env.block.time.seconds()),
+                gate: Decimal::percent(10),
+                capacity: Uint128::new(capacity),
+                redeemed: Uint128::zero(),
+                reserve: Uint128::new(capacity * 150),
+            },
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_handle_fails_without_window() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+
+        let error = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("lp7"),
+            "Security1".to_string(),
+            Uint128::new(10),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::RedemptionWindowClosed {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_fails_when_window_is_closed() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        let mut env = mock_env();
+        open_window(&mut deps, &env, 10);
+        env.block.time = env.block.time.plus_seconds(1);
+
+        let error = handle(
+            deps.as_mut(),
+            env,
+            Addr::unchecked("lp7"),
+            "Security1".to_string(),
+            Uint128::new(10),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::RedemptionWindowClosed {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_fails_without_enough_units() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        let env = mock_env();
+        open_window(&mut deps, &env, 10);
+
+        let error = handle(
+            deps.as_mut(),
+            env,
+            Addr::unchecked("lp7"),
+            "Security1".to_string(),
+            Uint128::new(101),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::InsufficientUnits {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_redeems_up_to_gate_and_queues_the_rest() {
+        let mut deps = mock_provenance_dependencies();
+        let lp = Addr::unchecked("lp7");
+        let security = "Security1".to_string();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        mock_investment_markers(&mut deps);
+        let env = mock_env();
+        open_window(&mut deps, &env, 10);
+        let investment_name = to::security_to_investment_name(&security, &env.contract.address);
+
+        let res = handle(
+            deps.as_mut(),
+            env.clone(),
+            lp.clone(),
+            security.clone(),
+            Uint128::new(15),
+        )
+        .unwrap();
+        assert_eq!(
+            vec![
+                SubMsg::new(
+                    transfer_marker_coins(
+                        15,
+                        &investment_name,
+                        Addr::unchecked(DEFAULT_MARKER_ADDRESS),
+                        lp.clone(),
+                        env.contract.address.clone(),
+                    )
+                    .unwrap()
+                ),
+                SubMsg::new(
+                    burn_marker_supply(10, &investment_name, env.contract.address.clone()).unwrap()
+                ),
+                SubMsg::new(
+                    transfer_marker_coins(
+                        1500,
+                        "denom",
+                        lp.clone(),
+                        env.contract.address.clone(),
+                        env.contract.address.clone(),
+                    )
+                    .unwrap()
+                ),
+            ],
+            res.messages
+        );
+        assert_eq!(
+            vec![
+                Event::new("redeemed")
+                    .add_attribute("gp", "gp")
+                    .add_attribute("lp", lp.clone())
+                    .add_attribute("securities", format!("{}:10", security))
+                    .add_attribute("amount", "1500denom"),
+                Event::new("redemption_queued")
+                    .add_attribute("gp", "gp")
+                    .add_attribute("lp", lp.clone())
+                    .add_attribute("securities", format!("{}:5", security)),
+            ],
+            res.events
+        );
+        assert_eq!(
+            vec![
+                Attribute::new("action", "redeem"),
+                Attribute::new("lp", "lp7")
+            ],
+            res.attributes
+        );
+
+        let window = redemptions::get_window(&deps.storage, security.clone()).unwrap();
+        assert_eq!(Uint128::new(10), window.redeemed);
+        assert_eq!(Uint128::zero(), window.reserve);
+        assert_eq!(
+            vec![(
+                0,
+                RedemptionRequest {
+                    lp: lp.clone(),
+                    amount: Uint128::new(5),
+                }
+            )],
+            redemptions::get_requests(&deps.storage, security.clone(), 10).unwrap()
+        );
+        assert_eq!(
+            Uint128::new(85),
+            distributions::get_holding(&deps.storage, lp, security)
+                .unwrap()
+                .units
+        );
+    }
+
+    #[test]
+    fn test_handle_queues_behind_waiting_requests() {
+        let mut deps = mock_provenance_dependencies();
+        let lp = Addr::unchecked("lp7");
+        let security = "Security1".to_string();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        mock_investment_markers(&mut deps);
+        let env = mock_env();
+        open_window(&mut deps, &env, 10);
+        let waiting = RedemptionRequest {
+            lp: Addr::unchecked("lp1"),
+            amount: Uint128::new(20),
+        };
+        redemptions::push_request(deps.as_mut().storage, security.clone(), &waiting).unwrap();
+
+        let res = handle(
+            deps.as_mut(),
+            env,
+            lp.clone(),
+            security.clone(),
+            Uint128::new(5),
+        )
+        .unwrap();
+        assert_eq!(1, res.messages.len());
+        assert_eq!(
+            vec![
+                (0, waiting),
+                (
+                    1,
+                    RedemptionRequest {
+                        lp,
+                        amount: Uint128::new(5),
+                    }
+                )
+            ],
+            redemptions::get_requests(&deps.storage, security, 10).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_handle_pays_attached_funds_with_a_bank_send() {
+        let mut deps = mock_provenance_dependencies();
+        let lp = Addr::unchecked("lp7");
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        mock_investment_markers(&mut deps);
+        set_deposit_mode(deps.as_mut().storage, DepositMode::Funds);
+        let env = mock_env();
+        open_window(&mut deps, &env, 10);
+
+        let res = handle(
+            deps.as_mut(),
+            env,
+            lp.clone(),
+            "Security1".to_string(),
+            Uint128::new(4),
+        )
+        .unwrap();
+        assert_eq!(
+            SubMsg::new(ProvMsg::Bank(BankMsg::Send {
+                to_address: lp.to_string(),
+                amount: vec![Coin::new(Uint128::new(600), "denom")],
+            })),
+            res.messages[2]
+        );
+    }
+
+    #[test]
+    fn test_handle_fails_when_reserve_is_short() {
+        let mut deps = mock_provenance_dependencies();
+        let security = "Security1".to_string();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        mock_investment_markers(&mut deps);
+        let env = mock_env();
+        open_window(&mut deps, &env, 10);
+        let mut window = redemptions::get_window(&deps.storage, security.clone()).unwrap();
+        window.reserve = Uint128::new(1499);
+        redemptions::set_window(deps.as_mut().storage, security.clone(), &window).unwrap();
+
+        let error = handle(
+            deps.as_mut(),
+            env,
+            Addr::unchecked("lp7"),
+            security,
+            Uint128::new(10),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::InsufficientRedemptionReserve {}.to_string(),
+            error.to_string()
+        );
+    }
+}
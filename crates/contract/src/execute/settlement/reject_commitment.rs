@@ -0,0 +1,232 @@
+use cosmwasm_std::{Addr, Env, Response};
+
+use crate::{
+    core::{
+        aliases::{ProvDepsMut, ProvTxResponse},
+        error::ContractError,
+        event::{ContractEvent, EventType},
+    },
+    storage::{
+        commitment_history,
+        commits::{self},
+    },
+    util::auth::{authorize, Role},
+};
+
+use super::{cancel_commitment::refund_lp, commitment::CommitmentState};
+
+pub fn handle(
+    deps: ProvDepsMut,
+    env: Env,
+    sender: Addr,
+    lp: Addr,
+    reason: String,
+) -> ProvTxResponse {
+    let state = authorize(deps.storage, &sender, Role::Gp)?;
+
+    let mut commitment = commits::get(deps.storage, lp.clone())?;
+    if commitment.state == CommitmentState::SETTLED {
+        return Err(ContractError::AlreadySettled {});
+    }
+    if commitment.state.is_terminated() {
+        return Err(ContractError::InvalidCommitmentState {});
+    }
+
+    let (refund_messages, refunded) = refund_lp(
+        deps.storage,
+        &state.deposit_mode,
+        &env.contract.address,
+        lp.clone(),
+    )?;
+
+    commitment.state = CommitmentState::REJECTED;
+    commitment.reason = Some(reason.clone());
+    commits::set(deps.storage, &commitment)?;
+    commitment_history::record(
+        deps.storage,
+        &env,
+        &lp,
+        &sender,
+        "rejected",
+        &commitment.commitments,
+    )?;
+
+    let mut event = ContractEvent::new(EventType::Rejected, &state.gp)
+        .lp(&lp)
+        .securities(&commitment.commitments)
+        .state(&commitment.state);
+    if let Some(refunded) = refunded {
+        event = event.amount(refunded);
+    }
+
+    Ok(Response::new()
+        .add_messages(refund_messages)
+        .add_event(event.attribute("reason", reason))
+        .add_attribute("action", "reject_commitment")
+        .add_attribute("gp", sender))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{testing::mock_env, Addr, Attribute, BankMsg, Coin, Event, SubMsg, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        core::{aliases::ProvMsg, error::ContractError},
+        execute::settlement::commitment::CommitmentState,
+        storage::{available_capital, commits, state::DepositMode},
+        util::{
+            provenance_utilities::transfer_marker_coins,
+            testing::{create_testing_commitments, instantiate_contract, set_deposit_mode},
+        },
+    };
+
+    use super::handle;
+
+    #[test]
+    fn test_handle_must_be_triggered_by_gp() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+
+        let error = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("lp4"),
+            Addr::unchecked("lp4"),
+            "reason".to_string(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_fails_when_settled() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+
+        let error = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            Addr::unchecked("lp7"),
+            "reason".to_string(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::AlreadySettled {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_rejects_pending_commitment() {
+        let mut deps = mock_provenance_dependencies();
+        let lp = Addr::unchecked("lp4");
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+
+        let res = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            lp.clone(),
+            "oversubscribed".to_string(),
+        )
+        .unwrap();
+        assert_eq!(0, res.messages.len());
+        assert_eq!(
+            vec![
+                Attribute::new("action", "reject_commitment"),
+                Attribute::new("gp", "gp")
+            ],
+            res.attributes
+        );
+        assert_eq!(
+            vec![Event::new("rejected")
+                .add_attribute("gp", "gp")
+                .add_attribute("lp", lp.clone())
+                .add_attribute("securities", "Security1:100,Security2:100")
+                .add_attribute("state", "rejected")
+                .add_attribute("reason", "oversubscribed")],
+            res.events
+        );
+
+        let commitment = commits::get(&deps.storage, lp.clone()).unwrap();
+        assert_eq!(CommitmentState::REJECTED, commitment.state);
+        assert_eq!(Some("oversubscribed".to_string()), commitment.reason);
+
+        // A rejected commitment can't be rejected again
+        let error = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            lp,
+            "oversubscribed".to_string(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidCommitmentState {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_rejects_and_refunds_accepted_commitment() {
+        let mut deps = mock_provenance_dependencies();
+        let lp = Addr::unchecked("lp2");
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+
+        let res = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            lp.clone(),
+            "failed kyc".to_string(),
+        )
+        .unwrap();
+        let contract = mock_env().contract.address;
+        assert_eq!(
+            vec![SubMsg::new(
+                transfer_marker_coins(10000, "denom", lp.clone(), contract.clone(), contract)
+                    .unwrap()
+            )],
+            res.messages
+        );
+        assert_eq!(false, available_capital::has_lp(&deps.storage, lp.clone()));
+
+        let commitment = commits::get(&deps.storage, lp).unwrap();
+        assert_eq!(CommitmentState::REJECTED, commitment.state);
+        assert_eq!(Some("failed kyc".to_string()), commitment.reason);
+    }
+
+    #[test]
+    fn test_handle_sends_attached_funds_back() {
+        let mut deps = mock_provenance_dependencies();
+        let lp = Addr::unchecked("lp2");
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        set_deposit_mode(deps.as_mut().storage, DepositMode::Funds);
+
+        let res = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            lp.clone(),
+            "failed kyc".to_string(),
+        )
+        .unwrap();
+        assert_eq!(
+            vec![SubMsg::new(ProvMsg::Bank(BankMsg::Send {
+                to_address: lp.to_string(),
+                amount: vec![Coin::new(Uint128::new(10000), "denom")],
+            }))],
+            res.messages
+        );
+    }
+}
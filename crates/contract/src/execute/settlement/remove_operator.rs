@@ -0,0 +1,88 @@
+use cosmwasm_std::{Addr, Env, Response};
+
+use crate::{
+    core::{
+        aliases::{ProvDepsMut, ProvTxResponse},
+        event::{ContractEvent, EventType},
+    },
+    storage::roles,
+    util::auth::{authorize, Role},
+};
+
+pub fn handle(deps: ProvDepsMut, _env: Env, sender: Addr, operator: Addr) -> ProvTxResponse {
+    authorize(deps.storage, &sender, Role::Gp)?;
+
+    roles::remove_operator(deps.storage, operator.clone());
+
+    Ok(Response::new()
+        .add_event(
+            ContractEvent::new(EventType::OperatorRemoved, &sender).attribute("operator", operator),
+        )
+        .add_attribute("action", "remove_operator")
+        .add_attribute("gp", sender))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{testing::mock_env, Addr, Attribute, Event};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        core::error::ContractError,
+        storage::roles,
+        util::testing::{add_operator_test, instantiate_contract},
+    };
+
+    use super::handle;
+
+    #[test]
+    fn test_handle_must_be_gp() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        add_operator_test(deps.as_mut(), mock_env(), "gp", "operator").unwrap();
+
+        let error = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("operator"),
+            Addr::unchecked("operator"),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_removes_operator() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        add_operator_test(deps.as_mut(), mock_env(), "gp", "operator").unwrap();
+
+        let res = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            Addr::unchecked("operator"),
+        )
+        .unwrap();
+        assert_eq!(
+            vec![
+                Attribute::new("action", "remove_operator"),
+                Attribute::new("gp", "gp")
+            ],
+            res.attributes
+        );
+        assert_eq!(
+            vec![Event::new("operator_removed")
+                .add_attribute("gp", "gp")
+                .add_attribute("operator", "operator")],
+            res.events
+        );
+        assert!(!roles::is_operator(
+            &deps.storage,
+            Addr::unchecked("operator")
+        ));
+    }
+}
@@ -1,13 +1,9 @@
 use cosmwasm_std::{Addr, Response};
 
+use crate::core::aliases::{ProvDepsMut, ProvTxResponse};
+use crate::core::event::{ContractEvent, EventType};
 use crate::storage::whitelist_contributors_store::remove_contributors;
-use crate::{
-    core::{
-        aliases::{ProvDepsMut, ProvTxResponse},
-        error::ContractError,
-    },
-    storage::state::{self},
-};
+use crate::util::auth::{authorize, Role};
 
 /// Handle function that processes removal of contributors from the loan pool whitelist.
 ///
@@ -27,23 +23,21 @@ use crate::{
 /// * if the sender is not the same as the "gp".
 /// * if the contract's storage is not populated.
 pub fn handle(mut deps: ProvDepsMut, sender: Addr, contributors: Vec<Addr>) -> ProvTxResponse {
-    let state = state::get(deps.storage)?;
-    if sender != state.gp {
-        // only gp can add whitelisted contributor
-        return Err(ContractError::Unauthorized {});
-    }
+    authorize(deps.storage, &sender, Role::Gp)?;
 
-    remove_loan_pool_contributors(&mut deps, contributors)
+    remove_loan_pool_contributors(&mut deps, &sender, contributors)
 }
 
 /// Helper function to remove contributors from the loan pool.
 ///
 /// This function is invoked by the handle function to execute the removal of contributors.
 /// It removes the contributors from the contract storage and constructs a response
-/// indicating success and specifying the contributors that were removed.
+/// indicating success and specifying the contributors that were removed, along with a
+/// `loan_pool_contributors_removed` event for the gp.
 ///
 /// Parameters:
 /// * `deps`: the storage dependency object that provides access to the relevant dependencies.
+/// * `gp`: the address of the gp that is removing the contributors.
 /// * `loan_pool_contributors`: a vector of contributor addresses that are supposed to be removed from the loan pool.
 ///
 /// Returns:
@@ -53,6 +47,7 @@ pub fn handle(mut deps: ProvDepsMut, sender: Addr, contributors: Vec<Addr>) -> P
 /// * if unable to remove contributors from the contract's storage.
 pub fn remove_loan_pool_contributors(
     deps: &mut ProvDepsMut,
+    gp: &Addr,
     loan_pool_contributors: Vec<Addr>,
 ) -> ProvTxResponse {
     remove_contributors(deps.storage, loan_pool_contributors.clone())?;
@@ -66,6 +61,10 @@ pub fn remove_loan_pool_contributors(
     let contributors_str = contributors_as_str.join(",");
 
     let response = Response::new()
+        .add_event(
+            ContractEvent::new(EventType::LoanPoolContributorsRemoved, gp)
+                .attribute("contributors", contributors_str.clone()),
+        )
         .add_attribute("action", "whitelist_removed")
         .add_attribute("whitelist_address_removed", contributors_str);
 
@@ -75,9 +74,10 @@ pub fn remove_loan_pool_contributors(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::error::ContractError;
     use crate::util::testing::create_test_state;
     use cosmwasm_std::testing::mock_env;
-    use cosmwasm_std::{Addr, StdResult};
+    use cosmwasm_std::{Addr, Event, StdResult};
     use provwasm_mocks::mock_provenance_dependencies;
 
     #[test]
@@ -97,6 +97,12 @@ mod tests {
         assert_eq!(response.attributes[0].value, "whitelist_removed");
         assert_eq!(response.attributes[1].key, "whitelist_address_removed");
         assert_eq!(response.attributes[1].value, "addr1,addr2");
+        assert_eq!(
+            vec![Event::new("loan_pool_contributors_removed")
+                .add_attribute("gp", "gp")
+                .add_attribute("contributors", "addr1,addr2")],
+            response.events
+        );
 
         // Test adding contributors by someone else
         let result = handle(deps.as_mut(), other.clone(), contributors.clone());
@@ -0,0 +1,225 @@
+use cosmwasm_std::{Addr, Env, Response, Uint128};
+
+use crate::{
+    core::{
+        aliases::{ProvDepsMut, ProvTxResponse},
+        error::ContractError,
+        event::{ContractEvent, EventType},
+        security::SecurityCommitment,
+    },
+    storage::{
+        remaining_securities,
+        securities::{self},
+    },
+    util::auth::{authorize, Role},
+};
+
+// Changes the total units offered for a security. Units that have already been accepted stay
+// reserved, so the security can't be made smaller than them.
+pub fn handle(
+    deps: ProvDepsMut,
+    _env: Env,
+    sender: Addr,
+    security: String,
+    amount: Uint128,
+) -> ProvTxResponse {
+    authorize(deps.storage, &sender, Role::Gp)?;
+
+    let mut existing = securities::get(deps.storage, security.clone())?;
+    let remaining = Uint128::new(remaining_securities::get(deps.storage, security.clone())?);
+    let accepted = existing
+        .amount
+        .checked_sub(remaining)
+        .map_err(|_| ContractError::InvalidSecurityResize {})?;
+    if amount < accepted {
+        return Err(ContractError::InvalidSecurityResize {});
+    }
+
+    if amount < existing.minimum_amount {
+        return Err(ContractError::InvalidSecurityList {});
+    }
+
+    existing.amount = amount;
+    securities::set(deps.storage, &existing)?;
+    remaining_securities::set(deps.storage, security.clone(), (amount - accepted).u128())?;
+
+    Ok(Response::new()
+        .add_event(
+            ContractEvent::new(EventType::SecurityResized, &sender).securities(&[
+                SecurityCommitment {
+                    name: security,
+                    amount,
+                },
+            ]),
+        )
+        .add_attribute("action", "resize_security")
+        .add_attribute("gp", sender))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{testing::mock_env, Addr, Attribute, Event, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        core::error::ContractError,
+        storage::{remaining_securities, securities},
+        util::testing::{create_testing_commitments, instantiate_contract},
+    };
+
+    use super::handle;
+
+    #[test]
+    fn test_handle_must_be_gp() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+
+        let error = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("lp"),
+            "Security1".to_string(),
+            Uint128::new(2000),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_fails_for_invalid_security() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+
+        handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            "Security3".to_string(),
+            Uint128::new(2000),
+        )
+        .unwrap_err();
+    }
+
+    #[test]
+    fn test_handle_cannot_drop_below_accepted() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+
+        // lp1, lp2, lp3 and lp7 each have 100 units of Security1 accepted
+        let error = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            "Security1".to_string(),
+            Uint128::new(399),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidSecurityResize {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_fails_when_remaining_exceeds_amount() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        let amount = securities::get(&deps.storage, "Security1".to_string())
+            .unwrap()
+            .amount;
+        remaining_securities::set(
+            deps.as_mut().storage,
+            "Security1".to_string(),
+            amount.u128() + 1,
+        )
+        .unwrap();
+
+        let error = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            "Security1".to_string(),
+            Uint128::new(2000),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidSecurityResize {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_cannot_drop_below_minimum() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+
+        let error = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            "Security1".to_string(),
+            Uint128::new(9),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidSecurityList {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_resizes_security() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+
+        let res = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            "Security1".to_string(),
+            Uint128::new(400),
+        )
+        .unwrap();
+        assert_eq!(
+            vec![
+                Attribute::new("action", "resize_security"),
+                Attribute::new("gp", "gp")
+            ],
+            res.attributes
+        );
+        assert_eq!(
+            vec![Event::new("security_resized")
+                .add_attribute("gp", "gp")
+                .add_attribute("securities", "Security1:400")],
+            res.events
+        );
+        assert_eq!(
+            Uint128::new(400),
+            securities::get(&deps.storage, "Security1".to_string())
+                .unwrap()
+                .amount
+        );
+        assert_eq!(
+            0,
+            remaining_securities::get(&deps.storage, "Security1".to_string()).unwrap()
+        );
+
+        handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            "Security1".to_string(),
+            Uint128::new(2000),
+        )
+        .unwrap();
+        assert_eq!(
+            1600,
+            remaining_securities::get(&deps.storage, "Security1".to_string()).unwrap()
+        );
+    }
+}
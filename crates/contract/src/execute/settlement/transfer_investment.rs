@@ -0,0 +1,257 @@
+use cosmwasm_std::{Addr, Env, Response, Uint128};
+
+use crate::{
+    core::{
+        aliases::{ProvDepsMut, ProvTxResponse},
+        error::ContractError,
+        event::{ContractEvent, EventType},
+        security::SecurityCommitment,
+    },
+    storage::{
+        distributions::{self},
+        securities::{self},
+        settlement_times,
+        state::{self},
+    },
+    util::{provenance_utilities::transfer_marker_coins, settlement::is_eligible, to},
+};
+
+// Moves investment tokens between holders of the restricted marker. The tokens are locked up for
+// a period after the holder settles, and the recipient must be eligible to invest in the security.
+pub fn handle(
+    deps: ProvDepsMut,
+    env: Env,
+    sender: Addr,
+    security: String,
+    recipient: Addr,
+    amount: Uint128,
+) -> ProvTxResponse {
+    let state = state::get(deps.storage)?;
+    securities::get(deps.storage, security.clone())?;
+    if recipient == sender || recipient == env.contract.address {
+        return Err(ContractError::InvalidInvestmentTransfer {});
+    }
+
+    if let (Some(lockup_period), Some(settlement_time)) = (
+        state.lockup_period,
+        settlement_times::get(deps.storage, sender.clone()),
+    ) {
+        // A lockup that ends past the largest time never ends
+        let unlocked = settlement_time
+            .checked_add(lockup_period)
+            .is_ok_and(|unlock_time| env.block.time.seconds() >= unlock_time.u64());
+        if !unlocked {
+            return Err(ContractError::InvestmentLocked {});
+        }
+    }
+
+    if !is_eligible(&deps.as_ref(), &recipient, security.clone())? {
+        return Err(ContractError::IneligibleLp { security });
+    }
+
+    distributions::remove_units(deps.storage, sender.clone(), security.clone(), amount)?;
+    distributions::add_units(deps.storage, recipient.clone(), security.clone(), amount)?;
+
+    let investment_name = to::security_to_investment_name(&security, &env.contract.address);
+    Ok(Response::new()
+        .add_message(transfer_marker_coins(
+            amount.u128(),
+            investment_name,
+            recipient.clone(),
+            sender.clone(),
+            env.contract.address,
+        )?)
+        .add_event(
+            ContractEvent::new(EventType::InvestmentTransferred, &state.gp)
+                .lp(&sender)
+                .securities(&[SecurityCommitment {
+                    name: security,
+                    amount,
+                }])
+                .attribute("recipient", recipient),
+        )
+        .add_attribute("action", "transfer_investment")
+        .add_attribute("lp", sender))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{testing::mock_env, Addr, Attribute, Event, SubMsg, Uint128, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        core::error::ContractError,
+        storage::{distributions, eligibility, state},
+        util::{
+            provenance_utilities::transfer_marker_coins,
+            testing::{
+                create_testing_commitments, instantiate_contract, mock_lp_attributes, MockDeps,
+            },
+            to,
+        },
+    };
+
+    use super::handle;
+
+    fn transfer(deps: &mut MockDeps, seconds: u64, recipient: &str) -> Result<(), ContractError> {
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(seconds);
+        handle(
+            deps.as_mut(),
+            env,
+            Addr::unchecked("lp7"),
+            "Security1".to_string(),
+            Addr::unchecked(recipient),
+            Uint128::new(40),
+        )
+        .map(|_| ())
+    }
+
+    fn set_lockup_period(deps: &mut MockDeps, lockup_period: u64) {
+        let mut contract_state = state::get(&deps.storage).unwrap();
+        contract_state.lockup_period = Some(Uint64::new(lockup_period));
+        state::set(deps.as_mut().storage, &contract_state).unwrap();
+    }
+
+    #[test]
+    fn test_handle_fails_for_invalid_recipient() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+
+        for recipient in ["lp7", mock_env().contract.address.as_str()] {
+            let error = transfer(&mut deps, 0, recipient).unwrap_err();
+            assert_eq!(
+                ContractError::InvalidInvestmentTransfer {}.to_string(),
+                error.to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn test_handle_fails_during_lockup() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        set_lockup_period(&mut deps, 100);
+
+        let error = transfer(&mut deps, 99, "buyer").unwrap_err();
+        assert_eq!(
+            ContractError::InvestmentLocked {}.to_string(),
+            error.to_string()
+        );
+        transfer(&mut deps, 100, "buyer").unwrap();
+    }
+
+    #[test]
+    fn test_handle_fails_when_lockup_never_ends() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        set_lockup_period(&mut deps, u64::MAX);
+
+        let error = transfer(&mut deps, 100, "buyer").unwrap_err();
+        assert_eq!(
+            ContractError::InvestmentLocked {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_fails_for_ineligible_recipient() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        eligibility::set_required_attributes(
+            deps.as_mut().storage,
+            "Security1".to_string(),
+            &vec!["accredited.investor.pb".to_string()],
+        )
+        .unwrap();
+        mock_lp_attributes(&mut deps, &[("accredited", "accredited.investor.pb")]);
+
+        let error = transfer(&mut deps, 0, "buyer").unwrap_err();
+        assert_eq!(
+            ContractError::IneligibleLp {
+                security: "Security1".to_string()
+            }
+            .to_string(),
+            error.to_string()
+        );
+        transfer(&mut deps, 0, "accredited").unwrap();
+    }
+
+    #[test]
+    fn test_handle_fails_without_enough_units() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        transfer(&mut deps, 0, "buyer").unwrap();
+        transfer(&mut deps, 0, "buyer").unwrap();
+
+        let error = transfer(&mut deps, 0, "buyer").unwrap_err();
+        assert_eq!(
+            ContractError::InsufficientUnits {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_transfers_units() {
+        let mut deps = mock_provenance_dependencies();
+        let security = "Security1".to_string();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        let env = mock_env();
+
+        let res = handle(
+            deps.as_mut(),
+            env.clone(),
+            Addr::unchecked("lp7"),
+            security.clone(),
+            Addr::unchecked("buyer"),
+            Uint128::new(40),
+        )
+        .unwrap();
+        assert_eq!(
+            vec![SubMsg::new(
+                transfer_marker_coins(
+                    40,
+                    to::security_to_investment_name(&security, &env.contract.address),
+                    Addr::unchecked("buyer"),
+                    Addr::unchecked("lp7"),
+                    env.contract.address.clone(),
+                )
+                .unwrap()
+            )],
+            res.messages
+        );
+        assert_eq!(
+            vec![Event::new("investment_transferred")
+                .add_attribute("gp", "gp")
+                .add_attribute("lp", "lp7")
+                .add_attribute("securities", format!("{}:40", security))
+                .add_attribute("recipient", "buyer")],
+            res.events
+        );
+        assert_eq!(
+            vec![
+                Attribute::new("action", "transfer_investment"),
+                Attribute::new("lp", "lp7")
+            ],
+            res.attributes
+        );
+
+        let units = |lp: &str| {
+            distributions::get_holding(&deps.storage, Addr::unchecked(lp), security.clone())
+                .unwrap()
+                .units
+        };
+        assert_eq!(Uint128::new(60), units("lp7"));
+        assert_eq!(Uint128::new(40), units("buyer"));
+        assert_eq!(
+            Uint128::new(100),
+            distributions::get_index(&deps.storage, security.clone()).total_units
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use cosmwasm_std::{Addr, Env, Response};
+
+use crate::{
+    core::{
+        aliases::{ProvDepsMut, ProvTxResponse},
+        event::{ContractEvent, EventType},
+    },
+    storage::{
+        eligibility::{self},
+        securities::{self},
+    },
+    util::auth::{authorize, Role},
+};
+
+// Replaces the attributes an lp must hold to propose a commitment to the security. An empty list
+// opens the security to every lp.
+pub fn handle(
+    deps: ProvDepsMut,
+    _env: Env,
+    sender: Addr,
+    security: String,
+    required_attributes: Vec<String>,
+) -> ProvTxResponse {
+    authorize(deps.storage, &sender, Role::Gp)?;
+
+    securities::get(deps.storage, security.clone())?;
+    eligibility::set_required_attributes(deps.storage, security.clone(), &required_attributes)?;
+
+    Ok(Response::new()
+        .add_event(
+            ContractEvent::new(EventType::EligibilityUpdated, &sender)
+                .attribute("security", security)
+                .attribute("required_attributes", required_attributes.join(",")),
+        )
+        .add_attribute("action", "update_eligibility")
+        .add_attribute("gp", sender))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{testing::mock_env, Addr, Attribute, Event};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        core::error::ContractError, storage::eligibility, util::testing::instantiate_contract,
+    };
+
+    use super::handle;
+
+    fn test_attributes() -> Vec<String> {
+        vec![
+            "accredited.investor.pb".to_string(),
+            "kyc.investor.pb".to_string(),
+        ]
+    }
+
+    #[test]
+    fn test_handle_must_be_gp() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+
+        let error = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("lp"),
+            "Security1".to_string(),
+            test_attributes(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_fails_for_invalid_security() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+
+        handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            "Security3".to_string(),
+            test_attributes(),
+        )
+        .unwrap_err();
+    }
+
+    #[test]
+    fn test_handle_updates_required_attributes() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+
+        let res = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            "Security1".to_string(),
+            test_attributes(),
+        )
+        .unwrap();
+        assert_eq!(
+            vec![
+                Attribute::new("action", "update_eligibility"),
+                Attribute::new("gp", "gp")
+            ],
+            res.attributes
+        );
+        assert_eq!(
+            vec![Event::new("eligibility_updated")
+                .add_attribute("gp", "gp")
+                .add_attribute("security", "Security1")
+                .add_attribute(
+                    "required_attributes",
+                    "accredited.investor.pb,kyc.investor.pb"
+                )],
+            res.events
+        );
+        assert_eq!(
+            test_attributes(),
+            eligibility::get_required_attributes(&deps.storage, "Security1".to_string())
+        );
+
+        handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            "Security1".to_string(),
+            vec![],
+        )
+        .unwrap();
+        assert!(
+            eligibility::get_required_attributes(&deps.storage, "Security1".to_string()).is_empty()
+        );
+    }
+}
@@ -0,0 +1,177 @@
+use cosmwasm_std::{Addr, Coin, Env, Response};
+
+use crate::{
+    core::{
+        aliases::{ProvDepsMut, ProvTxResponse},
+        error::ContractError,
+        event::{ContractEvent, EventType},
+        security::SecurityType,
+    },
+    storage::{
+        nav::{self},
+        securities::{self},
+        state::{self},
+    },
+    util::auth::{authorize, Role},
+};
+
+// Publishes the NAV per unit of a fund security. Deposits are priced against the latest NAV.
+pub fn handle(
+    deps: ProvDepsMut,
+    env: Env,
+    sender: Addr,
+    security: String,
+    nav_per_unit: Coin,
+) -> ProvTxResponse {
+    let fund = match securities::get(deps.storage, security.clone())?.security_type {
+        SecurityType::Fund(fund) => fund,
+        _ => return Err(ContractError::InvalidNav {}),
+    };
+
+    let state = match fund.pricing_agent {
+        Some(pricing_agent) if sender == pricing_agent => state::get(deps.storage)?,
+        _ => authorize(deps.storage, &sender, Role::Gp)?,
+    };
+
+    if nav_per_unit.denom != state.capital_denom {
+        return Err(ContractError::InvalidNav {});
+    }
+
+    let entry = nav::add(deps.storage, &env, security.clone(), nav_per_unit)?;
+
+    Ok(Response::new()
+        .add_event(
+            ContractEvent::new(EventType::NavUpdated, &state.gp)
+                .attribute("security", security)
+                .attribute("nav_per_unit", entry.nav_per_unit.to_string())
+                .attribute("sequence", entry.sequence.to_string())
+                .attribute("time", entry.time),
+        )
+        .add_attribute("action", "update_nav")
+        .add_attribute("sender", sender))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{testing::mock_env, Addr, Attribute, Coin, Event, Uint128, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        core::{
+            error::ContractError,
+            security::{FundSecurity, SecurityType, TrancheSecurity},
+        },
+        storage::{
+            nav::{self, NavEntry},
+            securities,
+        },
+        util::testing::{instantiate_contract, MockDeps},
+    };
+
+    use super::handle;
+
+    fn set_security_type(deps: &mut MockDeps, security_type: SecurityType) {
+        let mut security = securities::get(&deps.storage, "Security1".to_string()).unwrap();
+        security.security_type = security_type;
+        securities::set(deps.as_mut().storage, &security).unwrap();
+    }
+
+    #[test]
+    fn test_handle_must_be_gp_or_pricing_agent() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+
+        let error = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("agent"),
+            "Security1".to_string(),
+            Coin::new(Uint128::new(110), "denom"),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_fails_for_non_fund() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        set_security_type(&mut deps, SecurityType::Tranche(TrancheSecurity::default()));
+
+        let error = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            "Security1".to_string(),
+            Coin::new(Uint128::new(110), "denom"),
+        )
+        .unwrap_err();
+        assert_eq!(ContractError::InvalidNav {}.to_string(), error.to_string());
+    }
+
+    #[test]
+    fn test_handle_fails_for_wrong_denom() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+
+        let error = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            "Security1".to_string(),
+            Coin::new(Uint128::new(110), "other"),
+        )
+        .unwrap_err();
+        assert_eq!(ContractError::InvalidNav {}.to_string(), error.to_string());
+    }
+
+    #[test]
+    fn test_handle_pricing_agent_can_publish() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        set_security_type(
+            &mut deps,
+            SecurityType::Fund(FundSecurity {
+                pricing_agent: Some(Addr::unchecked("agent")),
+            }),
+        );
+        let env = mock_env();
+
+        let res = handle(
+            deps.as_mut(),
+            env.clone(),
+            Addr::unchecked("agent"),
+            "Security1".to_string(),
+            Coin::new(Uint128::new(110), "denom"),
+        )
+        .unwrap();
+        assert_eq!(
+            vec![
+                Attribute::new("action", "update_nav"),
+                Attribute::new("sender", "agent")
+            ],
+            res.attributes
+        );
+        assert_eq!(
+            vec![Event::new("nav_updated")
+                .add_attribute("gp", "gp")
+                .add_attribute("security", "Security1")
+                .add_attribute("nav_per_unit", "110denom")
+                .add_attribute("sequence", "0")
+                .add_attribute("time", env.block.time.seconds().to_string())],
+            res.events
+        );
+        assert_eq!(
+            Some(NavEntry {
+                sequence: 0,
+                nav_per_unit: Coin::new(Uint128::new(110), "denom"),
+                time: Uint64::new(env.block.time.seconds()),
+                height: Uint64::new(env.block.height),
+            }),
+            nav::get_latest(&deps.storage, "Security1".to_string()).unwrap()
+        );
+    }
+}
@@ -4,28 +4,41 @@ use crate::{
     core::{
         aliases::{ProvDepsMut, ProvTxResponse},
         error::ContractError,
+        event::{ContractEvent, EventType},
     },
     storage::{commits, state},
+    util::auth::{authorize, Role},
 };
 
 pub fn handle(deps: ProvDepsMut, sender: Addr, settlement_time: Option<Uint64>) -> ProvTxResponse {
-    let state = state::get(deps.storage)?;
-    if sender != state.gp {
-        return Err(ContractError::Unauthorized {});
+    let state = authorize(deps.storage, &sender, Role::Gp)?;
+
+    // Removing the settlement time would leave a minimum raise escrow that never resolves
+    if settlement_time.is_none() && state.minimum_raise.is_some() {
+        return Err(ContractError::MinimumRaiseRequiresSettlementTime {});
     }
 
     state::set_settlement_time(deps.storage, settlement_time)?;
     commits::set_settlement_time(deps.storage, settlement_time)?;
-    Ok(Response::default().add_attribute("action", "update_settlement_time"))
+
+    // Without a settlement time commitments no longer expire
+    let mut event = ContractEvent::new(EventType::SettlementTimeUpdated, &sender);
+    if let Some(settlement_time) = settlement_time {
+        event = event.attribute("settlement_time", settlement_time);
+    }
+    Ok(Response::default()
+        .add_event(event)
+        .add_attribute("action", "update_settlement_time"))
 }
 
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::{testing::mock_env, Addr, Attribute, Uint64};
+    use cosmwasm_std::{testing::mock_env, Addr, Attribute, Event, Uint128, Uint64};
     use provwasm_mocks::mock_provenance_dependencies;
 
     use crate::{
-        core::error::ContractError,
+        core::{error::ContractError, security::MinimumRaise},
+        storage::state,
         util::testing::{create_test_state, instantiate_contract},
     };
 
@@ -49,10 +62,37 @@ mod tests {
         instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
         create_test_state(&mut deps, &env, false);
         let res = super::handle(deps.as_mut(), sender, settlement_time).unwrap();
-        assert_eq!(0, res.events.len());
+        assert_eq!(
+            vec![Event::new("settlement_time_updated")
+                .add_attribute("gp", "gp")
+                .add_attribute("settlement_time", "9999")],
+            res.events
+        );
         assert_eq!(
             vec![Attribute::new("action", "update_settlement_time")],
             res.attributes
         );
     }
+
+    #[test]
+    fn test_handle_should_fail_to_remove_settlement_time_with_minimum_raise() {
+        let mut deps = mock_provenance_dependencies();
+        let env = mock_env();
+        create_test_state(&mut deps, &env, true);
+        let mut state = state::get(&deps.storage).unwrap();
+        state.minimum_raise = Some(MinimumRaise::Offering(Uint128::new(1000)));
+        state::set(deps.as_mut().storage, &state).unwrap();
+
+        let err = super::handle(deps.as_mut(), Addr::unchecked("gp"), None).unwrap_err();
+        assert_eq!(
+            ContractError::MinimumRaiseRequiresSettlementTime {}.to_string(),
+            err.to_string()
+        );
+        super::handle(
+            deps.as_mut(),
+            Addr::unchecked("gp"),
+            Some(Uint64::new(9999)),
+        )
+        .expect("should be able to move the settlement time");
+    }
 }
@@ -1,22 +1,14 @@
 use cosmwasm_std::{Addr, Response};
 
+use crate::core::aliases::{ProvDepsMut, ProvTxResponse};
+use crate::core::event::{ContractEvent, EventType};
 use crate::storage::whitelist_contributors_store::save_contributors;
-use crate::{
-    core::{
-        aliases::{ProvDepsMut, ProvTxResponse},
-        error::ContractError,
-    },
-    storage::state::{self},
-};
+use crate::util::auth::{authorize, Role};
 
 pub fn handle(mut deps: ProvDepsMut, sender: Addr, contributors: Vec<Addr>) -> ProvTxResponse {
-    let state = state::get(deps.storage)?;
-    if sender != state.gp {
-        // only gp can add whitelisted contributor
-        return Err(ContractError::Unauthorized {});
-    }
+    authorize(deps.storage, &sender, Role::Gp)?;
 
-    loan_pool_contributors(&mut deps, contributors)
+    loan_pool_contributors(&mut deps, &sender, contributors)
 }
 
 /// This method is used to process loan pool contributors.
@@ -24,10 +16,11 @@ pub fn handle(mut deps: ProvDepsMut, sender: Addr, contributors: Vec<Addr>) -> P
 /// Then it first transforms each contributor `Addr` object into a String,
 /// and joins them into a single `String` separated by commas.
 /// Finally, it generates a `Response` with a couple of attributes: action and addresses,
-/// and returns this Response. The method signature suggests it can also return
+/// along with a `loan_pool_contributors_whitelisted` event for the gp, and returns this Response. The method signature suggests it can also return
 /// an error, which would likely be if saving contributors fails.
 pub fn loan_pool_contributors(
     deps: &mut ProvDepsMut,
+    gp: &Addr,
     loan_pool_contributors: Vec<Addr>,
 ) -> ProvTxResponse {
     save_contributors(deps.storage, loan_pool_contributors.clone())?;
@@ -39,6 +32,10 @@ pub fn loan_pool_contributors(
     // Joining Vec<String> into a single String
     let contributors_str = contributors_as_str.join(",");
     let response = Response::new()
+        .add_event(
+            ContractEvent::new(EventType::LoanPoolContributorsWhitelisted, gp)
+                .attribute("contributors", contributors_str.clone()),
+        )
         .add_attribute("action", "whitelist_added")
         .add_attribute("addresses_whitelisted", contributors_str);
     Ok(response)
@@ -47,9 +44,10 @@ pub fn loan_pool_contributors(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::error::ContractError;
     use crate::util::testing::create_test_state;
     use cosmwasm_std::testing::mock_env;
-    use cosmwasm_std::{Addr, StdResult};
+    use cosmwasm_std::{Addr, Event, StdResult};
     use provwasm_mocks::mock_provenance_dependencies;
 
     #[test]
@@ -69,6 +67,12 @@ mod tests {
         assert_eq!(response.attributes[0].value, "whitelist_added");
         assert_eq!(response.attributes[1].key, "addresses_whitelisted");
         assert_eq!(response.attributes[1].value, "addr1,addr2");
+        assert_eq!(
+            vec![Event::new("loan_pool_contributors_whitelisted")
+                .add_attribute("gp", "gp")
+                .add_attribute("contributors", "addr1,addr2")],
+            response.events
+        );
 
         // Test adding contributors by someone else
         let result = handle(deps.as_mut(), other.clone(), contributors.clone());
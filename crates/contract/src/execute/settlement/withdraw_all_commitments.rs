@@ -4,53 +4,64 @@ use crate::{
     core::{
         aliases::{ProvDepsMut, ProvTxResponse},
         error::ContractError,
+        event::{ContractEvent, EventType},
+    },
+    storage::{self},
+    util::{
+        self,
+        auth::{authorize, Role},
     },
-    storage::{self, state},
-    util,
 };
 
 use super::{commitment::CommitmentState, withdraw_commitment};
 
 pub fn handle(mut deps: ProvDepsMut, env: Env, sender: Addr) -> ProvTxResponse {
-    let state = state::get(deps.storage)?;
-    if sender != state.gp {
-        return Err(ContractError::Unauthorized {});
-    }
+    let state = authorize(deps.storage, &sender, Role::Operator)?;
 
     if util::settlement::timestamp_is_expired(deps.storage, &env.block.time)? {
         return Err(ContractError::SettlmentExpired {});
     }
 
+    if !util::settlement::minimum_raise_met(deps.storage, &state.minimum_raise)? {
+        return Err(ContractError::MinimumRaiseNotMet {});
+    }
+
     let commits = storage::commits::get_with_state(deps.storage, CommitmentState::ACCEPTED);
     let mut res = Response::new()
         .add_attribute("action", "withdraw_all_commitments")
-        .add_attribute("gp", sender.clone());
+        .add_attribute("gp", state.gp.clone());
+    let mut withdrawn = 0;
     for commit in commits {
         if let Ok(withdraw) = withdraw_commitment::withdraw_commitment(
             &mut deps,
             &env,
-            sender.clone(),
+            &sender,
+            state.gp.clone(),
             commit.lp.clone(),
         ) {
             res = res.add_submessages(withdraw.messages);
             res = res.add_events(withdraw.events);
+            withdrawn += 1;
         }
     }
-    Ok(res)
+    Ok(res.add_event(
+        ContractEvent::new(EventType::CommitmentsWithdrawn, &state.gp)
+            .attribute("withdrawn", withdrawn.to_string()),
+    ))
 }
 
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::{testing::mock_env, Addr, Attribute, Coin, Uint128};
+    use cosmwasm_std::{testing::mock_env, Addr, Attribute, Coin, Event, Uint128};
     use provwasm_mocks::mock_provenance_dependencies;
 
     use crate::{
-        core::error::ContractError,
+        core::{error::ContractError, security::MinimumRaise},
         execute::settlement::{
             commitment::{Commitment, CommitmentState},
             withdraw_all_commitments::handle,
         },
-        storage::{available_capital, commits, paid_in_capital},
+        storage::{available_capital, commits, paid_in_capital, state},
         util::testing::{create_test_state, SettlementTester},
     };
 
@@ -137,7 +148,7 @@ mod tests {
         .unwrap();
 
         let res = handle(deps.as_mut(), mock_env(), gp.clone()).unwrap();
-        assert_eq!(1, res.events.len());
+        assert_eq!(2, res.events.len());
         assert_eq!(
             vec![
                 Attribute::new("action", "withdraw_all_commitments"),
@@ -178,7 +189,7 @@ mod tests {
         .unwrap();
 
         let res = handle(deps.as_mut(), mock_env(), gp.clone()).unwrap();
-        assert_eq!(1, res.events.len());
+        assert_eq!(2, res.events.len());
         assert_eq!(
             vec![
                 Attribute::new("action", "withdraw_all_commitments"),
@@ -220,7 +231,13 @@ mod tests {
         }
 
         let res = handle(deps.as_mut(), mock_env(), gp.clone()).unwrap();
-        assert_eq!(2, res.events.len());
+        assert_eq!(3, res.events.len());
+        assert_eq!(
+            Event::new("commitments_withdrawn")
+                .add_attribute("gp", "gp")
+                .add_attribute("withdrawn", "2"),
+            res.events[2]
+        );
         assert_eq!(
             vec![
                 Attribute::new("action", "withdraw_all_commitments"),
@@ -229,4 +246,36 @@ mod tests {
             res.attributes
         );
     }
+
+    #[test]
+    fn test_should_succeed_without_accepted_commitments() {
+        let mut deps = mock_provenance_dependencies();
+        let settlement_tester = SettlementTester::new();
+        settlement_tester.setup_test_state(deps.as_mut().storage);
+
+        let res = handle(deps.as_mut(), mock_env(), Addr::unchecked("gp")).unwrap();
+        assert!(res.messages.is_empty());
+        assert_eq!(
+            vec![Event::new("commitments_withdrawn")
+                .add_attribute("gp", "gp")
+                .add_attribute("withdrawn", "0")],
+            res.events
+        );
+    }
+
+    #[test]
+    fn test_should_fail_when_minimum_raise_not_met() {
+        let mut deps = mock_provenance_dependencies();
+        let env = mock_env();
+        create_test_state(&mut deps, &env, false);
+        let mut contract_state = state::get(&deps.storage).unwrap();
+        contract_state.minimum_raise = Some(MinimumRaise::Offering(Uint128::new(100)));
+        state::set(deps.as_mut().storage, &contract_state).unwrap();
+
+        let error = handle(deps.as_mut(), env, Addr::unchecked("gp")).unwrap_err();
+        assert_eq!(
+            ContractError::MinimumRaiseNotMet {}.to_string(),
+            error.to_string()
+        );
+    }
 }
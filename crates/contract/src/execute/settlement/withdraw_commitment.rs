@@ -1,35 +1,47 @@
-use cosmwasm_std::{Addr, Env, Event, Response, Storage};
+use cosmwasm_std::{Addr, Coin, Env, Response, Storage, Uint128, Uint64};
 
 use super::commitment::{Commitment, CommitmentState};
-use crate::util::provenance_utilities::{
-    mint_marker_supply, transfer_marker_coins, withdraw_coins,
-};
+use crate::util::provenance_utilities::{mint_marker_supply, withdraw_coins};
 use crate::{
     core::{
         aliases::{ProvDepsMut, ProvMsg, ProvTxResponse},
         error::ContractError,
+        event::{ContractEvent, EventType},
+        security::SecurityCommitment,
     },
     storage::{
         available_capital::{self},
+        capital_calls::{self},
+        commitment_history,
         commits::{self},
-        state::{self},
+        distributions::{self},
+        paid_in_capital, remaining_securities, settlement_times,
+        state::{self, DepositMode},
+    },
+    util::{
+        self,
+        auth::{authorize, Role},
+        capital::send_capital,
+        fees, to,
     },
-    util::{self, to},
 };
 
 pub fn handle(mut deps: ProvDepsMut, env: Env, sender: Addr, commitment: Addr) -> ProvTxResponse {
-    let state = state::get(deps.storage)?;
-    if sender != state.gp {
-        return Err(ContractError::Unauthorized {});
+    let state = authorize(deps.storage, &sender, Role::Operator)?;
+
+    if !util::settlement::minimum_raise_met(deps.storage, &state.minimum_raise)? {
+        return Err(ContractError::MinimumRaiseNotMet {});
     }
 
-    withdraw_commitment(&mut deps, &env, sender, commitment)
+    // Operators withdraw on behalf of the GP, so the capital is always released to the GP
+    withdraw_commitment(&mut deps, &env, &sender, state.gp, commitment)
 }
 
 pub fn withdraw_commitment(
     deps: &mut ProvDepsMut,
     env: &Env,
-    sender: Addr,
+    sender: &Addr,
+    gp: Addr,
     lp: Addr,
 ) -> ProvTxResponse {
     let commitment = commits::get(deps.storage, lp.clone())?;
@@ -38,21 +50,168 @@ pub fn withdraw_commitment(
         return Err(ContractError::SettlmentExpired {});
     }
 
+    fees::accrue_management_fee(deps.storage, lp.clone(), env.block.time)?;
     if !util::settlement::is_settling(deps.storage, &commitment) {
+        if util::settlement::is_call_funded(deps.storage, &commitment) {
+            return release_called_capital(deps, env, sender, gp, lp);
+        }
         return Err(ContractError::CommitmentNotMet {});
     }
 
-    let withdraw_messages = process_withdraw(deps.storage, &sender, &lp, &env.contract.address)?;
+    let deposit_mode = state::get(deps.storage)?.deposit_mode;
+    let capital = available_capital::get_capital(deps.storage, lp.clone())?[0].clone();
+    let withdraw_messages =
+        process_withdraw(deps.storage, &deposit_mode, &gp, &lp, &env.contract.address)?;
+    commitment_history::record(
+        deps.storage,
+        env,
+        &lp,
+        sender,
+        "settled",
+        &commitment.commitments,
+    )?;
+    settlement_times::set(
+        deps.storage,
+        lp.clone(),
+        Uint64::new(env.block.time.seconds()),
+    )?;
 
     Ok(Response::new()
         .add_messages(withdraw_messages)
-        .add_event(Event::new("settled").add_attribute("lp", lp))
+        .add_event(
+            ContractEvent::new(EventType::Settled, &gp)
+                .lp(&lp)
+                .securities(&commitment.commitments)
+                .amount(capital)
+                .state(&CommitmentState::SETTLED),
+        )
+        .add_attribute("action", "withdraw_commitment")
+        .add_attribute("gp", gp))
+}
+
+// Releases the capital deposited for a capital call to the GP without settling the commitment
+fn release_called_capital(
+    deps: &mut ProvDepsMut,
+    env: &Env,
+    sender: &Addr,
+    gp: Addr,
+    lp: Addr,
+) -> ProvTxResponse {
+    let deposit_mode = state::get(deps.storage)?.deposit_mode;
+    let mut called = vec![];
+    if let Some(mut capital_call) = capital_calls::get(deps.storage, lp.clone()) {
+        capital_call.released = true;
+        capital_calls::set(deps.storage, lp.clone(), &capital_call)?;
+        called = capital_call.securities;
+    }
+    commitment_history::record(deps.storage, env, &lp, sender, "capital_released", &called)?;
+    let capital = available_capital::remove_capital(deps.storage, lp.clone())?;
+    let messages = release_capital(
+        deps.storage,
+        &deposit_mode,
+        capital.clone(),
+        &gp,
+        &lp,
+        &env.contract.address,
+    )?;
+    let state = commits::get(deps.storage, lp.clone())?.state;
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_event(
+            ContractEvent::new(EventType::CapitalReleased, &gp)
+                .lp(&lp)
+                .securities(&called)
+                .amount(capital)
+                .state(&state),
+        )
+        .add_attribute("action", "withdraw_commitment")
+        .add_attribute("gp", gp))
+}
+
+// Settles a commitment for only the capital the lp has paid in. Capital that is still held for the lp
+// is released to the GP, and the units that were never paid for are returned to the offering.
+pub fn settle_paid_in_capital(
+    deps: &mut ProvDepsMut,
+    env: &Env,
+    sender: &Addr,
+    gp: Addr,
+    lp: Addr,
+) -> ProvTxResponse {
+    let mut commitment = commits::get(deps.storage, lp.clone())?;
+    let paid_in: Vec<SecurityCommitment> = paid_in_capital::get(deps.storage, lp.clone())
+        .into_iter()
+        .filter(|security| !security.amount.is_zero())
+        .collect();
+
+    fees::accrue_management_fee(deps.storage, lp.clone(), env.block.time)?;
+    for security in &commitment.commitments {
+        let paid = paid_in
+            .iter()
+            .find(|paid| paid.name == security.name)
+            .map_or(Uint128::zero(), |paid| paid.amount);
+        remaining_securities::add(
+            deps.storage,
+            security.name.clone(),
+            security.amount.saturating_sub(paid).u128(),
+        )?;
+    }
+
+    commitment.commitments = paid_in;
+    commitment.state = CommitmentState::SETTLED;
+    capital_calls::remove(deps.storage, lp.clone());
+    for security in &commitment.commitments {
+        distributions::add_units(
+            deps.storage,
+            lp.clone(),
+            security.name.clone(),
+            security.amount,
+        )?;
+    }
+    let mut messages = transfer_investment_tokens(&commitment, &env.contract.address)?;
+    let mut event = ContractEvent::new(EventType::Settled, &gp)
+        .lp(&lp)
+        .securities(&commitment.commitments)
+        .state(&CommitmentState::SETTLED);
+    if available_capital::has_lp(deps.storage, lp.clone()) {
+        let deposit_mode = state::get(deps.storage)?.deposit_mode;
+        let capital = available_capital::remove_capital(deps.storage, lp.clone())?;
+        messages.extend(release_capital(
+            deps.storage,
+            &deposit_mode,
+            capital.clone(),
+            &gp,
+            &lp,
+            &env.contract.address,
+        )?);
+        event = event.amount(capital);
+    }
+
+    commits::set(deps.storage, &commitment)?;
+    commitment_history::record(
+        deps.storage,
+        env,
+        &lp,
+        sender,
+        "settled",
+        &commitment.commitments,
+    )?;
+    settlement_times::set(
+        deps.storage,
+        lp.clone(),
+        Uint64::new(env.block.time.seconds()),
+    )?;
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_event(event)
         .add_attribute("action", "withdraw_commitment")
-        .add_attribute("gp", sender))
+        .add_attribute("gp", gp))
 }
 
 fn process_withdraw(
     storage: &mut dyn Storage,
+    deposit_mode: &DepositMode,
     gp: &Addr,
     lp: &Addr,
     contract: &Addr,
@@ -61,22 +220,56 @@ fn process_withdraw(
     let capital = available_capital::remove_capital(storage, lp.clone())?;
     let mut messages = vec![];
 
+    capital_calls::remove(storage, lp.clone());
     commitment.state = CommitmentState::SETTLED;
-    messages.extend(transfer_investment_tokens(&commitment, contract)?);
-    if !capital.amount.is_zero() {
-        messages.push(transfer_marker_coins(
-            capital.amount.u128(),
-            capital.denom,
-            gp.clone(),
-            contract.clone(),
-            contract.clone(),
-        )?);
+    for security in &commitment.commitments {
+        distributions::add_units(storage, lp.clone(), security.name.clone(), security.amount)?;
     }
+    messages.extend(transfer_investment_tokens(&commitment, contract)?);
+    messages.extend(release_capital(
+        storage,
+        deposit_mode,
+        capital,
+        gp,
+        lp,
+        contract,
+    )?);
 
     commits::set(storage, &commitment)?;
     Ok(messages)
 }
 
+// Sends the lp's capital to the GP after deducting their outstanding fees for the fee recipient
+fn release_capital(
+    storage: &mut dyn Storage,
+    deposit_mode: &DepositMode,
+    capital: Coin,
+    gp: &Addr,
+    lp: &Addr,
+    contract: &Addr,
+) -> Result<Vec<ProvMsg>, ContractError> {
+    let mut messages = vec![];
+    let mut remaining = capital.amount;
+    if let Some((recipient, fee)) = fees::deduct(storage, lp.clone(), capital.amount)? {
+        remaining -= fee;
+        messages.push(send_capital(
+            deposit_mode,
+            Coin::new(fee, capital.denom.clone()),
+            &recipient,
+            contract,
+        )?);
+    }
+    if !remaining.is_zero() {
+        messages.push(send_capital(
+            deposit_mode,
+            Coin::new(remaining, capital.denom),
+            gp,
+            contract,
+        )?);
+    }
+    Ok(messages)
+}
+
 fn transfer_investment_tokens(
     commitment: &Commitment,
     contract: &Addr,
@@ -101,18 +294,33 @@ fn transfer_investment_tokens(
 
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::{testing::mock_env, Addr, Attribute, Coin, Event, Uint128, Uint64};
+    use cosmwasm_std::{
+        testing::mock_env, Addr, Attribute, BankMsg, Coin, Decimal, Event, Uint128, Uint64,
+    };
     use provwasm_mocks::mock_provenance_dependencies;
 
     use super::{handle, process_withdraw, transfer_investment_tokens, withdraw_commitment};
-    use crate::util::provenance_utilities::{mint_marker_supply, withdraw_coins};
+    use crate::execute::settlement::cancel_commitment;
+    use crate::util::provenance_utilities::{
+        mint_marker_supply, transfer_marker_coins, withdraw_coins,
+    };
     use crate::{
-        core::error::ContractError,
+        core::{
+            aliases::ProvMsg,
+            error::ContractError,
+            fee::{FeeBasis, FeeSchedule},
+            security::MinimumRaise,
+        },
         execute::settlement::commitment::{Commitment, CommitmentState},
         storage::{
             available_capital::{self},
+            capital_calls::{self, CapitalCall},
             commits::{self},
+            distributions::{self},
+            fees::{self, FeeAccrual},
             paid_in_capital::{self},
+            settlement_times,
+            state::{self, DepositMode, State},
         },
         util::{testing::SettlementTester, to},
     };
@@ -154,7 +362,14 @@ mod tests {
         let lp = Addr::unchecked("lp");
         let gp = Addr::unchecked("gp");
         let contract = Addr::unchecked("contract");
-        process_withdraw(deps.as_mut().storage, &gp, &lp, &contract).unwrap_err();
+        process_withdraw(
+            deps.as_mut().storage,
+            &DepositMode::Marker,
+            &gp,
+            &lp,
+            &contract,
+        )
+        .unwrap_err();
     }
 
     #[test]
@@ -169,7 +384,14 @@ mod tests {
 
         commits::set(deps.as_mut().storage, &commitment).unwrap();
 
-        process_withdraw(deps.as_mut().storage, &gp, &commitment.lp, &contract).unwrap_err();
+        process_withdraw(
+            deps.as_mut().storage,
+            &DepositMode::Marker,
+            &gp,
+            &commitment.lp,
+            &contract,
+        )
+        .unwrap_err();
     }
 
     #[test]
@@ -198,15 +420,133 @@ mod tests {
             &settlement_tester.security_commitments,
         )
         .unwrap();
-        let messages =
-            process_withdraw(deps.as_mut().storage, &gp, &commitment.lp, &contract).unwrap();
+        let messages = process_withdraw(
+            deps.as_mut().storage,
+            &DepositMode::Marker,
+            &gp,
+            &commitment.lp,
+            &contract,
+        )
+        .unwrap();
 
         let updated = commits::get(&deps.storage, commitment.lp.clone()).unwrap();
         assert_eq!(CommitmentState::SETTLED, updated.state);
         assert_eq!(5, messages.len());
         assert_eq!(
             false,
-            available_capital::has_lp(deps.as_mut().storage, commitment.lp)
+            available_capital::has_lp(deps.as_mut().storage, commitment.lp.clone())
+        );
+        for security in &settlement_tester.security_commitments {
+            let holding = distributions::get_holding(
+                &deps.storage,
+                commitment.lp.clone(),
+                security.name.clone(),
+            )
+            .unwrap();
+            assert_eq!(security.amount, holding.units);
+        }
+    }
+
+    #[test]
+    fn test_process_withdraw_deducts_outstanding_fees() {
+        let mut deps = mock_provenance_dependencies();
+        let mut settlement_tester = SettlementTester::new();
+        settlement_tester.create_security_commitments(2);
+        let lp = Addr::unchecked("lp");
+        let gp = Addr::unchecked("gp");
+        let contract = Addr::unchecked("contract");
+        let mut commitment = Commitment::new(lp, settlement_tester.security_commitments.clone());
+        commitment.state = CommitmentState::ACCEPTED;
+        commits::set(deps.as_mut().storage, &commitment).unwrap();
+        available_capital::add_capital(
+            deps.as_mut().storage,
+            commitment.lp.clone(),
+            vec![Coin::new(Uint128::new(100), "denom".to_string())],
+        )
+        .unwrap();
+        fees::set_schedule(
+            deps.as_mut().storage,
+            &FeeSchedule {
+                recipient: Addr::unchecked("manager"),
+                management_fee_rate: Decimal::percent(2),
+                management_fee_basis: FeeBasis::Committed,
+                carry_rate: Decimal::percent(20),
+                hurdle: Decimal::percent(8),
+            },
+        )
+        .unwrap();
+        fees::set_accrual(
+            deps.as_mut().storage,
+            commitment.lp.clone(),
+            &FeeAccrual {
+                management_fee: Uint128::new(30),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        let messages = process_withdraw(
+            deps.as_mut().storage,
+            &DepositMode::Marker,
+            &gp,
+            &commitment.lp,
+            &contract,
+        )
+        .unwrap();
+        assert_eq!(6, messages.len());
+        assert_eq!(
+            vec![
+                transfer_marker_coins(
+                    30,
+                    "denom",
+                    Addr::unchecked("manager"),
+                    contract.clone(),
+                    contract.clone()
+                )
+                .unwrap(),
+                transfer_marker_coins(70, "denom", gp, contract.clone(), contract).unwrap()
+            ],
+            messages[4..].to_vec()
+        );
+        assert_eq!(
+            Uint128::new(30),
+            fees::get_accrual(&deps.storage, commitment.lp).paid
+        );
+    }
+
+    #[test]
+    fn test_process_withdraw_sends_attached_funds() {
+        let mut deps = mock_provenance_dependencies();
+        let mut settlement_tester = SettlementTester::new();
+        settlement_tester.create_security_commitments(2);
+        let lp = Addr::unchecked("lp");
+        let gp = Addr::unchecked("gp");
+        let contract = Addr::unchecked("contract");
+        let mut commitment = Commitment::new(lp, settlement_tester.security_commitments.clone());
+        commitment.state = CommitmentState::ACCEPTED;
+        commits::set(deps.as_mut().storage, &commitment).unwrap();
+        available_capital::add_capital(
+            deps.as_mut().storage,
+            commitment.lp.clone(),
+            vec![Coin::new(Uint128::new(100), "denom".to_string())],
+        )
+        .unwrap();
+
+        let messages = process_withdraw(
+            deps.as_mut().storage,
+            &DepositMode::Funds,
+            &gp,
+            &commitment.lp,
+            &contract,
+        )
+        .unwrap();
+        assert_eq!(5, messages.len());
+        assert_eq!(
+            ProvMsg::Bank(BankMsg::Send {
+                to_address: gp.to_string(),
+                amount: vec![Coin::new(100u128, "denom")],
+            }),
+            messages[4]
         );
     }
 
@@ -236,8 +576,14 @@ mod tests {
             &settlement_tester.security_commitments,
         )
         .unwrap();
-        let messages =
-            process_withdraw(deps.as_mut().storage, &gp, &commitment.lp, &contract).unwrap();
+        let messages = process_withdraw(
+            deps.as_mut().storage,
+            &DepositMode::Marker,
+            &gp,
+            &commitment.lp,
+            &contract,
+        )
+        .unwrap();
 
         let updated = commits::get(&deps.storage, commitment.lp.clone()).unwrap();
         assert_eq!(CommitmentState::SETTLED, updated.state);
@@ -253,7 +599,8 @@ mod tests {
         let mut deps = mock_provenance_dependencies();
         let sender = Addr::unchecked("gp");
         let lp = Addr::unchecked("lp");
-        withdraw_commitment(&mut deps.as_mut(), &mock_env(), sender, lp).unwrap_err();
+        withdraw_commitment(&mut deps.as_mut(), &mock_env(), &sender, sender.clone(), lp)
+            .unwrap_err();
     }
 
     #[test]
@@ -283,6 +630,7 @@ mod tests {
         let err = withdraw_commitment(
             &mut deps.as_mut(),
             &mock_env(),
+            &sender,
             sender.clone(),
             commitment.lp.clone(),
         )
@@ -321,6 +669,7 @@ mod tests {
         let err = withdraw_commitment(
             &mut deps.as_mut(),
             &mock_env(),
+            &sender,
             sender.clone(),
             commitment.lp.clone(),
         )
@@ -343,6 +692,11 @@ mod tests {
         commitment.state = CommitmentState::ACCEPTED;
 
         commits::set(deps.as_mut().storage, &commitment).unwrap();
+        state::set(
+            deps.as_mut().storage,
+            &State::new(gp.clone(), capital_denom.clone(), None),
+        )
+        .unwrap();
 
         available_capital::add_capital(
             deps.as_mut().storage,
@@ -361,6 +715,7 @@ mod tests {
         let res = withdraw_commitment(
             &mut deps.as_mut(),
             &mock_env(),
+            &gp,
             gp.clone(),
             commitment.lp.clone(),
         )
@@ -368,9 +723,171 @@ mod tests {
         assert_eq!(5, res.messages.len());
         assert_eq!(1, res.events.len());
         assert_eq!(
-            Event::new("settled").add_attribute("lp", commitment.lp),
+            Event::new("settled")
+                .add_attribute("gp", "gp")
+                .add_attribute("lp", commitment.lp.clone())
+                .add_attribute("securities", "Security1:11,Security2:12")
+                .add_attribute("amount", "100denom")
+                .add_attribute("state", "settled"),
+            res.events[0]
+        );
+        assert_eq!(
+            Some(Uint64::new(mock_env().block.time.seconds())),
+            settlement_times::get(&deps.storage, commitment.lp)
+        );
+    }
+
+    #[test]
+    fn test_withdraw_commitments_releases_called_capital() {
+        let mut deps = mock_provenance_dependencies();
+        let mut settlement_tester = SettlementTester::new();
+        settlement_tester.create_security_commitments(2);
+        let lp = Addr::unchecked("lp");
+        let gp = Addr::unchecked("gp");
+        let capital_denom = "denom".to_string();
+        let mut commitment = Commitment::new(lp, settlement_tester.security_commitments.clone());
+        commitment.state = CommitmentState::ACCEPTED;
+
+        commits::set(deps.as_mut().storage, &commitment).unwrap();
+        state::set(
+            deps.as_mut().storage,
+            &State::new(gp.clone(), capital_denom.clone(), None),
+        )
+        .unwrap();
+
+        available_capital::add_capital(
+            deps.as_mut().storage,
+            commitment.lp.clone(),
+            vec![Coin::new(Uint128::new(50), &capital_denom)],
+        )
+        .unwrap();
+
+        let mut called = settlement_tester.security_commitments.clone();
+        called[0].amount = Uint128::new(5);
+        called[1].amount = Uint128::new(6);
+        capital_calls::set(
+            deps.as_mut().storage,
+            commitment.lp.clone(),
+            &CapitalCall {
+                securities: called.clone(),
+                due_date: None,
+                released: false,
+            },
+        )
+        .unwrap();
+        paid_in_capital::set(deps.as_mut().storage, commitment.lp.clone(), &called).unwrap();
+
+        let res = withdraw_commitment(
+            &mut deps.as_mut(),
+            &mock_env(),
+            &gp,
+            gp.clone(),
+            commitment.lp.clone(),
+        )
+        .unwrap();
+        assert_eq!(1, res.messages.len());
+        assert_eq!(
+            Event::new("capital_released")
+                .add_attribute("gp", "gp")
+                .add_attribute("lp", commitment.lp.clone())
+                .add_attribute("securities", "Security1:5,Security2:6")
+                .add_attribute("amount", "50denom")
+                .add_attribute("state", "accepted"),
             res.events[0]
         );
+
+        let updated = commits::get(&deps.storage, commitment.lp.clone()).unwrap();
+        assert_eq!(CommitmentState::ACCEPTED, updated.state);
+        assert_eq!(
+            false,
+            available_capital::has_lp(&deps.storage, commitment.lp.clone())
+        );
+
+        // The released capital can't be released again
+        let err = withdraw_commitment(
+            &mut deps.as_mut(),
+            &mock_env(),
+            &gp,
+            gp.clone(),
+            commitment.lp,
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::CommitmentNotMet {}.to_string(),
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_cancel_fails_after_releasing_called_capital() {
+        let mut deps = mock_provenance_dependencies();
+        let mut settlement_tester = SettlementTester::new();
+        settlement_tester.create_security_commitments(1);
+        let lp = Addr::unchecked("lp");
+        let gp = Addr::unchecked("gp");
+        let capital_denom = "denom".to_string();
+        let mut commitment = Commitment::new(lp, settlement_tester.security_commitments.clone());
+        commitment.state = CommitmentState::ACCEPTED;
+
+        commits::set(deps.as_mut().storage, &commitment).unwrap();
+        state::set(
+            deps.as_mut().storage,
+            &State::new(gp.clone(), capital_denom.clone(), None),
+        )
+        .unwrap();
+        available_capital::add_capital(
+            deps.as_mut().storage,
+            commitment.lp.clone(),
+            vec![Coin::new(Uint128::new(50), &capital_denom)],
+        )
+        .unwrap();
+        let mut called = settlement_tester.security_commitments.clone();
+        called[0].amount = Uint128::new(5);
+        capital_calls::set(
+            deps.as_mut().storage,
+            commitment.lp.clone(),
+            &CapitalCall {
+                securities: called.clone(),
+                due_date: None,
+                released: false,
+            },
+        )
+        .unwrap();
+        paid_in_capital::set(deps.as_mut().storage, commitment.lp.clone(), &called).unwrap();
+
+        withdraw_commitment(
+            &mut deps.as_mut(),
+            &mock_env(),
+            &gp,
+            gp.clone(),
+            commitment.lp.clone(),
+        )
+        .unwrap();
+        assert!(
+            capital_calls::get(&deps.storage, commitment.lp.clone())
+                .unwrap()
+                .released
+        );
+
+        let err = cancel_commitment::handle(
+            deps.as_mut(),
+            mock_env(),
+            commitment.lp.clone(),
+            commitment.lp.clone(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::CapitalAlreadyReleased {}.to_string(),
+            err.to_string()
+        );
+        assert_eq!(
+            called,
+            paid_in_capital::get(&deps.storage, commitment.lp.clone())
+        );
+        assert_eq!(
+            CommitmentState::ACCEPTED,
+            commits::get(&deps.storage, commitment.lp).unwrap().state
+        );
     }
 
     #[test]
@@ -426,4 +943,26 @@ mod tests {
         );
         assert_eq!(Attribute::new("gp", gp), res.attributes[1]);
     }
+
+    #[test]
+    fn test_handle_fails_when_minimum_raise_not_met() {
+        let mut deps = mock_provenance_dependencies();
+        let settlement_tester = SettlementTester::new();
+        settlement_tester.setup_test_state(deps.as_mut().storage);
+        let mut contract_state = state::get(&deps.storage).unwrap();
+        contract_state.minimum_raise = Some(MinimumRaise::Offering(Uint128::new(100)));
+        state::set(deps.as_mut().storage, &contract_state).unwrap();
+
+        let error = handle(
+            deps.as_mut(),
+            mock_env(),
+            Addr::unchecked("gp"),
+            Addr::unchecked("lp"),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::MinimumRaiseNotMet {}.to_string(),
+            error.to_string()
+        );
+    }
 }
@@ -8,10 +8,11 @@ use crate::{
     core::{
         aliases::{ProvDepsMut, ProvTxResponse},
         error::ContractError,
+        event::{ContractEvent, EventType},
     },
-    storage::state::{self},
+    util::auth::{authorize, Role},
 };
-use cosmwasm_std::{to_json_binary, Addr, DepsMut, Env, Event, MessageInfo, Response};
+use cosmwasm_std::{to_json_binary, Addr, Coin, DepsMut, Env, MessageInfo, Response};
 use provwasm_std::types::provenance::marker::v1::{AccessGrant, MarkerQuerier};
 use provwasm_std::types::provenance::metadata::v1::p8e::PartyType::Marker;
 
@@ -42,12 +43,8 @@ pub fn handle(
     info: MessageInfo,
     loan_pools: WithdrawLoanPools,
 ) -> ProvTxResponse {
-    let state = state::get(deps.storage)?;
-
     // the gp can only release the pool
-    if info.sender != state.gp {
-        return Err(ContractError::Unauthorized {});
-    }
+    let state = authorize(deps.storage, &info.sender, Role::Gp)?;
 
     // create empty response object
     let mut response = Response::new();
@@ -78,19 +75,22 @@ pub fn handle(
     {
         remove(deps.storage, &collateral)?;
 
-        // store each collateral in collaterals vector
-        collaterals.push(collateral);
-
         response = response.add_messages(messages).add_event(
-            Event::new("loan_pool_withdrawn").add_attribute("marker_address", address.to_string()),
+            ContractEvent::new(EventType::LoanPoolWithdrawn, &state.gp)
+                .amount(Coin::new(collateral.share_count, &collateral.marker_denom))
+                .attribute("marker_address", address)
+                .attribute("contributor", collateral.original_contributor.clone()),
         );
+
+        // store each collateral in collaterals vector
+        collaterals.push(collateral);
     }
 
     // Add removed_by attribute only if loan_pool_withdrawn event is added
     if response
         .events
         .iter()
-        .any(|event| event.ty == "loan_pool_withdrawn")
+        .any(|event| event.ty == EventType::LoanPoolWithdrawn.as_str())
     {
         response = response.add_attribute("action", "loan_pool_removed");
         response = response.add_attribute("loan_pool_removed_by", info.sender);
@@ -142,8 +142,8 @@ mod tests {
     use cosmwasm_std::testing::{message_info, mock_env, mock_info};
     use cosmwasm_std::ReplyOn::Never;
     use cosmwasm_std::{
-        from_json, to_json_binary, Addr, AnyMsg, Binary, ContractResult, SubMsg, SystemResult,
-        Uint128,
+        from_json, to_json_binary, Addr, AnyMsg, Binary, ContractResult, Event, SubMsg,
+        SystemResult, Uint128,
     };
     use provwasm_mocks::mock_provenance_dependencies;
     use provwasm_std::shim::Any;
@@ -328,6 +328,17 @@ mod tests {
                     from_json(&response.data.unwrap()).unwrap();
                 assert_eq!(withdraw_loan_pool_result.collaterals, expected_collaterals); //replace `collaterals` with expected vec of collaterals
                 assert_eq!(response.events.len(), 1);
+                assert_eq!(
+                    Event::new("loan_pool_withdrawn")
+                        .add_attribute("gp", "gp")
+                        .add_attribute("amount", format!("100{}", denom))
+                        .add_attribute(
+                            "marker_address",
+                            expected_collaterals[0].marker_address.clone()
+                        )
+                        .add_attribute("contributor", "contributor"),
+                    response.events[0]
+                );
                 assert_eq!(response.attributes.len(), 2);
                 // Checking response attributes and events
                 let mut found_event = false;
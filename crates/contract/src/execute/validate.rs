@@ -1,7 +1,11 @@
-use cosmwasm_std::Coin;
+use cosmwasm_std::{Coin, Decimal, Uint128};
 
 use crate::{
-    core::{error::ContractError, msg::ExecuteMsg},
+    core::{
+        error::ContractError,
+        msg::ExecuteMsg,
+        security::{CapitalCallAmount, SecurityType},
+    },
     util::validate::{Validate, ValidateResult},
 };
 
@@ -24,6 +28,17 @@ impl Validate for ExecuteMsg {
                     return Err(ContractError::EmptyAcceptedCommitmentList {});
                 }
             }
+            ExecuteMsg::CounterProposeCommitment { securities, .. } => {
+                if securities.is_empty() {
+                    return Err(ContractError::EmptySecurityCommitmentList {});
+                }
+                if securities
+                    .iter()
+                    .any(|commitment| commitment.amount.is_zero())
+                {
+                    return Err(ContractError::InvalidSecurityCommitmentAmount {});
+                }
+            }
             ExecuteMsg::DepositCommitment { securities } => {
                 if securities.is_empty() {
                     return Err(ContractError::EmptySecurityCommitmentList {});
@@ -35,12 +50,90 @@ impl Validate for ExecuteMsg {
                     return Err(ContractError::InvalidSecurityCommitmentAmount {});
                 }
             }
+            ExecuteMsg::IssueCapitalCall { call, .. } => match call {
+                CapitalCallAmount::Percentage(percentage) => {
+                    if percentage.is_zero() || *percentage > Decimal::one() {
+                        return Err(ContractError::InvalidCapitalCall {});
+                    }
+                }
+                CapitalCallAmount::Commitments(commitments) => {
+                    if commitments.is_empty() {
+                        return Err(ContractError::InvalidCapitalCall {});
+                    }
+                    if commitments
+                        .iter()
+                        .flat_map(|commitment| &commitment.securities)
+                        .any(|security| security.amount.is_zero())
+                    {
+                        return Err(ContractError::InvalidSecurityCommitmentAmount {});
+                    }
+                }
+            },
+            ExecuteMsg::Distribute { amount, .. } | ExecuteMsg::DistributeWaterfall { amount }
+                if amount.is_zero() =>
+            {
+                return Err(ContractError::InvalidDistribution {});
+            }
+            ExecuteMsg::OpenRedemptionWindow { terms, .. }
+                if terms.price_per_unit.is_zero()
+                    || terms.gate.is_zero()
+                    || terms.gate > Decimal::one()
+                    || terms.start_time > terms.end_time =>
+            {
+                return Err(ContractError::InvalidRedemptionWindow {});
+            }
+            ExecuteMsg::UpdateNav { nav_per_unit, .. } if nav_per_unit.amount.is_zero() => {
+                return Err(ContractError::InvalidNav {});
+            }
+            ExecuteMsg::AddSecurity { security } => {
+                let invalid_tranche = matches!(
+                    &security.security_type,
+                    SecurityType::Tranche(tranche) if tranche.principal_cap == Some(Uint128::zero())
+                );
+                if security.amount.is_zero()
+                    || security.minimum_amount > security.amount
+                    || invalid_tranche
+                {
+                    return Err(ContractError::InvalidSecurityList {});
+                }
+                if !security.limits_are_valid() {
+                    return Err(ContractError::InvalidAllocationLimits {});
+                }
+            }
+            ExecuteMsg::ResizeSecurity { amount, .. } if amount.is_zero() => {
+                return Err(ContractError::InvalidSecurityList {});
+            }
+            ExecuteMsg::Redeem { amount, .. } | ExecuteMsg::TransferInvestment { amount, .. }
+                if amount.is_zero() =>
+            {
+                return Err(ContractError::InvalidSecurityCommitmentAmount {});
+            }
+            ExecuteMsg::UpdateEligibility {
+                required_attributes,
+                ..
+            } if required_attributes.iter().any(|name| name.is_empty()) => {
+                return Err(ContractError::InvalidEligibilityRules {});
+            }
+            ExecuteMsg::AddToAllowlist { lps } | ExecuteMsg::RemoveFromAllowlist { lps }
+                if lps.is_empty() =>
+            {
+                return Err(ContractError::EmptyLpList {});
+            }
             _ => {}
         };
         Ok(())
     }
 
     fn validate_msg_funds(&self, funds: &[Coin]) -> ValidateResult {
+        // Deposits and distributions are checked against the offering's deposit mode, and
+        // redemption reserves against the window's capacity, when they are handled
+        if let ExecuteMsg::DepositCommitment { .. }
+        | ExecuteMsg::Distribute { .. }
+        | ExecuteMsg::DistributeWaterfall { .. }
+        | ExecuteMsg::OpenRedemptionWindow { .. } = self
+        {
+            return Ok(());
+        }
         if !funds.is_empty() {
             return Err(ContractError::UnexpectedFunds {});
         }
@@ -50,15 +143,18 @@ impl Validate for ExecuteMsg {
 
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::{Addr, Coin, Uint128};
+    use cosmwasm_std::{Addr, Coin, Decimal, Uint128, Uint64};
 
     use crate::{
         core::{
             error::ContractError,
             msg::ExecuteMsg,
-            security::{AcceptedCommitment, SecurityCommitment},
+            security::{
+                AcceptedCommitment, CapitalCallAmount, RedemptionTerms, Security,
+                SecurityCommitment, SecurityType, TrancheSecurity,
+            },
         },
-        util::validate::Validate,
+        util::{testing::create_test_securities, validate::Validate},
     };
 
     #[test]
@@ -188,7 +284,83 @@ mod tests {
     }
 
     #[test]
-    fn test_invalid_msg_funds_deposit() {
+    fn test_counter_propose_has_securities() {
+        let msg = ExecuteMsg::CounterProposeCommitment {
+            lp: Addr::unchecked("lp"),
+            securities: vec![],
+        };
+        let output = msg.validate().unwrap_err();
+        let expected = ContractError::EmptySecurityCommitmentList {}.to_string();
+        assert_eq!(expected, output.to_string());
+    }
+
+    #[test]
+    fn test_counter_propose_has_valid_security_amounts() {
+        let msg = ExecuteMsg::CounterProposeCommitment {
+            lp: Addr::unchecked("lp"),
+            securities: vec![SecurityCommitment {
+                name: "test".to_string(),
+                amount: Uint128::zero(),
+            }],
+        };
+        let output = msg.validate().unwrap_err();
+        let expected = ContractError::InvalidSecurityCommitmentAmount {}.to_string();
+        assert_eq!(expected, output.to_string());
+    }
+
+    #[test]
+    fn test_capital_call_percentage_must_be_valid() {
+        for percentage in [Decimal::zero(), Decimal::percent(101)] {
+            let msg = ExecuteMsg::IssueCapitalCall {
+                call: CapitalCallAmount::Percentage(percentage),
+                due_date: None,
+            };
+            let output = msg.validate().unwrap_err();
+            let expected = ContractError::InvalidCapitalCall {}.to_string();
+            assert_eq!(expected, output.to_string());
+        }
+    }
+
+    #[test]
+    fn test_capital_call_has_commitments() {
+        let msg = ExecuteMsg::IssueCapitalCall {
+            call: CapitalCallAmount::Commitments(vec![]),
+            due_date: None,
+        };
+        let output = msg.validate().unwrap_err();
+        let expected = ContractError::InvalidCapitalCall {}.to_string();
+        assert_eq!(expected, output.to_string());
+    }
+
+    #[test]
+    fn test_capital_call_has_valid_security_amounts() {
+        let msg = ExecuteMsg::IssueCapitalCall {
+            call: CapitalCallAmount::Commitments(vec![AcceptedCommitment {
+                lp: Addr::unchecked("lp"),
+                securities: vec![SecurityCommitment {
+                    name: "test".to_string(),
+                    amount: Uint128::zero(),
+                }],
+            }]),
+            due_date: None,
+        };
+        let output = msg.validate().unwrap_err();
+        let expected = ContractError::InvalidSecurityCommitmentAmount {}.to_string();
+        assert_eq!(expected, output.to_string());
+    }
+
+    #[test]
+    fn test_valid_capital_call() {
+        let msg = ExecuteMsg::IssueCapitalCall {
+            call: CapitalCallAmount::Percentage(Decimal::one()),
+            due_date: None,
+        };
+        msg.validate()
+            .expect("should pass with a full capital call");
+    }
+
+    #[test]
+    fn test_msg_funds_deposit_are_left_to_the_handler() {
         let msg = ExecuteMsg::DepositCommitment {
             securities: vec![SecurityCommitment {
                 name: "test".to_string(),
@@ -199,9 +371,29 @@ mod tests {
             denom: "denom".to_string(),
             amount: Uint128::new(5),
         }];
-        let output = msg.validate_msg_funds(&funds).unwrap_err();
-        let expected = ContractError::UnexpectedFunds {}.to_string();
-        assert_eq!(expected, output.to_string());
+        msg.validate_msg_funds(&funds)
+            .expect("should leave attached funds to the deposit mode");
+    }
+
+    #[test]
+    fn test_msg_funds_redemption_reserve_are_left_to_the_handler() {
+        let msg = ExecuteMsg::OpenRedemptionWindow {
+            security: "test".to_string(),
+            terms: RedemptionTerms {
+                price_per_unit: Uint128::new(100),
+                start_time: Uint64::new(0),
+                end_time: Uint64::new(1),
+                gate: Decimal::percent(10),
+            },
+        };
+        let funds = vec![Coin {
+            denom: "denom".to_string(),
+            amount: Uint128::new(500),
+        }];
+        msg.validate_msg_funds(&funds)
+            .expect("should leave attached funds to the window's capacity");
+        msg.validate_msg_funds(&[])
+            .expect("should leave missing funds to the window's capacity");
     }
 
     #[test]
@@ -248,4 +440,196 @@ mod tests {
         msg2.validate_msg_funds(&funds)
             .expect("should pass with no funds");
     }
+
+    #[test]
+    fn test_distribute_amount_must_be_nonzero() {
+        let msg = ExecuteMsg::Distribute {
+            security: "Security1".to_string(),
+            amount: Uint128::zero(),
+        };
+        let output = msg.validate().unwrap_err();
+        let expected = ContractError::InvalidDistribution {}.to_string();
+        assert_eq!(expected, output.to_string());
+    }
+
+    #[test]
+    fn test_distribute_waterfall_amount_must_be_nonzero() {
+        let msg = ExecuteMsg::DistributeWaterfall {
+            amount: Uint128::zero(),
+        };
+        let output = msg.validate().unwrap_err();
+        let expected = ContractError::InvalidDistribution {}.to_string();
+        assert_eq!(expected, output.to_string());
+    }
+
+    #[test]
+    fn test_distribute_funds_are_checked_when_handled() {
+        let msg = ExecuteMsg::Distribute {
+            security: "Security1".to_string(),
+            amount: Uint128::new(5),
+        };
+        msg.validate_msg_funds(&[])
+            .expect("should pass without funds");
+
+        let funds = vec![Coin {
+            denom: "denom".to_string(),
+            amount: Uint128::new(5),
+        }];
+        msg.validate_msg_funds(&funds)
+            .expect("should pass with funds");
+
+        let msg = ExecuteMsg::DistributeWaterfall {
+            amount: Uint128::new(5),
+        };
+        msg.validate_msg_funds(&[])
+            .expect("should pass without funds");
+        msg.validate_msg_funds(&funds)
+            .expect("should pass with funds");
+    }
+
+    #[test]
+    fn test_redemption_window_terms_must_be_valid() {
+        let terms = RedemptionTerms {
+            price_per_unit: Uint128::new(100),
+            start_time: Uint64::new(10),
+            end_time: Uint64::new(20),
+            gate: Decimal::percent(10),
+        };
+        let invalid_terms = vec![
+            RedemptionTerms {
+                price_per_unit: Uint128::zero(),
+                ..terms.clone()
+            },
+            RedemptionTerms {
+                gate: Decimal::zero(),
+                ..terms.clone()
+            },
+            RedemptionTerms {
+                gate: Decimal::percent(101),
+                ..terms.clone()
+            },
+            RedemptionTerms {
+                start_time: Uint64::new(21),
+                ..terms.clone()
+            },
+        ];
+        for terms in invalid_terms {
+            let msg = ExecuteMsg::OpenRedemptionWindow {
+                security: "Security1".to_string(),
+                terms,
+            };
+            let output = msg.validate().unwrap_err();
+            let expected = ContractError::InvalidRedemptionWindow {}.to_string();
+            assert_eq!(expected, output.to_string());
+        }
+
+        let msg = ExecuteMsg::OpenRedemptionWindow {
+            security: "Security1".to_string(),
+            terms,
+        };
+        msg.validate().expect("should accept valid terms");
+    }
+
+    #[test]
+    fn test_redeem_amount_must_be_nonzero() {
+        let msg = ExecuteMsg::Redeem {
+            security: "Security1".to_string(),
+            amount: Uint128::zero(),
+        };
+        let output = msg.validate().unwrap_err();
+        let expected = ContractError::InvalidSecurityCommitmentAmount {}.to_string();
+        assert_eq!(expected, output.to_string());
+    }
+
+    #[test]
+    fn test_update_nav_must_be_nonzero() {
+        let msg = ExecuteMsg::UpdateNav {
+            security: "Security1".to_string(),
+            nav_per_unit: Coin::new(Uint128::zero(), "denom"),
+        };
+        let output = msg.validate().unwrap_err();
+        let expected = ContractError::InvalidNav {}.to_string();
+        assert_eq!(expected, output.to_string());
+    }
+
+    #[test]
+    fn test_add_security_must_be_valid() {
+        let security = create_test_securities()[0].clone();
+        let invalid_securities = vec![
+            Security {
+                amount: Uint128::zero(),
+                ..security.clone()
+            },
+            Security {
+                minimum_amount: security.amount + Uint128::one(),
+                ..security.clone()
+            },
+            Security {
+                security_type: SecurityType::Tranche(TrancheSecurity {
+                    principal_cap: Some(Uint128::zero()),
+                    ..Default::default()
+                }),
+                ..security.clone()
+            },
+        ];
+        for security in invalid_securities {
+            let msg = ExecuteMsg::AddSecurity { security };
+            let output = msg.validate().unwrap_err();
+            let expected = ContractError::InvalidSecurityList {}.to_string();
+            assert_eq!(expected, output.to_string());
+        }
+
+        let msg = ExecuteMsg::AddSecurity {
+            security: Security {
+                maximum_amount: Some(security.minimum_amount - Uint128::one()),
+                ..security.clone()
+            },
+        };
+        let output = msg.validate().unwrap_err();
+        let expected = ContractError::InvalidAllocationLimits {}.to_string();
+        assert_eq!(expected, output.to_string());
+
+        let msg = ExecuteMsg::AddSecurity { security };
+        msg.validate().expect("should accept a valid security");
+    }
+
+    #[test]
+    fn test_resize_security_must_be_nonzero() {
+        let msg = ExecuteMsg::ResizeSecurity {
+            security: "Security1".to_string(),
+            amount: Uint128::zero(),
+        };
+        let output = msg.validate().unwrap_err();
+        let expected = ContractError::InvalidSecurityList {}.to_string();
+        assert_eq!(expected, output.to_string());
+    }
+
+    #[test]
+    fn test_update_eligibility_attributes_must_be_named() {
+        let msg = ExecuteMsg::UpdateEligibility {
+            security: "Security1".to_string(),
+            required_attributes: vec!["accredited.investor.pb".to_string(), "".to_string()],
+        };
+        let output = msg.validate().unwrap_err();
+        let expected = ContractError::InvalidEligibilityRules {}.to_string();
+        assert_eq!(expected, output.to_string());
+
+        let msg = ExecuteMsg::UpdateEligibility {
+            security: "Security1".to_string(),
+            required_attributes: vec![],
+        };
+        msg.validate().expect("should accept clearing the rules");
+    }
+
+    #[test]
+    fn test_allowlist_must_have_lps() {
+        for msg in [
+            ExecuteMsg::AddToAllowlist { lps: vec![] },
+            ExecuteMsg::RemoveFromAllowlist { lps: vec![] },
+        ] {
+            let output = msg.validate().unwrap_err();
+            let expected = ContractError::EmptyLpList {}.to_string();
+            assert_eq!(expected, output.to_string());
+        }
+    }
 }
@@ -8,7 +8,7 @@ use crate::{
         msg::InstantiateMsg,
     },
     storage::{
-        remaining_securities,
+        fees, remaining_securities,
         securities::{self},
         state::{self, State},
     },
@@ -26,9 +26,16 @@ pub fn handle(
     msg: InstantiateMsg,
 ) -> ProvTxResponse {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-    let state = State::new(msg.gp, msg.capital_denom, msg.settlement_time);
+    let mut state = State::new(msg.gp, msg.capital_denom, msg.settlement_time);
+    state.minimum_raise = msg.minimum_raise;
+    state.maximum_lp_commitment = msg.maximum_lp_commitment;
+    state.lockup_period = msg.lockup_period;
+    state.deposit_mode = msg.deposit_mode.unwrap_or_default();
     let mut response = Response::new();
     state::set(deps.storage, &state)?;
+    if let Some(fee_schedule) = &msg.fee_schedule {
+        fees::set_schedule(deps.storage, fee_schedule)?;
+    }
 
     // Create the markers
     let mut messages: Vec<CosmosMsg> = Vec::new();
@@ -69,7 +76,11 @@ pub fn handle(
     Ok(response)
 }
 
-fn new_active_marker(owner: Addr, denom: &String, amount: Uint128) -> StdResult<Vec<CosmosMsg>> {
+pub fn new_active_marker(
+    owner: Addr,
+    denom: &String,
+    amount: Uint128,
+) -> StdResult<Vec<CosmosMsg>> {
     let address = Addr::unchecked("address");
     let grants = vec![AccessGrant {
         address: owner.to_string(),
@@ -194,14 +205,23 @@ mod tests {
                 amount: Uint128::new(1000),
                 minimum_amount: Uint128::new(100),
                 price_per_unit: Coin::new(Uint128::new(100), "denom"),
-                security_type: crate::core::security::SecurityType::Tranche(TrancheSecurity {}),
+                security_type: crate::core::security::SecurityType::Tranche(
+                    TrancheSecurity::default(),
+                ),
+                maximum_amount: None,
+                maximum_percentage: None,
             },
             Security {
                 name: "Tranche 2".to_string(),
                 amount: Uint128::new(1000),
                 minimum_amount: Uint128::new(100),
                 price_per_unit: Coin::new(Uint128::new(100), "denom"),
-                security_type: crate::core::security::SecurityType::Tranche(TrancheSecurity {}),
+                security_type: crate::core::security::SecurityType::Tranche(TrancheSecurity {
+                    seniority: 1,
+                    ..Default::default()
+                }),
+                maximum_amount: None,
+                maximum_percentage: None,
             },
         ];
         let init_msg = InstantiateMsg {
@@ -209,7 +229,12 @@ mod tests {
             securities: securities.clone(),
             capital_denom: DEFAULT_CAPITAL_DENOM.to_string(),
             settlement_time: DEFAULT_TIME,
+            minimum_raise: None,
             fee: None,
+            maximum_lp_commitment: None,
+            lockup_period: None,
+            fee_schedule: None,
+            deposit_mode: None,
         };
 
         // initialize
@@ -261,14 +286,23 @@ mod tests {
                 amount: Uint128::new(1000),
                 minimum_amount: Uint128::new(100),
                 price_per_unit: Coin::new(Uint128::new(100), "denom"),
-                security_type: crate::core::security::SecurityType::Tranche(TrancheSecurity {}),
+                security_type: crate::core::security::SecurityType::Tranche(
+                    TrancheSecurity::default(),
+                ),
+                maximum_amount: None,
+                maximum_percentage: None,
             },
             Security {
                 name: "Tranche 2".to_string(),
                 amount: Uint128::new(1000),
                 minimum_amount: Uint128::new(100),
                 price_per_unit: Coin::new(Uint128::new(100), "denom"),
-                security_type: crate::core::security::SecurityType::Tranche(TrancheSecurity {}),
+                security_type: crate::core::security::SecurityType::Tranche(TrancheSecurity {
+                    seniority: 1,
+                    ..Default::default()
+                }),
+                maximum_amount: None,
+                maximum_percentage: None,
             },
         ];
         let init_msg = InstantiateMsg {
@@ -276,10 +310,15 @@ mod tests {
             securities: securities.clone(),
             capital_denom: DEFAULT_CAPITAL_DENOM.to_string(),
             settlement_time: DEFAULT_TIME,
+            minimum_raise: None,
             fee: Some(Fee {
                 recipient: Some(Addr::unchecked("recipient")),
                 amount: Coin::new(Uint128::new(100), "nhash"),
             }),
+            maximum_lp_commitment: None,
+            lockup_period: None,
+            fee_schedule: None,
+            deposit_mode: None,
         };
 
         // initialize
@@ -339,14 +378,23 @@ mod tests {
                 amount: Uint128::new(1000),
                 minimum_amount: Uint128::new(100),
                 price_per_unit: Coin::new(Uint128::new(100), "denom"),
-                security_type: crate::core::security::SecurityType::Tranche(TrancheSecurity {}),
+                security_type: crate::core::security::SecurityType::Tranche(
+                    TrancheSecurity::default(),
+                ),
+                maximum_amount: None,
+                maximum_percentage: None,
             },
             Security {
                 name: "Tranche 2".to_string(),
                 amount: Uint128::new(1000),
                 minimum_amount: Uint128::new(100),
                 price_per_unit: Coin::new(Uint128::new(100), "denom"),
-                security_type: crate::core::security::SecurityType::Tranche(TrancheSecurity {}),
+                security_type: crate::core::security::SecurityType::Tranche(TrancheSecurity {
+                    seniority: 1,
+                    ..Default::default()
+                }),
+                maximum_amount: None,
+                maximum_percentage: None,
             },
         ];
         let init_msg = InstantiateMsg {
@@ -354,10 +402,15 @@ mod tests {
             securities: securities.clone(),
             capital_denom: DEFAULT_CAPITAL_DENOM.to_string(),
             settlement_time: DEFAULT_TIME,
+            minimum_raise: None,
             fee: Some(Fee {
                 recipient: None,
                 amount: Coin::new(Uint128::new(100), "nhash"),
             }),
+            maximum_lp_commitment: None,
+            lockup_period: None,
+            fee_schedule: None,
+            deposit_mode: None,
         };
 
         // initialize
@@ -417,7 +470,12 @@ mod tests {
             securities: securities.clone(),
             capital_denom: DEFAULT_CAPITAL_DENOM.to_string(),
             settlement_time: DEFAULT_TIME,
+            minimum_raise: None,
             fee: None,
+            maximum_lp_commitment: None,
+            lockup_period: None,
+            fee_schedule: None,
+            deposit_mode: None,
         };
 
         // initialize
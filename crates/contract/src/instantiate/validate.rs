@@ -1,7 +1,13 @@
-use cosmwasm_std::Coin;
+use std::mem::discriminant;
+
+use cosmwasm_std::{Coin, Decimal, Uint128};
 
 use crate::{
-    core::{error::ContractError, msg::InstantiateMsg},
+    core::{
+        error::ContractError,
+        msg::InstantiateMsg,
+        security::{MinimumRaise, SecurityType},
+    },
     util::validate::{Validate, ValidateResult},
 };
 
@@ -12,14 +18,17 @@ impl Validate for InstantiateMsg {
             return Err(ContractError::EmptySecurityList {});
         }
 
-        let same_type = self
-            .securities
-            .iter()
-            .all(|security| security.security_type == self.securities[0].security_type);
+        let same_type = self.securities.iter().all(|security| {
+            discriminant(&security.security_type) == discriminant(&self.securities[0].security_type)
+        });
         if !same_type {
             return Err(ContractError::InvalidSecurityList {});
         }
 
+        if !self.tranches_are_valid() {
+            return Err(ContractError::InvalidSecurityList {});
+        }
+
         let minimums_are_valid = self
             .securities
             .iter()
@@ -28,6 +37,15 @@ impl Validate for InstantiateMsg {
             return Err(ContractError::InvalidSecurityList {});
         }
 
+        let limits_are_valid = self
+            .securities
+            .iter()
+            .all(|security| security.limits_are_valid())
+            && self.maximum_lp_commitment != Some(Uint128::zero());
+        if !limits_are_valid {
+            return Err(ContractError::InvalidAllocationLimits {});
+        }
+
         let amounts_are_valid = self
             .securities
             .iter()
@@ -54,6 +72,24 @@ impl Validate for InstantiateMsg {
             return Err(ContractError::InvalidSecurityPriceDenom {});
         }
 
+        if let Some(minimum_raise) = &self.minimum_raise {
+            if !self.minimum_raise_is_valid(minimum_raise) {
+                return Err(ContractError::InvalidMinimumRaise {});
+            }
+            // The escrow is only resolved once the settlement time has passed
+            if self.settlement_time.is_none() {
+                return Err(ContractError::MinimumRaiseRequiresSettlementTime {});
+            }
+        }
+
+        if let Some(fee_schedule) = &self.fee_schedule {
+            if fee_schedule.management_fee_rate > Decimal::one()
+                || fee_schedule.carry_rate > Decimal::one()
+            {
+                return Err(ContractError::InvalidFeeSchedule {});
+            }
+        }
+
         Ok(())
     }
 
@@ -65,18 +101,63 @@ impl Validate for InstantiateMsg {
     }
 }
 
+impl InstantiateMsg {
+    // Every tranche needs its own rank in the waterfall, and a principal cap can't be zero
+    fn tranches_are_valid(&self) -> bool {
+        let mut seniorities = vec![];
+        for security in &self.securities {
+            if let SecurityType::Tranche(tranche) = &security.security_type {
+                if seniorities.contains(&tranche.seniority)
+                    || tranche.principal_cap == Some(Uint128::zero())
+                {
+                    return false;
+                }
+                seniorities.push(tranche.seniority);
+            }
+        }
+        true
+    }
+
+    // Every security minimum must be for an existing security, and the raise must be achievable
+    fn minimum_raise_is_valid(&self, minimum_raise: &MinimumRaise) -> bool {
+        match minimum_raise {
+            MinimumRaise::Securities(minimums) => {
+                !minimums.is_empty()
+                    && minimums.iter().all(|minimum| {
+                        self.securities.iter().any(|security| {
+                            security.name == minimum.name
+                                && !minimum.amount.is_zero()
+                                && minimum.amount <= security.amount
+                        })
+                    })
+            }
+            MinimumRaise::Offering(amount) => {
+                let total: Uint128 = self
+                    .securities
+                    .iter()
+                    .map(|security| security.amount * security.price_per_unit.amount)
+                    .sum();
+                !amount.is_zero() && *amount <= total
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::{Addr, Coin, Uint128};
+    use cosmwasm_std::{Addr, Coin, Decimal, Uint128, Uint64};
 
     use crate::{
         core::{
             error::ContractError,
-            fee::Fee,
+            fee::{Fee, FeeBasis, FeeSchedule},
             msg::InstantiateMsg,
-            security::{FundSecurity, Security, TrancheSecurity},
+            security::{
+                FundSecurity, MinimumRaise, Security, SecurityCommitment, SecurityType,
+                TrancheSecurity,
+            },
         },
-        util::validate::Validate,
+        util::{testing::test_init_message, validate::Validate},
     };
 
     #[test]
@@ -92,7 +173,11 @@ mod tests {
                         denom: "denom".to_string(),
                         amount: Uint128::new(5),
                     },
-                    security_type: crate::core::security::SecurityType::Tranche(TrancheSecurity {}),
+                    security_type: crate::core::security::SecurityType::Tranche(
+                        TrancheSecurity::default(),
+                    ),
+                    maximum_amount: None,
+                    maximum_percentage: None,
                 },
                 Security {
                     name: "security 2".to_string(),
@@ -102,15 +187,25 @@ mod tests {
                         denom: "denom".to_string(),
                         amount: Uint128::new(5),
                     },
-                    security_type: crate::core::security::SecurityType::Tranche(TrancheSecurity {}),
+                    security_type: crate::core::security::SecurityType::Tranche(TrancheSecurity {
+                        seniority: 1,
+                        ..Default::default()
+                    }),
+                    maximum_amount: None,
+                    maximum_percentage: None,
                 },
             ],
             capital_denom: "denom".to_string(),
             settlement_time: None,
+            minimum_raise: None,
             fee: Some(Fee {
                 recipient: Some(Addr::unchecked("receiver")),
                 amount: Coin::new(Uint128::new(100), "nhash"),
             }),
+            maximum_lp_commitment: None,
+            lockup_period: None,
+            fee_schedule: None,
+            deposit_mode: None,
         };
         let funds = vec![];
         msg.validate().expect("should pass validation");
@@ -131,7 +226,11 @@ mod tests {
                         denom: "denom".to_string(),
                         amount: Uint128::new(5),
                     },
-                    security_type: crate::core::security::SecurityType::Tranche(TrancheSecurity {}),
+                    security_type: crate::core::security::SecurityType::Tranche(
+                        TrancheSecurity::default(),
+                    ),
+                    maximum_amount: None,
+                    maximum_percentage: None,
                 },
                 Security {
                     name: "security 2".to_string(),
@@ -141,12 +240,22 @@ mod tests {
                         denom: "denom2".to_string(),
                         amount: Uint128::new(5),
                     },
-                    security_type: crate::core::security::SecurityType::Tranche(TrancheSecurity {}),
+                    security_type: crate::core::security::SecurityType::Tranche(TrancheSecurity {
+                        seniority: 1,
+                        ..Default::default()
+                    }),
+                    maximum_amount: None,
+                    maximum_percentage: None,
                 },
             ],
             capital_denom: "denom".to_string(),
             settlement_time: None,
+            minimum_raise: None,
             fee: None,
+            maximum_lp_commitment: None,
+            lockup_period: None,
+            fee_schedule: None,
+            deposit_mode: None,
         };
         let expected = ContractError::InvalidSecurityPriceDenom {}.to_string();
         let output = msg.validate().unwrap_err();
@@ -166,7 +275,11 @@ mod tests {
                         denom: "denom".to_string(),
                         amount: Uint128::new(5),
                     },
-                    security_type: crate::core::security::SecurityType::Tranche(TrancheSecurity {}),
+                    security_type: crate::core::security::SecurityType::Tranche(
+                        TrancheSecurity::default(),
+                    ),
+                    maximum_amount: None,
+                    maximum_percentage: None,
                 },
                 Security {
                     name: "security 2".to_string(),
@@ -176,12 +289,21 @@ mod tests {
                         denom: "denom".to_string(),
                         amount: Uint128::new(5),
                     },
-                    security_type: crate::core::security::SecurityType::Fund(FundSecurity {}),
+                    security_type: crate::core::security::SecurityType::Fund(
+                        FundSecurity::default(),
+                    ),
+                    maximum_amount: None,
+                    maximum_percentage: None,
                 },
             ],
             capital_denom: "denom".to_string(),
             settlement_time: None,
+            minimum_raise: None,
             fee: None,
+            maximum_lp_commitment: None,
+            lockup_period: None,
+            fee_schedule: None,
+            deposit_mode: None,
         };
         let expected = ContractError::InvalidSecurityList {}.to_string();
         let output = msg.validate().unwrap_err();
@@ -195,7 +317,12 @@ mod tests {
             securities: vec![],
             capital_denom: "denom".to_string(),
             settlement_time: None,
+            minimum_raise: None,
             fee: None,
+            maximum_lp_commitment: None,
+            lockup_period: None,
+            fee_schedule: None,
+            deposit_mode: None,
         };
         let output = msg.validate().unwrap_err();
         let expected = ContractError::EmptySecurityList {}.to_string();
@@ -215,7 +342,11 @@ mod tests {
                         denom: "denom".to_string(),
                         amount: Uint128::new(5),
                     },
-                    security_type: crate::core::security::SecurityType::Tranche(TrancheSecurity {}),
+                    security_type: crate::core::security::SecurityType::Tranche(
+                        TrancheSecurity::default(),
+                    ),
+                    maximum_amount: None,
+                    maximum_percentage: None,
                 },
                 Security {
                     name: "security 1".to_string(),
@@ -225,12 +356,21 @@ mod tests {
                         denom: "denom".to_string(),
                         amount: Uint128::new(5),
                     },
-                    security_type: crate::core::security::SecurityType::Fund(FundSecurity {}),
+                    security_type: crate::core::security::SecurityType::Fund(
+                        FundSecurity::default(),
+                    ),
+                    maximum_amount: None,
+                    maximum_percentage: None,
                 },
             ],
             capital_denom: "denom".to_string(),
             settlement_time: None,
+            minimum_raise: None,
             fee: None,
+            maximum_lp_commitment: None,
+            lockup_period: None,
+            fee_schedule: None,
+            deposit_mode: None,
         };
         let expected = ContractError::InvalidSecurityList {}.to_string();
         let output = msg.validate().unwrap_err();
@@ -250,7 +390,11 @@ mod tests {
                         denom: "denom".to_string(),
                         amount: Uint128::new(5),
                     },
-                    security_type: crate::core::security::SecurityType::Tranche(TrancheSecurity {}),
+                    security_type: crate::core::security::SecurityType::Tranche(
+                        TrancheSecurity::default(),
+                    ),
+                    maximum_amount: None,
+                    maximum_percentage: None,
                 },
                 Security {
                     name: "security 2".to_string(),
@@ -260,12 +404,22 @@ mod tests {
                         denom: "denom".to_string(),
                         amount: Uint128::new(5),
                     },
-                    security_type: crate::core::security::SecurityType::Tranche(TrancheSecurity {}),
+                    security_type: crate::core::security::SecurityType::Tranche(TrancheSecurity {
+                        seniority: 1,
+                        ..Default::default()
+                    }),
+                    maximum_amount: None,
+                    maximum_percentage: None,
                 },
             ],
             capital_denom: "denom".to_string(),
             settlement_time: None,
+            minimum_raise: None,
             fee: None,
+            maximum_lp_commitment: None,
+            lockup_period: None,
+            fee_schedule: None,
+            deposit_mode: None,
         };
         let expected = ContractError::InvalidSecurityList {}.to_string();
         let output = msg.validate().unwrap_err();
@@ -285,7 +439,11 @@ mod tests {
                         denom: "denom".to_string(),
                         amount: Uint128::new(5),
                     },
-                    security_type: crate::core::security::SecurityType::Tranche(TrancheSecurity {}),
+                    security_type: crate::core::security::SecurityType::Tranche(
+                        TrancheSecurity::default(),
+                    ),
+                    maximum_amount: None,
+                    maximum_percentage: None,
                 },
                 Security {
                     name: "security 2".to_string(),
@@ -295,12 +453,22 @@ mod tests {
                         denom: "denom".to_string(),
                         amount: Uint128::new(5),
                     },
-                    security_type: crate::core::security::SecurityType::Tranche(TrancheSecurity {}),
+                    security_type: crate::core::security::SecurityType::Tranche(TrancheSecurity {
+                        seniority: 1,
+                        ..Default::default()
+                    }),
+                    maximum_amount: None,
+                    maximum_percentage: None,
                 },
             ],
             capital_denom: "denom".to_string(),
             settlement_time: None,
+            minimum_raise: None,
             fee: None,
+            maximum_lp_commitment: None,
+            lockup_period: None,
+            fee_schedule: None,
+            deposit_mode: None,
         };
         let expected = ContractError::InvalidSecurityList {}.to_string();
         let output = msg.validate().unwrap_err();
@@ -319,11 +487,20 @@ mod tests {
                     denom: "denom".to_string(),
                     amount: Uint128::new(5),
                 },
-                security_type: crate::core::security::SecurityType::Tranche(TrancheSecurity {}),
+                security_type: crate::core::security::SecurityType::Tranche(
+                    TrancheSecurity::default(),
+                ),
+                maximum_amount: None,
+                maximum_percentage: None,
             }],
             capital_denom: "".to_string(),
             settlement_time: None,
+            minimum_raise: None,
             fee: None,
+            maximum_lp_commitment: None,
+            lockup_period: None,
+            fee_schedule: None,
+            deposit_mode: None,
         };
         let expected = ContractError::InvalidCapitalDenom {}.to_string();
         let output = msg.validate().unwrap_err();
@@ -343,7 +520,11 @@ mod tests {
                         denom: "denom".to_string(),
                         amount: Uint128::new(5),
                     },
-                    security_type: crate::core::security::SecurityType::Tranche(TrancheSecurity {}),
+                    security_type: crate::core::security::SecurityType::Tranche(
+                        TrancheSecurity::default(),
+                    ),
+                    maximum_amount: None,
+                    maximum_percentage: None,
                 },
                 Security {
                     name: "security 2".to_string(),
@@ -353,12 +534,22 @@ mod tests {
                         denom: "denom".to_string(),
                         amount: Uint128::new(5),
                     },
-                    security_type: crate::core::security::SecurityType::Tranche(TrancheSecurity {}),
+                    security_type: crate::core::security::SecurityType::Tranche(TrancheSecurity {
+                        seniority: 1,
+                        ..Default::default()
+                    }),
+                    maximum_amount: None,
+                    maximum_percentage: None,
                 },
             ],
             capital_denom: "denom".to_string(),
             settlement_time: None,
+            minimum_raise: None,
             fee: None,
+            maximum_lp_commitment: None,
+            lockup_period: None,
+            fee_schedule: None,
+            deposit_mode: None,
         };
         let funds = vec![Coin {
             denom: "denom".to_string(),
@@ -369,4 +560,181 @@ mod tests {
 
         assert_eq!(expected.to_string(), output.to_string());
     }
+
+    #[test]
+    fn test_minimum_raise_securities_must_exist() {
+        let mut msg = test_init_message();
+        msg.minimum_raise = Some(MinimumRaise::Securities(vec![SecurityCommitment {
+            name: "invalid".to_string(),
+            amount: Uint128::new(5),
+        }]));
+        let expected = ContractError::InvalidMinimumRaise {}.to_string();
+        let output = msg.validate().unwrap_err();
+        assert_eq!(expected, output.to_string());
+    }
+
+    #[test]
+    fn test_minimum_raise_securities_cannot_exceed_amount() {
+        let mut msg = test_init_message();
+        msg.minimum_raise = Some(MinimumRaise::Securities(vec![SecurityCommitment {
+            name: msg.securities[0].name.clone(),
+            amount: msg.securities[0].amount + Uint128::one(),
+        }]));
+        let expected = ContractError::InvalidMinimumRaise {}.to_string();
+        let output = msg.validate().unwrap_err();
+        assert_eq!(expected, output.to_string());
+    }
+
+    #[test]
+    fn test_minimum_raise_offering_cannot_exceed_total() {
+        let mut msg = test_init_message();
+        let total: Uint128 = msg
+            .securities
+            .iter()
+            .map(|security| security.amount * security.price_per_unit.amount)
+            .sum();
+        msg.minimum_raise = Some(MinimumRaise::Offering(total + Uint128::one()));
+        let expected = ContractError::InvalidMinimumRaise {}.to_string();
+        let output = msg.validate().unwrap_err();
+        assert_eq!(expected, output.to_string());
+
+        msg.minimum_raise = Some(MinimumRaise::Offering(Uint128::zero()));
+        let output = msg.validate().unwrap_err();
+        assert_eq!(expected, output.to_string());
+    }
+
+    #[test]
+    fn test_minimum_raise_requires_settlement_time() {
+        let mut msg = test_init_message();
+        msg.settlement_time = None;
+        msg.minimum_raise = Some(MinimumRaise::Offering(Uint128::new(1000)));
+        let expected = ContractError::MinimumRaiseRequiresSettlementTime {}.to_string();
+        let output = msg.validate().unwrap_err();
+        assert_eq!(expected, output.to_string());
+    }
+
+    #[test]
+    fn test_valid_minimum_raise() {
+        let mut msg = test_init_message();
+        msg.settlement_time = Some(Uint64::new(9999));
+        msg.minimum_raise = Some(MinimumRaise::Offering(Uint128::new(1000)));
+        msg.validate()
+            .expect("should accept an offering minimum raise");
+
+        msg.minimum_raise = Some(MinimumRaise::Securities(vec![SecurityCommitment {
+            name: msg.securities[0].name.clone(),
+            amount: Uint128::new(100),
+        }]));
+        msg.validate()
+            .expect("should accept a security minimum raise");
+    }
+
+    fn test_tranche_message(seniorities: &[u32]) -> InstantiateMsg {
+        let mut msg = test_init_message();
+        for (security, seniority) in msg.securities.iter_mut().zip(seniorities) {
+            security.security_type = SecurityType::Tranche(TrancheSecurity {
+                seniority: *seniority,
+                target_return: Decimal::percent(8),
+                principal_cap: None,
+            });
+        }
+        msg
+    }
+
+    #[test]
+    fn test_tranches_with_different_terms_are_the_same_type() {
+        let msg = test_tranche_message(&[0, 1]);
+        msg.validate()
+            .expect("should accept tranches with different seniorities");
+    }
+
+    #[test]
+    fn test_allocation_limits_must_be_valid() {
+        let mut msg = test_init_message();
+        msg.maximum_lp_commitment = Some(Uint128::zero());
+        let error = msg.validate().unwrap_err();
+        assert_eq!(
+            ContractError::InvalidAllocationLimits {}.to_string(),
+            error.to_string()
+        );
+
+        let invalid_limits = [
+            (Some(Uint128::zero()), None),
+            (Some(Uint128::new(9)), None),
+            (None, Some(Decimal::zero())),
+            (None, Some(Decimal::percent(101))),
+            (None, Some(Decimal::permille(9))),
+        ];
+        for (maximum_amount, maximum_percentage) in invalid_limits {
+            let mut msg = test_init_message();
+            msg.securities[0].maximum_amount = maximum_amount;
+            msg.securities[0].maximum_percentage = maximum_percentage;
+            let error = msg.validate().unwrap_err();
+            assert_eq!(
+                ContractError::InvalidAllocationLimits {}.to_string(),
+                error.to_string()
+            );
+        }
+
+        let mut msg = test_init_message();
+        msg.securities[0].maximum_amount = Some(Uint128::new(10));
+        msg.securities[0].maximum_percentage = Some(Decimal::percent(1));
+        msg.maximum_lp_commitment = Some(Uint128::new(1000));
+        msg.validate().expect("should accept valid limits");
+    }
+
+    #[test]
+    fn test_fee_schedule_rates_cannot_exceed_one() {
+        let schedule = FeeSchedule {
+            recipient: Addr::unchecked("manager"),
+            management_fee_rate: Decimal::percent(2),
+            management_fee_basis: FeeBasis::Called,
+            carry_rate: Decimal::percent(20),
+            hurdle: Decimal::percent(8),
+        };
+        let mut msg = test_init_message();
+        msg.fee_schedule = Some(schedule.clone());
+        msg.validate().expect("should accept a valid fee schedule");
+
+        for (management_fee_rate, carry_rate) in [
+            (Decimal::percent(101), Decimal::percent(20)),
+            (Decimal::percent(2), Decimal::percent(101)),
+        ] {
+            msg.fee_schedule = Some(FeeSchedule {
+                management_fee_rate,
+                carry_rate,
+                ..schedule.clone()
+            });
+            let error = msg.validate().unwrap_err();
+            assert_eq!(
+                ContractError::InvalidFeeSchedule {}.to_string(),
+                error.to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn test_tranches_must_have_unique_seniority() {
+        let msg = test_tranche_message(&[1, 1]);
+        let error = msg.validate().unwrap_err();
+        assert_eq!(
+            ContractError::InvalidSecurityList {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_tranche_principal_cap_cannot_be_zero() {
+        let mut msg = test_tranche_message(&[0, 1]);
+        msg.securities[0].security_type = SecurityType::Tranche(TrancheSecurity {
+            seniority: 0,
+            target_return: Decimal::percent(8),
+            principal_cap: Some(Uint128::zero()),
+        });
+        let error = msg.validate().unwrap_err();
+        assert_eq!(
+            ContractError::InvalidSecurityList {}.to_string(),
+            error.to_string()
+        );
+    }
 }
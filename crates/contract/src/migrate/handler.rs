@@ -1,24 +1,42 @@
-use cosmwasm_std::{Env, Response, Storage};
+use cosmwasm_std::{Env, Event, Response, Storage};
 use semver::Version;
 
 use crate::{
     core::{
         aliases::{ProvDepsMut, ProvTxResponse},
         constants::{CONTRACT_NAME, CONTRACT_VERSION},
+        error::ContractError,
         msg::MigrateMsg,
     },
-    util::validate::ValidateResult,
+    storage::state,
 };
 
-pub fn handle(deps: &ProvDepsMut, _env: Env, _msg: MigrateMsg) -> ProvTxResponse {
-    validate_migration(deps.storage)?;
+use super::registry;
 
-    // Do migration here
+pub fn handle(deps: ProvDepsMut, _env: Env, msg: MigrateMsg) -> ProvTxResponse {
+    let previous_version = validate_migration(deps.storage)?;
 
-    Ok(Response::new())
+    let applied = registry::run(deps.storage, &previous_version, &msg)?;
+
+    if let Some(paused) = msg.paused {
+        let mut state = state::get(deps.storage)?;
+        state.paused = paused;
+        state::set(deps.storage, &state)?;
+    }
+
+    let steps: Vec<String> = applied.iter().map(|version| version.to_string()).collect();
+    Ok(Response::new()
+        .add_event(
+            Event::new("migrated")
+                .add_attribute("previous_version", previous_version.to_string())
+                .add_attribute("version", CONTRACT_VERSION)
+                .add_attribute("steps", steps.join(",")),
+        )
+        .add_attribute("action", "migrate"))
 }
 
-fn validate_migration(storage: &dyn Storage) -> ValidateResult {
+// Returns the version of the contract being migrated from
+fn validate_migration(storage: &dyn Storage) -> Result<Version, ContractError> {
     let version: Version = CONTRACT_VERSION.parse()?;
     let storage_version: Version = cw2::get_contract_version(storage)?.version.parse().unwrap();
     let ver = cw2::get_contract_version(storage)?;
@@ -31,5 +49,76 @@ fn validate_migration(storage: &dyn Storage) -> ValidateResult {
         return Err(crate::core::error::ContractError::InvalidVersion {});
     }
 
-    Ok(())
+    Ok(storage_version)
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{testing::mock_env, Attribute, Event};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        core::{
+            constants::{CONTRACT_NAME, CONTRACT_VERSION},
+            error::ContractError,
+            msg::MigrateMsg,
+        },
+        storage::state,
+        util::testing::instantiate_contract,
+    };
+
+    use super::handle;
+
+    #[test]
+    fn test_handle_fails_for_same_version() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).unwrap();
+
+        let error = handle(deps.as_mut(), mock_env(), MigrateMsg::default()).unwrap_err();
+        assert_eq!(
+            ContractError::InvalidVersion {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_fails_for_other_contract() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).unwrap();
+        cw2::set_contract_version(deps.as_mut().storage, "other", "1.0.0").unwrap();
+
+        let error = handle(deps.as_mut(), mock_env(), MigrateMsg::default()).unwrap_err();
+        assert_eq!(
+            ContractError::ContractNameMismatch {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_handle_runs_newer_steps() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).unwrap();
+        cw2::set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "1.1.1").unwrap();
+
+        let res = handle(deps.as_mut(), mock_env(), MigrateMsg::default()).unwrap();
+        assert_eq!(vec![Attribute::new("action", "migrate")], res.attributes);
+        assert_eq!(
+            vec![Event::new("migrated")
+                .add_attribute("previous_version", "1.1.1")
+                .add_attribute("version", CONTRACT_VERSION)
+                .add_attribute("steps", "1.2.0")],
+            res.events
+        );
+        assert!(!state::get(&deps.storage).unwrap().paused);
+    }
+
+    #[test]
+    fn test_handle_can_leave_contract_paused() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).unwrap();
+        cw2::set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "1.1.1").unwrap();
+
+        handle(deps.as_mut(), mock_env(), MigrateMsg { paused: Some(true) }).unwrap();
+        assert!(state::get(&deps.storage).unwrap().paused);
+    }
 }
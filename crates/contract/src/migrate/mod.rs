@@ -1,2 +1,4 @@
 pub mod handler;
+pub mod registry;
+pub mod v1_2_0;
 pub mod validate;
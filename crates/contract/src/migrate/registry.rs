@@ -0,0 +1,132 @@
+use cosmwasm_std::Storage;
+use semver::Version;
+
+use crate::core::{constants::CONTRACT_VERSION, error::ContractError, msg::MigrateMsg};
+
+use super::v1_2_0;
+
+// A step transforms the data stored by earlier versions of the contract
+pub type MigrationStep = fn(&mut dyn Storage, &MigrateMsg) -> Result<(), ContractError>;
+
+// Every migration step in order, keyed by the version that introduced it
+pub const MIGRATIONS: &[(&str, MigrationStep)] = &[("1.2.0", v1_2_0::migrate)];
+
+// Runs every registered step newer than the stored version, returning the versions that were applied
+pub fn run(
+    storage: &mut dyn Storage,
+    from: &Version,
+    msg: &MigrateMsg,
+) -> Result<Vec<Version>, ContractError> {
+    run_steps(storage, MIGRATIONS, from, &CONTRACT_VERSION.parse()?, msg)
+}
+
+fn run_steps(
+    storage: &mut dyn Storage,
+    steps: &[(&str, MigrationStep)],
+    from: &Version,
+    to: &Version,
+    msg: &MigrateMsg,
+) -> Result<Vec<Version>, ContractError> {
+    let mut applied = vec![];
+    for (version, step) in steps {
+        let version: Version = version.parse()?;
+        if version > *from && version <= *to {
+            step(storage, msg)?;
+            applied.push(version);
+        }
+    }
+    Ok(applied)
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Storage;
+    use provwasm_mocks::mock_provenance_dependencies;
+    use semver::Version;
+
+    use crate::core::{constants::CONTRACT_VERSION, error::ContractError, msg::MigrateMsg};
+
+    use super::{run_steps, MigrationStep, MIGRATIONS};
+
+    fn append(storage: &mut dyn Storage, value: u8) {
+        let mut steps = storage.get(b"steps").unwrap_or_default();
+        steps.push(value);
+        storage.set(b"steps", &steps);
+    }
+
+    fn step_one(storage: &mut dyn Storage, _msg: &MigrateMsg) -> Result<(), ContractError> {
+        append(storage, 1);
+        Ok(())
+    }
+
+    fn step_two(storage: &mut dyn Storage, _msg: &MigrateMsg) -> Result<(), ContractError> {
+        append(storage, 2);
+        Ok(())
+    }
+
+    fn step_three(storage: &mut dyn Storage, _msg: &MigrateMsg) -> Result<(), ContractError> {
+        append(storage, 3);
+        Ok(())
+    }
+
+    const STEPS: &[(&str, MigrationStep)] = &[
+        ("1.0.0", step_one),
+        ("1.1.0", step_two),
+        ("2.0.0", step_three),
+    ];
+
+    #[test]
+    fn test_registry_is_ordered_and_released() {
+        let current: Version = CONTRACT_VERSION.parse().unwrap();
+        let versions: Vec<Version> = MIGRATIONS
+            .iter()
+            .map(|(version, _)| version.parse().unwrap())
+            .collect();
+        assert!(versions.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(versions.iter().all(|version| *version <= current));
+    }
+
+    #[test]
+    fn test_run_steps_applies_newer_steps_in_order() {
+        let mut deps = mock_provenance_dependencies();
+
+        let applied = run_steps(
+            &mut deps.storage,
+            STEPS,
+            &"0.9.0".parse().unwrap(),
+            &"1.5.0".parse().unwrap(),
+            &MigrateMsg::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            vec![
+                "1.0.0".parse::<Version>().unwrap(),
+                "1.1.0".parse().unwrap()
+            ],
+            applied
+        );
+        assert_eq!(Some(vec![1, 2]), deps.storage.get(b"steps"));
+    }
+
+    #[test]
+    fn test_run_steps_skips_applied_steps() {
+        let mut deps = mock_provenance_dependencies();
+
+        let applied = run_steps(
+            &mut deps.storage,
+            STEPS,
+            &"1.0.0".parse().unwrap(),
+            &"2.0.0".parse().unwrap(),
+            &MigrateMsg::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            vec![
+                "1.1.0".parse::<Version>().unwrap(),
+                "2.0.0".parse().unwrap()
+            ],
+            applied
+        );
+        assert_eq!(Some(vec![2, 3]), deps.storage.get(b"steps"));
+    }
+}
@@ -0,0 +1,256 @@
+use cosmwasm_std::{Addr, Order, StdResult, Storage};
+use cw_storage_plus::Map;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::{
+        collateral::LoanPoolMarkerCollateral,
+        constants::PAID_IN_CAPITAL_KEY,
+        error::ContractError,
+        msg::MigrateMsg,
+        security::{SecurityCommitment, SecurityType},
+    },
+    storage::{commits, loan_pool_collateral, offering_totals, securities},
+};
+
+// Before 1.2.0 the loan pool collateral was stored under the paid in capital key,
+// keyed by marker address instead of by lp
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum LegacyValue {
+    Collateral(LoanPoolMarkerCollateral),
+    PaidInCapital(Vec<SecurityCommitment>),
+}
+
+const LEGACY: Map<Addr, LegacyValue> = Map::new(PAID_IN_CAPITAL_KEY);
+
+pub fn migrate(storage: &mut dyn Storage, msg: &MigrateMsg) -> Result<(), ContractError> {
+    move_loan_pool_collateral(storage, msg)?;
+    index_commitments(storage, msg)?;
+    total_offering(storage, msg)?;
+    rank_tranches(storage, msg)
+}
+
+// Commitments saved before 1.2.0 are missing from the commitment state index
+pub fn index_commitments(
+    storage: &mut dyn Storage,
+    _msg: &MigrateMsg,
+) -> Result<(), ContractError> {
+    commits::reindex(storage)
+}
+
+// Offerings saved before 1.2.0 have no running totals, so they're counted from what's stored. This
+// runs after the loan pool collateral has been moved out of the paid in capital.
+pub fn total_offering(storage: &mut dyn Storage, _msg: &MigrateMsg) -> Result<(), ContractError> {
+    offering_totals::rebuild(storage)
+}
+
+// Tranches saved before 1.2.0 have no terms, so they all default to the same seniority. When
+// seniorities are shared, every tranche is ranked in the order the securities are stored.
+pub fn rank_tranches(storage: &mut dyn Storage, _msg: &MigrateMsg) -> Result<(), ContractError> {
+    let mut tranches = vec![];
+    for name in securities::get_security_types(storage) {
+        let security = securities::get(storage, name)?;
+        if let SecurityType::Tranche(tranche) = &security.security_type {
+            tranches.push((tranche.seniority, security));
+        }
+    }
+
+    let mut seniorities: Vec<u32> = tranches.iter().map(|(seniority, _)| *seniority).collect();
+    seniorities.sort_unstable();
+    seniorities.dedup();
+    if seniorities.len() == tranches.len() {
+        return Ok(());
+    }
+
+    for (seniority, (_, mut security)) in (0..).zip(tranches) {
+        if let SecurityType::Tranche(tranche) = &mut security.security_type {
+            tranche.seniority = seniority;
+        }
+        securities::set(storage, &security)?;
+    }
+    Ok(())
+}
+
+// Moves the loan pool collateral into its own namespace, leaving the paid in capital in place
+pub fn move_loan_pool_collateral(
+    storage: &mut dyn Storage,
+    _msg: &MigrateMsg,
+) -> Result<(), ContractError> {
+    let collaterals = LEGACY
+        .range(storage, None, None, Order::Ascending)
+        .filter_map(|item| match item {
+            Ok((_, LegacyValue::Collateral(collateral))) => Some(Ok(collateral)),
+            Ok(_) => None,
+            Err(error) => Some(Err(error)),
+        })
+        .collect::<StdResult<Vec<LoanPoolMarkerCollateral>>>()?;
+
+    for collateral in collaterals {
+        LEGACY.remove(storage, collateral.marker_address.clone());
+        loan_pool_collateral::set(storage, &collateral)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{Addr, Storage, Uint128};
+    use cw_storage_plus::Map;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        core::{
+            collateral::LoanPoolMarkerCollateral,
+            constants::COMMITS_KEY,
+            msg::MigrateMsg,
+            security::{SecurityCommitment, SecurityType, TrancheSecurity},
+        },
+        execute::settlement::commitment::{Commitment, CommitmentState},
+        storage::{commits, loan_pool_collateral, offering_totals, paid_in_capital, securities},
+        util::testing::create_test_securities,
+    };
+
+    use super::{
+        index_commitments, move_loan_pool_collateral, rank_tranches, total_offering, LegacyValue,
+        LEGACY,
+    };
+
+    #[test]
+    fn test_moves_collateral_and_keeps_paid_in_capital() {
+        let mut deps = mock_provenance_dependencies();
+        let collateral = LoanPoolMarkerCollateral::new(
+            Addr::unchecked("marker"),
+            "denom".to_string(),
+            100,
+            Addr::unchecked("owner"),
+            vec![],
+        );
+        let capital = vec![SecurityCommitment {
+            name: "Security1".to_string(),
+            amount: Uint128::new(5),
+        }];
+        LEGACY
+            .save(
+                &mut deps.storage,
+                Addr::unchecked("marker"),
+                &LegacyValue::Collateral(collateral.clone()),
+            )
+            .unwrap();
+        paid_in_capital::set(&mut deps.storage, Addr::unchecked("lp"), &capital).unwrap();
+
+        move_loan_pool_collateral(&mut deps.storage, &MigrateMsg::default()).unwrap();
+
+        assert_eq!(
+            collateral,
+            loan_pool_collateral::get(&deps.storage, Addr::unchecked("marker")).unwrap()
+        );
+        assert!(!paid_in_capital::has_lp(
+            &deps.storage,
+            Addr::unchecked("marker")
+        ));
+        assert_eq!(
+            capital,
+            paid_in_capital::get(&deps.storage, Addr::unchecked("lp"))
+        );
+    }
+
+    #[test]
+    fn test_indexes_existing_commitments() {
+        let mut deps = mock_provenance_dependencies();
+        let commitment = Commitment::new(Addr::unchecked("lp"), vec![]);
+        // Saved without the index, the way earlier versions stored commitments
+        let unindexed: Map<Addr, Commitment> = Map::new(COMMITS_KEY);
+        unindexed
+            .save(&mut deps.storage, Addr::unchecked("lp"), &commitment)
+            .unwrap();
+        assert!(commits::get_with_state(&deps.storage, CommitmentState::PENDING).is_empty());
+
+        index_commitments(&mut deps.storage, &MigrateMsg::default()).unwrap();
+
+        assert_eq!(
+            vec![commitment],
+            commits::get_with_state(&deps.storage, CommitmentState::PENDING)
+        );
+    }
+
+    #[test]
+    fn test_totals_existing_offering() {
+        let mut deps = mock_provenance_dependencies();
+        let units = vec![SecurityCommitment {
+            name: "Security1".to_string(),
+            amount: Uint128::new(20),
+        }];
+        let mut commitment = Commitment::new(Addr::unchecked("lp"), units.clone());
+        commitment.state = CommitmentState::ACCEPTED;
+        let unindexed: Map<Addr, Commitment> = Map::new(COMMITS_KEY);
+        unindexed
+            .save(&mut deps.storage, Addr::unchecked("lp"), &commitment)
+            .unwrap();
+        LEGACY
+            .save(
+                &mut deps.storage,
+                Addr::unchecked("lp"),
+                &LegacyValue::PaidInCapital(units),
+            )
+            .unwrap();
+
+        index_commitments(&mut deps.storage, &MigrateMsg::default()).unwrap();
+        total_offering(&mut deps.storage, &MigrateMsg::default()).unwrap();
+
+        let totals = offering_totals::get(&deps.storage, "Security1".to_string()).unwrap();
+        assert_eq!(Uint128::new(20), totals.accepted);
+        assert_eq!(Uint128::new(20), totals.paid_in);
+    }
+
+    fn seniority(storage: &dyn Storage, name: &str) -> Option<u32> {
+        match securities::get(storage, name.to_string())
+            .unwrap()
+            .security_type
+        {
+            SecurityType::Tranche(tranche) => Some(tranche.seniority),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_ranks_tranches_that_share_a_seniority() {
+        let mut deps = mock_provenance_dependencies();
+        let mut fund = create_test_securities()[0].clone();
+        fund.name = "Fund".to_string();
+        securities::set(&mut deps.storage, &fund).unwrap();
+        // Saved without terms, the way earlier versions stored tranches
+        for name in ["Senior", "Junior", "Mezzanine"] {
+            let mut tranche = create_test_securities()[0].clone();
+            tranche.name = name.to_string();
+            tranche.security_type = SecurityType::Tranche(TrancheSecurity::default());
+            securities::set(&mut deps.storage, &tranche).unwrap();
+        }
+
+        rank_tranches(&mut deps.storage, &MigrateMsg::default()).unwrap();
+
+        assert_eq!(None, seniority(&deps.storage, "Fund"));
+        assert_eq!(Some(0), seniority(&deps.storage, "Junior"));
+        assert_eq!(Some(1), seniority(&deps.storage, "Mezzanine"));
+        assert_eq!(Some(2), seniority(&deps.storage, "Senior"));
+    }
+
+    #[test]
+    fn test_keeps_distinct_tranche_seniorities() {
+        let mut deps = mock_provenance_dependencies();
+        for (name, seniority) in [("Junior", 4), ("Senior", 2)] {
+            let mut tranche = create_test_securities()[0].clone();
+            tranche.name = name.to_string();
+            tranche.security_type = SecurityType::Tranche(TrancheSecurity {
+                seniority,
+                ..TrancheSecurity::default()
+            });
+            securities::set(&mut deps.storage, &tranche).unwrap();
+        }
+
+        rank_tranches(&mut deps.storage, &MigrateMsg::default()).unwrap();
+
+        assert_eq!(Some(4), seniority(&deps.storage, "Junior"));
+        assert_eq!(Some(2), seniority(&deps.storage, "Senior"));
+    }
+}
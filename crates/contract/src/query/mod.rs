@@ -1,7 +1,15 @@
+mod query_cap_table;
+mod query_claimable;
+mod query_commitment_history;
 mod query_commitments;
+mod query_fee_accruals;
 mod query_investor;
 pub mod query_loan_pool_collaterals;
+mod query_nav_history;
+mod query_offering_status;
 mod query_securitizations;
+mod query_simulate_deposit;
+mod query_simulate_propose;
 mod query_state;
 mod query_version;
 pub mod query_white_list_contributors;
@@ -0,0 +1,308 @@
+use std::iter::{from_fn, Peekable};
+
+use cosmwasm_std::{to_json_binary, Addr, Decimal, Order, StdResult, Storage, Uint128};
+use cw_storage_plus::Bound;
+
+use crate::{
+    core::{
+        aliases::ProvQueryResponse,
+        msg::{CapTableEntry, CapTableSecurity, QueryCapTableResponse},
+        security::SecurityCommitment,
+    },
+    execute::settlement::commitment::CommitmentState,
+    storage::{commits, distributions, paid_in_capital, remaining_securities, securities},
+    util::pagination::paginate,
+};
+
+pub fn handle(
+    storage: &dyn Storage,
+    security: Option<String>,
+    start_after: Option<Addr>,
+    limit: Option<u32>,
+) -> ProvQueryResponse {
+    let names = match security {
+        Some(security) => vec![securities::get(storage, security)?.name],
+        None => securities::get_security_types(storage),
+    };
+
+    let mut totals = vec![];
+    for name in &names {
+        let total = securities::get(storage, name.clone())?.amount;
+        let remaining = Uint128::new(remaining_securities::get(storage, name.clone())?);
+        totals.push(CapTableSecurity {
+            name: name.clone(),
+            total,
+            sold: total.saturating_sub(remaining),
+            remaining,
+        });
+    }
+
+    let rows = lps(storage, start_after)
+        .map(|lp| lp.map(|lp| (lp.clone(), entries(storage, &lp, &totals))))
+        .filter(|row| !matches!(row, Ok((_, entries)) if entries.is_empty()));
+    let (rows, next_start_after) = paginate(rows, limit, |(lp, _)| lp.clone())?;
+
+    Ok(to_json_binary(&QueryCapTableResponse {
+        securities: totals,
+        entries: rows.into_iter().flat_map(|(_, entries)| entries).collect(),
+        next_start_after,
+    })?)
+}
+
+// Every lp with a commitment or investment tokens, in order. Lps that were transferred investment
+// tokens don't have a commitment, so the holders are merged in with the lps.
+fn lps(
+    storage: &dyn Storage,
+    start_after: Option<Addr>,
+) -> impl Iterator<Item = StdResult<Addr>> + '_ {
+    let committed = commits::COMMITS
+        .keys(
+            storage,
+            start_after.clone().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .peekable();
+    let holders = distributions::HOLDERS
+        .keys(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .peekable();
+    merge(committed, holders)
+}
+
+// Merges two ordered iterators of addresses, skipping duplicates
+fn merge<'a>(
+    mut left: Peekable<impl Iterator<Item = StdResult<Addr>> + 'a>,
+    mut right: Peekable<impl Iterator<Item = StdResult<Addr>> + 'a>,
+) -> impl Iterator<Item = StdResult<Addr>> + 'a {
+    let mut last: Option<Addr> = None;
+    from_fn(move || loop {
+        let next = match (left.peek(), right.peek()) {
+            (Some(Ok(l)), Some(Ok(r))) if r < l => right.next(),
+            (Some(_), _) => left.next(),
+            (None, _) => right.next(),
+        };
+        match next {
+            Some(Ok(lp)) if last.as_ref() == Some(&lp) => continue,
+            Some(Ok(lp)) => {
+                last = Some(lp.clone());
+                return Some(Ok(lp));
+            }
+            other => return other,
+        }
+    })
+}
+
+// The lp's position in each of the securities, leaving out securities they have no part in
+fn entries(storage: &dyn Storage, lp: &Addr, totals: &[CapTableSecurity]) -> Vec<CapTableEntry> {
+    let commitment = commits::get(storage, lp.clone())
+        .ok()
+        .filter(|commitment| !commitment.state.is_terminated());
+    let settled = commitment
+        .as_ref()
+        .is_some_and(|commitment| commitment.state == CommitmentState::SETTLED);
+    let paid_in = paid_in_capital::get(storage, lp.clone());
+
+    let mut entries = vec![];
+    for total in totals {
+        let committed = commitment.as_ref().map_or(Uint128::zero(), |commitment| {
+            amount_of(&commitment.commitments, &total.name)
+        });
+        let investment_tokens = distributions::get_holding(storage, lp.clone(), total.name.clone())
+            .map_or(Uint128::zero(), |holding| holding.units);
+        if committed.is_zero() && investment_tokens.is_zero() {
+            continue;
+        }
+
+        // Until the commitment settles the lp's share is what they committed to
+        let position = if commitment.is_none() || settled {
+            investment_tokens
+        } else {
+            committed
+        };
+        entries.push(CapTableEntry {
+            lp: lp.clone(),
+            security: total.name.clone(),
+            committed,
+            paid_in_capital: amount_of(&paid_in, &total.name),
+            settled: if settled { committed } else { Uint128::zero() },
+            investment_tokens,
+            percentage: if total.total.is_zero() {
+                Decimal::zero()
+            } else {
+                Decimal::from_ratio(position, total.total)
+            },
+        });
+    }
+    entries
+}
+
+fn amount_of(commitments: &[SecurityCommitment], security: &str) -> Uint128 {
+    commitments
+        .iter()
+        .find(|commitment| commitment.name == security)
+        .map_or(Uint128::zero(), |commitment| commitment.amount)
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{from_json, testing::mock_env, Addr, Decimal, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        contract::query,
+        core::msg::{CapTableEntry, CapTableSecurity, QueryCapTableResponse, QueryMsg},
+        storage::distributions,
+        util::testing::{cancel_test, create_testing_commitments, instantiate_contract},
+    };
+
+    fn query_cap_table(
+        deps: &crate::util::testing::MockDeps,
+        security: Option<&str>,
+        start_after: Option<Addr>,
+        limit: Option<u32>,
+    ) -> QueryCapTableResponse {
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::QueryCapTable {
+                security: security.map(str::to_string),
+                start_after,
+                limit,
+            },
+        )
+        .unwrap();
+        from_json(&res).unwrap()
+    }
+
+    #[test]
+    fn test_cap_table_reports_positions_and_totals() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+
+        let value = query_cap_table(&deps, None, None, Some(30));
+        assert_eq!(
+            vec![
+                CapTableSecurity {
+                    name: "Security1".to_string(),
+                    total: Uint128::new(1000),
+                    sold: Uint128::new(400),
+                    remaining: Uint128::new(600),
+                },
+                CapTableSecurity {
+                    name: "Security2".to_string(),
+                    total: Uint128::new(1000),
+                    sold: Uint128::new(400),
+                    remaining: Uint128::new(600),
+                }
+            ],
+            value.securities
+        );
+        assert_eq!(14, value.entries.len());
+        assert_eq!(None, value.next_start_after);
+        assert_eq!(
+            CapTableEntry {
+                lp: Addr::unchecked("lp1"),
+                security: "Security1".to_string(),
+                committed: Uint128::new(100),
+                paid_in_capital: Uint128::new(100),
+                settled: Uint128::zero(),
+                investment_tokens: Uint128::zero(),
+                percentage: Decimal::percent(10),
+            },
+            value.entries[0]
+        );
+        assert_eq!(
+            CapTableEntry {
+                lp: Addr::unchecked("lp7"),
+                security: "Security2".to_string(),
+                committed: Uint128::new(100),
+                paid_in_capital: Uint128::new(100),
+                settled: Uint128::new(100),
+                investment_tokens: Uint128::new(100),
+                percentage: Decimal::percent(10),
+            },
+            value.entries[13]
+        );
+    }
+
+    #[test]
+    fn test_cap_table_filters_by_security_and_paginates() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+
+        let value = query_cap_table(&deps, Some("Security1"), None, Some(2));
+        assert_eq!(1, value.securities.len());
+        assert_eq!(
+            vec![
+                (Addr::unchecked("lp1"), "Security1".to_string()),
+                (Addr::unchecked("lp2"), "Security1".to_string())
+            ],
+            value
+                .entries
+                .into_iter()
+                .map(|entry| (entry.lp, entry.security))
+                .collect::<Vec<(Addr, String)>>()
+        );
+        assert_eq!(Some(Addr::unchecked("lp2")), value.next_start_after);
+
+        let value = query_cap_table(&deps, Some("Security1"), value.next_start_after, Some(10));
+        assert_eq!(5, value.entries.len());
+        assert_eq!(Addr::unchecked("lp3"), value.entries[0].lp);
+        assert_eq!(None, value.next_start_after);
+    }
+
+    #[test]
+    fn test_cap_table_includes_holders_and_skips_terminated_commitments() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        cancel_test(deps.as_mut(), mock_env(), "lp4", "lp4").unwrap();
+        distributions::add_units(
+            deps.as_mut().storage,
+            Addr::unchecked("holder"),
+            "Security1".to_string(),
+            Uint128::new(50),
+        )
+        .unwrap();
+
+        let value = query_cap_table(&deps, None, None, Some(30));
+        let lps: Vec<Addr> = value.entries.iter().map(|entry| entry.lp.clone()).collect();
+        assert!(!lps.contains(&Addr::unchecked("lp4")));
+        assert_eq!(
+            CapTableEntry {
+                lp: Addr::unchecked("holder"),
+                security: "Security1".to_string(),
+                committed: Uint128::zero(),
+                paid_in_capital: Uint128::zero(),
+                settled: Uint128::zero(),
+                investment_tokens: Uint128::new(50),
+                percentage: Decimal::percent(5),
+            },
+            value.entries[0]
+        );
+    }
+
+    #[test]
+    fn test_cap_table_fails_for_unknown_security() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::QueryCapTable {
+                security: Some("Unknown".to_string()),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap_err();
+    }
+}
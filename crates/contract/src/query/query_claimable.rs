@@ -0,0 +1,69 @@
+use cosmwasm_std::{to_json_binary, Addr, Coin, Storage};
+
+use crate::{
+    core::{aliases::ProvQueryResponse, msg::QueryClaimableResponse},
+    storage,
+};
+
+pub fn handle(storage: &dyn Storage, lp: Addr) -> ProvQueryResponse {
+    let state = storage::state::get(storage)?;
+    let claimable = storage::distributions::claimable(storage, lp)?;
+    let response = QueryClaimableResponse {
+        claimable: Coin::new(claimable, state.capital_denom),
+    };
+    Ok(to_json_binary(&response)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{from_json, testing::mock_env, Addr, Coin, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        contract::query,
+        core::msg::{QueryClaimableResponse, QueryMsg},
+        storage::distributions,
+        util::testing::{create_testing_commitments, instantiate_contract},
+    };
+
+    #[test]
+    fn test_query_claimable() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        distributions::distribute(
+            deps.as_mut().storage,
+            "Security1".to_string(),
+            Uint128::new(300),
+        )
+        .unwrap();
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::QueryClaimable {
+                lp: Addr::unchecked("lp7"),
+            },
+        )
+        .unwrap();
+        let value: QueryClaimableResponse = from_json(&res).unwrap();
+        assert_eq!(Coin::new(Uint128::new(300), "denom"), value.claimable);
+    }
+
+    #[test]
+    fn test_query_claimable_without_holdings() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::QueryClaimable {
+                lp: Addr::unchecked("lp1"),
+            },
+        )
+        .unwrap();
+        let value: QueryClaimableResponse = from_json(&res).unwrap();
+        assert_eq!(Coin::new(Uint128::zero(), "denom"), value.claimable);
+    }
+}
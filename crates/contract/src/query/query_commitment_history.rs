@@ -0,0 +1,118 @@
+use cosmwasm_std::{to_json_binary, Addr, Storage};
+
+use crate::{
+    core::{aliases::ProvQueryResponse, msg::QueryCommitmentHistoryResponse},
+    storage,
+};
+
+pub fn handle(
+    storage: &dyn Storage,
+    lp: Addr,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> ProvQueryResponse {
+    let (history, next_start_after) =
+        storage::commitment_history::get_page(storage, lp, start_after, limit)?;
+    let response = QueryCommitmentHistoryResponse {
+        history,
+        next_start_after,
+    };
+    Ok(to_json_binary(&response)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{from_json, testing::mock_env, Addr};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        contract::query,
+        core::msg::{QueryCommitmentHistoryResponse, QueryMsg},
+        execute::settlement::commitment::CommitmentState,
+        util::testing::{
+            cancel_test, create_testing_commitments, instantiate_contract,
+            test_security_commitments,
+        },
+    };
+
+    fn query_history(
+        deps: &crate::util::testing::MockDeps,
+        lp: &str,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> QueryCommitmentHistoryResponse {
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::QueryCommitmentHistory {
+                lp: Addr::unchecked(lp),
+                start_after,
+                limit,
+            },
+        )
+        .unwrap();
+        from_json(&res).unwrap()
+    }
+
+    #[test]
+    fn test_history_records_the_commitment_lifecycle() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+
+        let value = query_history(&deps, "lp7", None, None);
+        assert_eq!(None, value.next_start_after);
+        assert_eq!(
+            vec![
+                ("proposed", "lp7", CommitmentState::PENDING),
+                ("accepted", "gp", CommitmentState::ACCEPTED),
+                ("deposited", "lp7", CommitmentState::ACCEPTED),
+                ("settled", "gp", CommitmentState::SETTLED),
+            ],
+            value
+                .history
+                .iter()
+                .map(|entry| (
+                    entry.action.as_str(),
+                    entry.actor.as_str(),
+                    entry.state.clone()
+                ))
+                .collect::<Vec<(&str, &str, CommitmentState)>>()
+        );
+        for (sequence, entry) in value.history.iter().enumerate() {
+            assert_eq!(sequence as u64, entry.sequence);
+            assert_eq!(mock_env().block.height, entry.height.u64());
+            assert_eq!(mock_env().block.time.seconds(), entry.time.u64());
+            assert_eq!(test_security_commitments(), entry.amounts);
+        }
+    }
+
+    #[test]
+    fn test_history_is_kept_when_canceled_and_paginated() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        cancel_test(deps.as_mut(), mock_env(), "lp2", "lp2").unwrap();
+
+        let value = query_history(&deps, "lp2", None, Some(2));
+        assert_eq!(2, value.history.len());
+        assert_eq!(Some(1), value.next_start_after);
+
+        let value = query_history(&deps, "lp2", value.next_start_after, Some(2));
+        assert_eq!(None, value.next_start_after);
+        assert_eq!(2, value.history[0].sequence);
+        assert_eq!("deposited", value.history[0].action);
+        assert_eq!("canceled", value.history[1].action);
+        assert_eq!(CommitmentState::CANCELED, value.history[1].state);
+    }
+
+    #[test]
+    fn test_history_is_empty_without_commitment() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+
+        let value = query_history(&deps, "lp1", None, None);
+        assert!(value.history.is_empty());
+        assert_eq!(None, value.next_start_after);
+    }
+}
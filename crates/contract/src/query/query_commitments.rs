@@ -1,4 +1,4 @@
-use cosmwasm_std::{to_binary, Storage};
+use cosmwasm_std::{to_binary, Addr, Storage};
 
 use crate::{
     core::{aliases::ProvQueryResponse, msg::QueryCommitmentsResponse},
@@ -6,9 +6,18 @@ use crate::{
     storage,
 };
 
-pub fn handle(storage: &dyn Storage, commitment_state: CommitmentState) -> ProvQueryResponse {
-    let commitments = storage::commits::get_with_state(storage, commitment_state);
-    let response = QueryCommitmentsResponse { commitments };
+pub fn handle(
+    storage: &dyn Storage,
+    commitment_state: CommitmentState,
+    start_after: Option<Addr>,
+    limit: Option<u32>,
+) -> ProvQueryResponse {
+    let (commitments, next_start_after) =
+        storage::commits::get_page_with_state(storage, commitment_state, start_after, limit)?;
+    let response = QueryCommitmentsResponse {
+        commitments,
+        next_start_after,
+    };
     Ok(to_binary(&response)?)
 }
 
@@ -19,10 +28,11 @@ mod tests {
         core::msg::{QueryCommitmentsResponse, QueryMsg},
         execute::settlement::commitment::CommitmentState,
         util::testing::{
-            create_testing_commitments, instantiate_contract, test_security_commitments,
+            cancel_test, create_testing_commitments, instantiate_contract, reject_test,
+            test_security_commitments,
         },
     };
-    use cosmwasm_std::{from_binary, testing::mock_env, Addr};
+    use cosmwasm_std::{from_binary, from_json, testing::mock_env, Addr};
     use provwasm_mocks::mock_provenance_dependencies;
 
     #[test]
@@ -35,6 +45,8 @@ mod tests {
             mock_env(),
             QueryMsg::QueryCommitments {
                 commitment_state: CommitmentState::PENDING,
+                start_after: None,
+                limit: None,
             },
         )
         .unwrap();
@@ -62,6 +74,8 @@ mod tests {
             mock_env(),
             QueryMsg::QueryCommitments {
                 commitment_state: CommitmentState::ACCEPTED,
+                start_after: None,
+                limit: None,
             },
         )
         .unwrap();
@@ -89,6 +103,8 @@ mod tests {
             mock_env(),
             QueryMsg::QueryCommitments {
                 commitment_state: CommitmentState::SETTLED,
+                start_after: None,
+                limit: None,
             },
         )
         .unwrap();
@@ -105,4 +121,91 @@ mod tests {
             assert_eq!(CommitmentState::SETTLED, value.commitments[i].state);
         }
     }
+
+    #[test]
+    fn test_canceled_commitments() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("Contract should instantiate.");
+        create_testing_commitments(&mut deps);
+        cancel_test(deps.as_mut(), mock_env(), "lp4", "lp4").unwrap();
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::QueryCommitments {
+                commitment_state: CommitmentState::CANCELED,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+        let value: QueryCommitmentsResponse = from_json(&res).unwrap();
+        assert_eq!(1, value.commitments.len());
+        assert_eq!(Addr::unchecked("lp4"), value.commitments[0].lp);
+        assert_eq!(CommitmentState::CANCELED, value.commitments[0].state);
+    }
+
+    #[test]
+    fn test_rejected_commitments() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("Contract should instantiate.");
+        create_testing_commitments(&mut deps);
+        reject_test(deps.as_mut(), mock_env(), "gp", "lp5").unwrap();
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::QueryCommitments {
+                commitment_state: CommitmentState::REJECTED,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+        let value: QueryCommitmentsResponse = from_json(&res).unwrap();
+        assert_eq!(1, value.commitments.len());
+        assert_eq!(Addr::unchecked("lp5"), value.commitments[0].lp);
+        assert_eq!(CommitmentState::REJECTED, value.commitments[0].state);
+        assert_eq!(Some("rejected".to_string()), value.commitments[0].reason);
+    }
+
+    #[test]
+    fn test_commitments_are_paginated() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("Contract should instantiate.");
+        create_testing_commitments(&mut deps);
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::QueryCommitments {
+                commitment_state: CommitmentState::PENDING,
+                start_after: None,
+                limit: Some(2),
+            },
+        )
+        .unwrap();
+        let value: QueryCommitmentsResponse = from_json(&res).unwrap();
+        assert_eq!(
+            vec![Addr::unchecked("lp4"), Addr::unchecked("lp5")],
+            value
+                .commitments
+                .into_iter()
+                .map(|commitment| commitment.lp)
+                .collect::<Vec<Addr>>()
+        );
+        assert_eq!(Some(Addr::unchecked("lp5")), value.next_start_after);
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::QueryCommitments {
+                commitment_state: CommitmentState::PENDING,
+                start_after: value.next_start_after,
+                limit: Some(2),
+            },
+        )
+        .unwrap();
+        let value: QueryCommitmentsResponse = from_json(&res).unwrap();
+        assert_eq!(1, value.commitments.len());
+        assert_eq!(Addr::unchecked("lp6"), value.commitments[0].lp);
+        assert_eq!(None, value.next_start_after);
+    }
 }
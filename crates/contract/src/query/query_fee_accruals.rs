@@ -0,0 +1,87 @@
+use cosmwasm_std::{to_json_binary, Addr, Coin, Storage, Timestamp};
+
+use crate::{
+    core::{aliases::ProvQueryResponse, msg::QueryFeeAccrualsResponse},
+    storage::{self, fees},
+    util,
+};
+
+// The lp's fees accrued up to the current block, including what is owed since the last accrual
+pub fn handle(storage: &dyn Storage, time: Timestamp, lp: Addr) -> ProvQueryResponse {
+    let state = storage::state::get(storage)?;
+    let accrual = match fees::get_schedule(storage) {
+        None => fees::get_accrual(storage, lp),
+        Some(schedule) => util::fees::projected_accrual(storage, &schedule, lp, time)?,
+    };
+    let response = QueryFeeAccrualsResponse {
+        outstanding: Coin::new(accrual.outstanding(), state.capital_denom),
+        accrual,
+    };
+    Ok(to_json_binary(&response)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{from_json, testing::mock_env, Addr, Coin, Decimal, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        contract::query,
+        core::{
+            fee::{FeeBasis, FeeSchedule},
+            msg::{QueryFeeAccrualsResponse, QueryMsg},
+        },
+        storage::fees::{self, FeeAccrual},
+        util::testing::{create_testing_commitments, instantiate_contract},
+    };
+
+    #[test]
+    fn test_query_fee_accruals_without_schedule() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::QueryFeeAccruals {
+                lp: Addr::unchecked("lp1"),
+            },
+        )
+        .unwrap();
+        let value: QueryFeeAccrualsResponse = from_json(&res).unwrap();
+        assert_eq!(FeeAccrual::default(), value.accrual);
+        assert_eq!(Coin::new(Uint128::zero(), "denom"), value.outstanding);
+    }
+
+    #[test]
+    fn test_query_fee_accruals_projects_to_block_time() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        fees::set_schedule(
+            deps.as_mut().storage,
+            &FeeSchedule {
+                recipient: Addr::unchecked("manager"),
+                management_fee_rate: Decimal::percent(2),
+                management_fee_basis: FeeBasis::Committed,
+                carry_rate: Decimal::percent(20),
+                hurdle: Decimal::percent(8),
+            },
+        )
+        .unwrap();
+        create_testing_commitments(&mut deps);
+
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_days(365);
+        let res = query(
+            deps.as_ref(),
+            env,
+            QueryMsg::QueryFeeAccruals {
+                lp: Addr::unchecked("lp1"),
+            },
+        )
+        .unwrap();
+        let value: QueryFeeAccrualsResponse = from_json(&res).unwrap();
+        assert_eq!(Uint128::new(400), value.accrual.management_fee);
+        assert_eq!(Coin::new(Uint128::new(400), "denom"), value.outstanding);
+    }
+}
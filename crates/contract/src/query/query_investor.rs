@@ -7,10 +7,14 @@ use crate::{
 
 pub fn handle(storage: &dyn Storage, lp: Addr) -> ProvQueryResponse {
     let commitment = storage::commits::get(storage, lp.clone())?;
-    let paid_in_capital = storage::paid_in_capital::get(storage, lp);
+    let paid_in_capital = storage::paid_in_capital::get(storage, lp.clone());
+    let capital_call = storage::capital_calls::get(storage, lp.clone());
+    let counter_proposal = storage::counter_proposals::get(storage, lp);
     let response = QueryInvestorResponse {
         commitment,
         paid_in_capital,
+        capital_call,
+        counter_proposal,
     };
     Ok(to_binary(&response)?)
 }
@@ -47,5 +51,7 @@ mod tests {
         assert_eq!(test_security_commitments(), value.commitment.commitments);
         // They are not settled until it is withdrawn
         assert_eq!(CommitmentState::ACCEPTED, value.commitment.state);
+        assert_eq!(None, value.capital_call);
+        assert_eq!(None, value.counter_proposal);
     }
 }
@@ -1,15 +1,17 @@
 use crate::core::aliases::ProvQueryResponse;
 use crate::core::msg::QueryLoanPoolCollateralResponse;
-use crate::storage::loan_pool_collateral::get_all_states;
-use cosmwasm_std::{to_binary, Storage};
+use crate::storage::loan_pool_collateral::get_page;
+use cosmwasm_std::{to_binary, Addr, Storage};
 
-/// This function handles the process of getting all states from storage and
+/// This function handles the process of getting a page of states from storage and
 /// creates a `QueryLoanPoolCollateralResponse` with the resulting collaterals.
 /// The response is then serialized into binary form.
 ///
 /// # Arguments
 ///
 /// * `storage` - A dynamic reference to the storage from which to get all states
+/// * `start_after` - The marker address the page starts after, or `None` for the first page
+/// * `limit` - The maximum number of collaterals to return
 ///
 /// # Returns
 ///
@@ -24,9 +26,15 @@ use cosmwasm_std::{to_binary, Storage};
 /// # Example
 ///
 
-pub fn handle(storage: &dyn Storage) -> ProvQueryResponse {
+pub fn handle(
+    storage: &dyn Storage,
+    start_after: Option<Addr>,
+    limit: Option<u32>,
+) -> ProvQueryResponse {
+    let (collaterals, next_start_after) = get_page(storage, start_after, limit)?;
     Ok(to_binary(&QueryLoanPoolCollateralResponse {
-        collaterals: get_all_states(storage),
+        collaterals,
+        next_start_after,
     })?)
 }
 
@@ -127,7 +135,15 @@ mod tests {
         assert!(loan_pool_result.is_ok());
 
         //query all states
-        let res = query(deps.as_ref(), mock_env(), QueryMsg::QueryCollaterals {}).unwrap();
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::QueryCollaterals {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
         let value: QueryLoanPoolCollateralResponse = from_binary(&res).unwrap();
         assert_eq!(1, value.collaterals.len());
     }
@@ -186,7 +202,15 @@ mod tests {
         );
 
         //query all states
-        let res = query(deps.as_ref(), mock_env(), QueryMsg::QueryCollaterals {}).unwrap();
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::QueryCollaterals {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
         let value: QueryLoanPoolCollateralResponse = from_binary(&res).unwrap();
         assert_eq!(0, value.collaterals.len());
     }
@@ -0,0 +1,98 @@
+use cosmwasm_std::{to_json_binary, Storage};
+
+use crate::{
+    core::{aliases::ProvQueryResponse, msg::QueryNavHistoryResponse},
+    storage,
+};
+
+pub fn handle(
+    storage: &dyn Storage,
+    security: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> ProvQueryResponse {
+    storage::securities::get(storage, security.clone())?;
+    let (history, next_start_after) =
+        storage::nav::get_page(storage, security, start_after, limit)?;
+    let response = QueryNavHistoryResponse {
+        history,
+        next_start_after,
+    };
+    Ok(to_json_binary(&response)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{from_json, testing::mock_env, Coin, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        contract::query,
+        core::msg::{QueryMsg, QueryNavHistoryResponse},
+        storage::nav::{self},
+        util::testing::instantiate_contract,
+    };
+
+    #[test]
+    fn test_query_nav_history() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        let mut entries = vec![];
+        for amount in [110u128, 120, 130] {
+            entries.push(
+                nav::add(
+                    deps.as_mut().storage,
+                    &mock_env(),
+                    "Security1".to_string(),
+                    Coin::new(Uint128::new(amount), "denom"),
+                )
+                .unwrap(),
+            );
+        }
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::QueryNavHistory {
+                security: "Security1".to_string(),
+                start_after: None,
+                limit: Some(2),
+            },
+        )
+        .unwrap();
+        let value: QueryNavHistoryResponse = from_json(&res).unwrap();
+        assert_eq!(entries[..2].to_vec(), value.history);
+        assert_eq!(Some(1), value.next_start_after);
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::QueryNavHistory {
+                security: "Security1".to_string(),
+                start_after: value.next_start_after,
+                limit: Some(2),
+            },
+        )
+        .unwrap();
+        let value: QueryNavHistoryResponse = from_json(&res).unwrap();
+        assert_eq!(entries[2..].to_vec(), value.history);
+        assert_eq!(None, value.next_start_after);
+    }
+
+    #[test]
+    fn test_query_nav_history_invalid_security() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::QueryNavHistory {
+                security: "Security3".to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap_err();
+    }
+}
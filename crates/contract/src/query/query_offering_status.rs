@@ -0,0 +1,152 @@
+use cosmwasm_std::{to_json_binary, Coin, Storage, Uint128};
+
+use crate::{
+    core::{
+        aliases::ProvQueryResponse,
+        error::ContractError,
+        msg::{OfferingSecurityStatus, OfferingTotals, QueryOfferingStatusResponse},
+    },
+    storage::{available_capital, nav, offering_totals, remaining_securities, securities, state},
+};
+
+pub fn handle(storage: &dyn Storage) -> ProvQueryResponse {
+    let state = state::get(storage)?;
+
+    let zero = Coin::new(Uint128::zero(), state.capital_denom.clone());
+    let mut totals = OfferingTotals {
+        total: zero.clone(),
+        pending: zero.clone(),
+        accepted: zero.clone(),
+        paid_in: zero.clone(),
+        settled: zero.clone(),
+        remaining: zero,
+        available_capital: Coin::new(
+            available_capital::get_total(storage, &state.capital_denom)?,
+            state.capital_denom.clone(),
+        ),
+    };
+
+    let mut statuses = vec![];
+    for name in securities::get_security_types(storage) {
+        let security = securities::get(storage, name.clone())?;
+        let units = offering_totals::get(storage, name.clone())?;
+        let status = OfferingSecurityStatus {
+            total: security.amount,
+            pending: units.pending,
+            accepted: units.accepted,
+            paid_in: units.paid_in,
+            settled: units.settled,
+            remaining: Uint128::new(remaining_securities::get(storage, name.clone())?),
+            name,
+        };
+
+        let price = nav::current_price(storage, &security)?;
+        if price.denom != state.capital_denom {
+            return Err(ContractError::InvalidSecurityPriceDenom {});
+        }
+        let price = price.amount;
+        totals.total.amount += status.total * price;
+        totals.pending.amount += status.pending * price;
+        totals.accepted.amount += status.accepted * price;
+        totals.paid_in.amount += status.paid_in * price;
+        totals.settled.amount += status.settled * price;
+        totals.remaining.amount += status.remaining * price;
+        statuses.push(status);
+    }
+
+    Ok(to_json_binary(&QueryOfferingStatusResponse {
+        securities: statuses,
+        totals,
+    })?)
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{from_json, testing::mock_env, Coin, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        contract::query,
+        core::{
+            error::ContractError,
+            msg::{OfferingSecurityStatus, QueryMsg, QueryOfferingStatusResponse},
+        },
+        storage::securities,
+        util::testing::{create_testing_commitments, instantiate_contract},
+    };
+
+    #[test]
+    fn test_query_offering_status_without_commitments() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::QueryOfferingStatus {}).unwrap();
+        let value: QueryOfferingStatusResponse = from_json(&res).unwrap();
+        assert_eq!(
+            OfferingSecurityStatus {
+                name: "Security1".to_string(),
+                total: Uint128::new(1000),
+                pending: Uint128::zero(),
+                accepted: Uint128::zero(),
+                paid_in: Uint128::zero(),
+                settled: Uint128::zero(),
+                remaining: Uint128::new(1000),
+            },
+            value.securities[0]
+        );
+        assert_eq!(2, value.securities.len());
+        assert_eq!(Coin::new(200000u128, "denom"), value.totals.total);
+        assert_eq!(Coin::new(200000u128, "denom"), value.totals.remaining);
+        assert_eq!(Coin::new(0u128, "denom"), value.totals.pending);
+        assert_eq!(Coin::new(0u128, "denom"), value.totals.available_capital);
+    }
+
+    #[test]
+    fn test_query_offering_status() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::QueryOfferingStatus {}).unwrap();
+        let value: QueryOfferingStatusResponse = from_json(&res).unwrap();
+        for (security, name) in value.securities.iter().zip(["Security1", "Security2"]) {
+            assert_eq!(
+                &OfferingSecurityStatus {
+                    name: name.to_string(),
+                    total: Uint128::new(1000),
+                    pending: Uint128::new(300),
+                    accepted: Uint128::new(300),
+                    paid_in: Uint128::new(250),
+                    settled: Uint128::new(100),
+                    remaining: Uint128::new(600),
+                },
+                security
+            );
+        }
+        assert_eq!(Coin::new(200000u128, "denom"), value.totals.total);
+        assert_eq!(Coin::new(60000u128, "denom"), value.totals.pending);
+        assert_eq!(Coin::new(60000u128, "denom"), value.totals.accepted);
+        assert_eq!(Coin::new(50000u128, "denom"), value.totals.paid_in);
+        assert_eq!(Coin::new(20000u128, "denom"), value.totals.settled);
+        assert_eq!(Coin::new(120000u128, "denom"), value.totals.remaining);
+        assert_eq!(
+            Coin::new(30000u128, "denom"),
+            value.totals.available_capital
+        );
+    }
+
+    #[test]
+    fn test_query_offering_status_fails_for_price_in_other_denom() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        let mut security = securities::get(&deps.storage, "Security1".to_string()).unwrap();
+        security.price_per_unit = Coin::new(100u128, "otherdenom");
+        securities::set(deps.as_mut().storage, &security).unwrap();
+
+        let error = query(deps.as_ref(), mock_env(), QueryMsg::QueryOfferingStatus {}).unwrap_err();
+        assert_eq!(
+            ContractError::InvalidSecurityPriceDenom {}.to_string(),
+            error.to_string()
+        );
+    }
+}
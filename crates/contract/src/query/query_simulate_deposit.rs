@@ -0,0 +1,166 @@
+use cosmwasm_std::{to_json_binary, Addr, Coin, Env};
+
+use crate::{
+    core::{
+        aliases::{ProvDeps, ProvQueryResponse},
+        error::ContractError,
+        msg::{ExecuteMsg, QuerySimulateDepositResponse},
+        security::SecurityCommitment,
+    },
+    execute::{router::check_allowed, settlement::deposit_commitment},
+    storage::state,
+    util::validate::Validate,
+};
+
+pub fn handle(
+    deps: ProvDeps,
+    env: Env,
+    lp: Addr,
+    securities: Vec<SecurityCommitment>,
+) -> ProvQueryResponse {
+    let response = match simulate(&deps, &env, &lp, securities) {
+        Ok(funds) => QuerySimulateDepositResponse { funds, error: None },
+        Err(error) => QuerySimulateDepositResponse {
+            funds: vec![],
+            error: Some(error.to_string()),
+        },
+    };
+    Ok(to_json_binary(&response)?)
+}
+
+// Runs the same checks as a DepositCommitment sent by the lp, in the same order
+fn simulate(
+    deps: &ProvDeps,
+    env: &Env,
+    lp: &Addr,
+    securities: Vec<SecurityCommitment>,
+) -> Result<Vec<Coin>, ContractError> {
+    let msg = ExecuteMsg::DepositCommitment {
+        securities: securities.clone(),
+    };
+    msg.validate()?;
+    check_allowed(&state::get(deps.storage)?, &msg)?;
+    deposit_commitment::validate(deps, env, lp, &securities)
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{from_json, testing::mock_env, Addr, Attribute, Coin, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        contract::query,
+        core::{
+            error::ContractError,
+            msg::{QueryMsg, QuerySimulateDepositResponse},
+            security::SecurityCommitment,
+        },
+        util::testing::{
+            accept_test_commitment, deposit_test, instantiate_contract, pause_test,
+            propose_test_commitment, test_security_commitments, MockDeps,
+        },
+    };
+
+    fn simulate(
+        deps: &MockDeps,
+        securities: Vec<SecurityCommitment>,
+    ) -> QuerySimulateDepositResponse {
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::QuerySimulateDeposit {
+                lp: Addr::unchecked("lp1"),
+                securities,
+            },
+        )
+        .unwrap();
+        from_json(&res).unwrap()
+    }
+
+    #[test]
+    fn test_simulate_deposit_returns_funds() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).unwrap();
+        propose_test_commitment(deps.as_mut(), mock_env(), "lp1").unwrap();
+        accept_test_commitment(deps.as_mut(), mock_env(), "gp", &["lp1"]).unwrap();
+
+        let value = simulate(&deps, test_security_commitments());
+        assert_eq!(
+            QuerySimulateDepositResponse {
+                funds: vec![Coin::new(20000u128, "denom")],
+                error: None,
+            },
+            value
+        );
+
+        let res = deposit_test(
+            deps.as_mut(),
+            mock_env(),
+            "lp1",
+            &test_security_commitments(),
+        )
+        .unwrap();
+        assert_eq!(Attribute::new("funds_amount", "20000"), res.attributes[3]);
+    }
+
+    #[test]
+    fn test_simulate_deposit_returns_error_for_pending_commitment() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).unwrap();
+        propose_test_commitment(deps.as_mut(), mock_env(), "lp1").unwrap();
+
+        let value = simulate(&deps, test_security_commitments());
+        assert!(value.funds.is_empty());
+        assert_eq!(
+            Some(ContractError::InvalidCommitmentState {}.to_string()),
+            value.error
+        );
+    }
+
+    #[test]
+    fn test_simulate_deposit_returns_error_for_excessive_deposit() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).unwrap();
+        propose_test_commitment(deps.as_mut(), mock_env(), "lp1").unwrap();
+        accept_test_commitment(deps.as_mut(), mock_env(), "gp", &["lp1"]).unwrap();
+
+        let value = simulate(
+            &deps,
+            vec![SecurityCommitment {
+                name: "Security1".to_string(),
+                amount: Uint128::new(101),
+            }],
+        );
+        assert_eq!(
+            Some(ContractError::ExcessiveDeposit {}.to_string()),
+            value.error
+        );
+    }
+
+    #[test]
+    fn test_simulate_deposit_returns_error_for_invalid_message() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).unwrap();
+
+        let value = simulate(&deps, vec![]);
+        assert_eq!(
+            Some(ContractError::EmptySecurityCommitmentList {}.to_string()),
+            value.error
+        );
+    }
+
+    #[test]
+    fn test_simulate_deposit_returns_error_while_paused() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).unwrap();
+        propose_test_commitment(deps.as_mut(), mock_env(), "lp1").unwrap();
+        accept_test_commitment(deps.as_mut(), mock_env(), "gp", &["lp1"]).unwrap();
+        pause_test(deps.as_mut(), mock_env(), "gp").unwrap();
+
+        let value = simulate(&deps, test_security_commitments());
+        assert_eq!(
+            Some(ContractError::ContractPaused {}.to_string()),
+            value.error
+        );
+    }
+}
@@ -0,0 +1,198 @@
+use cosmwasm_std::{to_json_binary, Addr, Env};
+
+use crate::{
+    core::{
+        aliases::{ProvDeps, ProvQueryResponse},
+        error::ContractError,
+        msg::{ExecuteMsg, QuerySimulateProposeResponse},
+        security::SecurityCommitment,
+    },
+    execute::{
+        router::check_allowed,
+        settlement::{commitment::Commitment, propose_commitment},
+    },
+    storage::state,
+    util::validate::Validate,
+};
+
+pub fn handle(
+    deps: ProvDeps,
+    env: Env,
+    lp: Addr,
+    securities: Vec<SecurityCommitment>,
+) -> ProvQueryResponse {
+    let response = match simulate(&deps, &env, &lp, securities) {
+        Ok(commitment) => QuerySimulateProposeResponse {
+            commitment: Some(commitment),
+            error: None,
+        },
+        Err(error) => QuerySimulateProposeResponse {
+            commitment: None,
+            error: Some(error.to_string()),
+        },
+    };
+    Ok(to_json_binary(&response)?)
+}
+
+// Runs the same checks as a ProposeCommitment sent by the lp, in the same order
+fn simulate(
+    deps: &ProvDeps,
+    env: &Env,
+    lp: &Addr,
+    securities: Vec<SecurityCommitment>,
+) -> Result<Commitment, ContractError> {
+    let msg = ExecuteMsg::ProposeCommitment {
+        securities: securities.clone(),
+    };
+    msg.validate()?;
+    check_allowed(&state::get(deps.storage)?, &msg)?;
+    propose_commitment::validate(deps, env, lp, &securities)
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{
+        from_json,
+        testing::{message_info, mock_env},
+        Addr, Uint128,
+    };
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        contract::{execute, query},
+        core::{
+            error::ContractError,
+            msg::{ExecuteMsg, QueryMsg, QuerySimulateProposeResponse},
+            security::SecurityCommitment,
+        },
+        execute::settlement::commitment::Commitment,
+        storage::commits,
+        util::testing::{
+            accept_test_commitment, close_offering_test, instantiate_contract,
+            propose_test_commitment, test_security_commitments, MockDeps,
+        },
+    };
+
+    fn simulate(
+        deps: &MockDeps,
+        securities: Vec<SecurityCommitment>,
+    ) -> QuerySimulateProposeResponse {
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::QuerySimulatePropose {
+                lp: Addr::unchecked("lp1"),
+                securities,
+            },
+        )
+        .unwrap();
+        from_json(&res).unwrap()
+    }
+
+    #[test]
+    fn test_simulate_propose_returns_commitment() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).unwrap();
+
+        let value = simulate(&deps, test_security_commitments());
+        assert_eq!(
+            QuerySimulateProposeResponse {
+                commitment: Some(Commitment::new(
+                    Addr::unchecked("lp1"),
+                    test_security_commitments()
+                )),
+                error: None,
+            },
+            value
+        );
+        assert!(!commits::exists(&deps.storage, Addr::unchecked("lp1")));
+
+        propose_test_commitment(deps.as_mut(), mock_env(), "lp1").unwrap();
+        assert_eq!(
+            value.commitment.unwrap(),
+            commits::get(&deps.storage, Addr::unchecked("lp1")).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_simulate_propose_adds_to_existing_commitment() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).unwrap();
+        let securities = test_security_commitments();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("lp1"), &[]),
+            ExecuteMsg::ProposeCommitment {
+                securities: vec![securities[0].clone()],
+            },
+        )
+        .unwrap();
+
+        let value = simulate(&deps, vec![securities[1].clone()]);
+        assert_eq!(None, value.error);
+        assert_eq!(
+            vec![securities[1].clone(), securities[0].clone()],
+            value.commitment.unwrap().commitments
+        );
+    }
+
+    #[test]
+    fn test_simulate_propose_returns_error_for_proposed_securities() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).unwrap();
+        propose_test_commitment(deps.as_mut(), mock_env(), "lp1").unwrap();
+
+        let value = simulate(&deps, test_security_commitments());
+        assert_eq!(
+            Some(ContractError::InvalidSecurityCommitment {}.to_string()),
+            value.error
+        );
+        assert_eq!(None, value.commitment);
+    }
+
+    #[test]
+    fn test_simulate_propose_returns_error_for_accepted_commitment() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).unwrap();
+        propose_test_commitment(deps.as_mut(), mock_env(), "lp1").unwrap();
+        accept_test_commitment(deps.as_mut(), mock_env(), "gp", &["lp1"]).unwrap();
+
+        let value = simulate(&deps, test_security_commitments());
+        assert_eq!(
+            Some(ContractError::AlreadyAccepted {}.to_string()),
+            value.error
+        );
+    }
+
+    #[test]
+    fn test_simulate_propose_returns_error_below_minimum() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).unwrap();
+
+        let value = simulate(
+            &deps,
+            vec![SecurityCommitment {
+                name: "Security1".to_string(),
+                amount: Uint128::new(1),
+            }],
+        );
+        assert_eq!(
+            Some(ContractError::InvalidSecurityCommitmentAmount {}.to_string()),
+            value.error
+        );
+    }
+
+    #[test]
+    fn test_simulate_propose_returns_error_once_offering_is_closed() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).unwrap();
+        close_offering_test(deps.as_mut(), mock_env(), "gp").unwrap();
+
+        let value = simulate(&deps, test_security_commitments());
+        assert_eq!(
+            Some(ContractError::InvalidOfferingStatus {}.to_string()),
+            value.error
+        );
+    }
+}
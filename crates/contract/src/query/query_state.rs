@@ -13,6 +13,8 @@ pub fn handle(storage: &dyn Storage) -> ProvQueryResponse {
         securities,
         capital_denom: state.capital_denom,
         settlement_time: state.settlement_time,
+        paused: state.paused,
+        deposit_mode: state.deposit_mode,
     };
     Ok(to_binary(&response)?)
 }
@@ -46,5 +48,6 @@ mod tests {
         assert_eq!(expected.capital_denom, value.capital_denom);
         assert_eq!(expected.settlement_time, value.settlement_time);
         assert_eq!(securities, value.securities);
+        assert!(!value.paused);
     }
 }
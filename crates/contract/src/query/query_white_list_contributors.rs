@@ -1,7 +1,7 @@
 use crate::core::aliases::ProvQueryResponse;
 use crate::core::msg::QueryLoanPoolContributorsResponse;
-use crate::storage::whitelist_contributors_store::get_whitelist_contributors;
-use cosmwasm_std::{to_binary, Storage};
+use crate::storage::whitelist_contributors_store::get_whitelist_contributors_page;
+use cosmwasm_std::{to_binary, Addr, Storage};
 
 /// Handles the querying of whitelist contributors from your storage,
 /// and returns a ProvQueryResponse with the result.
@@ -10,6 +10,8 @@ use cosmwasm_std::{to_binary, Storage};
 ///
 /// * `storage` - A dynamic reference to an object that implements the Storage trait.
 ///    It should be capable of storing and retrieving data objects.
+/// * `start_after` - The contributor the page starts after, or `None` for the first page.
+/// * `limit` - The maximum number of contributors to return.
 ///
 /// # Returns
 ///
@@ -31,9 +33,16 @@ use cosmwasm_std::{to_binary, Storage};
 /// # Safety
 ///
 /// This function assumes the storage passed as parameter correctly and safely implements the `Storage` trait.
-pub fn handle(storage: &dyn Storage) -> ProvQueryResponse {
+pub fn handle(
+    storage: &dyn Storage,
+    start_after: Option<Addr>,
+    limit: Option<u32>,
+) -> ProvQueryResponse {
+    let (contributors, next_start_after) =
+        get_whitelist_contributors_page(storage, start_after, limit)?;
     Ok(to_binary(&QueryLoanPoolContributorsResponse {
-        contributors: get_whitelist_contributors(storage),
+        contributors,
+        next_start_after,
     })?)
 }
 
@@ -62,7 +71,10 @@ mod tests {
         let res = query(
             deps.as_ref(),
             mock_env(),
-            QueryMsg::QueryLoanPoolContributors {},
+            QueryMsg::QueryLoanPoolContributors {
+                start_after: None,
+                limit: None,
+            },
         )
         .unwrap();
         let value: QueryLoanPoolContributorsResponse = from_binary(&res).unwrap();
@@ -78,7 +90,10 @@ mod tests {
         let res = query(
             deps.as_ref(),
             mock_env(),
-            QueryMsg::QueryLoanPoolContributors {},
+            QueryMsg::QueryLoanPoolContributors {
+                start_after: None,
+                limit: None,
+            },
         )
         .unwrap();
         let value: QueryLoanPoolContributorsResponse = from_binary(&res).unwrap();
@@ -5,24 +5,56 @@ use crate::core::{
     msg::QueryMsg,
 };
 use crate::query::{
-    query_commitments, query_investor, query_loan_pool_collaterals, query_securitizations,
+    query_cap_table, query_claimable, query_commitment_history, query_commitments,
+    query_fee_accruals, query_investor, query_loan_pool_collaterals, query_nav_history,
+    query_offering_status, query_securitizations, query_simulate_deposit, query_simulate_propose,
     query_state, query_version, query_white_list_contributors,
 };
 
-pub fn route(deps: ProvDeps, _env: Env, msg: QueryMsg) -> ProvQueryResponse {
+pub fn route(deps: ProvDeps, env: Env, msg: QueryMsg) -> ProvQueryResponse {
     match msg {
         QueryMsg::QueryInvestor { investor } => query_investor::handle(deps.storage, investor),
-        QueryMsg::QueryCommitments { commitment_state } => {
-            query_commitments::handle(deps.storage, commitment_state)
-        }
+        QueryMsg::QueryCommitments {
+            commitment_state,
+            start_after,
+            limit,
+        } => query_commitments::handle(deps.storage, commitment_state, start_after, limit),
         QueryMsg::QuerySecuritizations { securities } => {
             query_securitizations::handle(deps.storage, securities)
         }
         QueryMsg::QueryState {} => query_state::handle(deps.storage),
         QueryMsg::QueryVersion {} => query_version::handle(deps.storage),
-        QueryMsg::QueryCollaterals {} => query_loan_pool_collaterals::handle(deps.storage),
-        QueryMsg::QueryLoanPoolContributors {} => {
-            query_white_list_contributors::handle(deps.storage)
+        QueryMsg::QueryCollaterals { start_after, limit } => {
+            query_loan_pool_collaterals::handle(deps.storage, start_after, limit)
+        }
+        QueryMsg::QueryLoanPoolContributors { start_after, limit } => {
+            query_white_list_contributors::handle(deps.storage, start_after, limit)
+        }
+        QueryMsg::QueryClaimable { lp } => query_claimable::handle(deps.storage, lp),
+        QueryMsg::QueryNavHistory {
+            security,
+            start_after,
+            limit,
+        } => query_nav_history::handle(deps.storage, security, start_after, limit),
+        QueryMsg::QueryFeeAccruals { lp } => {
+            query_fee_accruals::handle(deps.storage, env.block.time, lp)
+        }
+        QueryMsg::QueryCommitmentHistory {
+            lp,
+            start_after,
+            limit,
+        } => query_commitment_history::handle(deps.storage, lp, start_after, limit),
+        QueryMsg::QueryCapTable {
+            security,
+            start_after,
+            limit,
+        } => query_cap_table::handle(deps.storage, security, start_after, limit),
+        QueryMsg::QueryOfferingStatus {} => query_offering_status::handle(deps.storage),
+        QueryMsg::QuerySimulateDeposit { lp, securities } => {
+            query_simulate_deposit::handle(deps, env, lp, securities)
+        }
+        QueryMsg::QuerySimulatePropose { lp, securities } => {
+            query_simulate_propose::handle(deps, env, lp, securities)
         }
     }
 }
@@ -34,8 +66,9 @@ mod tests {
 
     use crate::{
         core::msg::{
-            QueryCommitmentsResponse, QueryInvestorResponse, QuerySecuritizationsResponse,
-            QueryStateResponse, QueryVersionResponse,
+            QueryClaimableResponse, QueryCommitmentsResponse, QueryFeeAccrualsResponse,
+            QueryInvestorResponse, QueryNavHistoryResponse, QueryOfferingStatusResponse,
+            QuerySecuritizationsResponse, QueryStateResponse, QueryVersionResponse,
         },
         util,
     };
@@ -59,6 +92,8 @@ mod tests {
         let mut deps = mock_provenance_dependencies();
         let msg = crate::core::msg::QueryMsg::QueryCommitments {
             commitment_state: crate::execute::settlement::commitment::CommitmentState::PENDING,
+            start_after: None,
+            limit: None,
         };
         util::testing::instantiate_contract(deps.as_mut()).unwrap();
         util::testing::propose_test_commitment(deps.as_mut(), mock_env(), "lp1").unwrap();
@@ -96,4 +131,49 @@ mod tests {
         let bin = route(deps.as_ref(), mock_env(), msg).unwrap();
         let _: QuerySecuritizationsResponse = from_binary(&bin).unwrap();
     }
+
+    #[test]
+    fn tests_query_claimable_has_correct_response() {
+        let mut deps = mock_provenance_dependencies();
+        let msg = crate::core::msg::QueryMsg::QueryClaimable {
+            lp: Addr::unchecked("lp1"),
+        };
+        util::testing::instantiate_contract(deps.as_mut()).unwrap();
+        let bin = route(deps.as_ref(), mock_env(), msg).unwrap();
+        let _: QueryClaimableResponse = from_json(&bin).unwrap();
+    }
+
+    #[test]
+    fn tests_query_nav_history_has_correct_response() {
+        let mut deps = mock_provenance_dependencies();
+        let msg = crate::core::msg::QueryMsg::QueryNavHistory {
+            security: "Security1".to_string(),
+            start_after: None,
+            limit: None,
+        };
+        util::testing::instantiate_contract(deps.as_mut()).unwrap();
+        let bin = route(deps.as_ref(), mock_env(), msg).unwrap();
+        let _: QueryNavHistoryResponse = from_json(&bin).unwrap();
+    }
+
+    #[test]
+    fn tests_query_fee_accruals_has_correct_response() {
+        let mut deps = mock_provenance_dependencies();
+        let msg = crate::core::msg::QueryMsg::QueryFeeAccruals {
+            lp: Addr::unchecked("lp1"),
+        };
+        util::testing::instantiate_contract(deps.as_mut()).unwrap();
+        let bin = route(deps.as_ref(), mock_env(), msg).unwrap();
+        let _: QueryFeeAccrualsResponse = from_json(&bin).unwrap();
+    }
+
+    #[test]
+    fn tests_query_offering_status_has_correct_response() {
+        let mut deps = mock_provenance_dependencies();
+        let msg = crate::core::msg::QueryMsg::QueryOfferingStatus {};
+        util::testing::instantiate_contract(deps.as_mut()).unwrap();
+        util::testing::propose_test_commitment(deps.as_mut(), mock_env(), "lp1").unwrap();
+        let bin = route(deps.as_ref(), mock_env(), msg).unwrap();
+        let _: QueryOfferingStatusResponse = from_json(&bin).unwrap();
+    }
 }
@@ -1,4 +1,4 @@
-use cosmwasm_std::{Addr, Coin, StdResult, Storage};
+use cosmwasm_std::{Addr, Coin, Order, StdResult, Storage, Uint128};
 use cw_storage_plus::Map;
 
 use crate::core::{constants::AVAILABLE_CAPITAL_KEY, error::ContractError};
@@ -36,6 +36,20 @@ pub fn get_lps(storage: &dyn Storage) -> Result<Vec<Addr>, ContractError> {
     Ok(keys.unwrap())
 }
 
+// The capital that has been deposited in the denom and not yet withdrawn, across every lp
+pub fn get_total(storage: &dyn Storage, denom: &str) -> Result<Uint128, ContractError> {
+    let mut total = Uint128::zero();
+    for capital in AVAILABLE_CAPITAL.range(storage, None, None, Order::Ascending) {
+        let (_, capital) = capital?;
+        total += capital
+            .iter()
+            .filter(|coin| coin.denom == denom)
+            .map(|coin| coin.amount)
+            .sum::<Uint128>();
+    }
+    Ok(total)
+}
+
 pub fn remove_capital(storage: &mut dyn Storage, lp: Addr) -> Result<Coin, ContractError> {
     let capital = AVAILABLE_CAPITAL.load(storage, lp.clone())?;
     AVAILABLE_CAPITAL.remove(storage, lp);
@@ -67,7 +81,7 @@ mod tests {
 
     use crate::storage::available_capital::{add_capital, add_to_capital, has_lp, remove_capital};
 
-    use super::{get_capital, get_lps};
+    use super::{get_capital, get_lps, get_total};
 
     #[test]
     fn test_add_to_capital_works_with_empty() {
@@ -168,6 +182,29 @@ mod tests {
         assert_eq!(0, lps.len());
     }
 
+    #[test]
+    fn test_get_total() {
+        let mut deps = mock_provenance_dependencies();
+        assert_eq!(Uint128::zero(), get_total(&deps.storage, "denom").unwrap());
+
+        add_capital(
+            deps.as_mut().storage,
+            Addr::unchecked("lp"),
+            vec![Coin::new(Uint128::new(50), "denom".to_string())],
+        )
+        .unwrap();
+        add_capital(
+            deps.as_mut().storage,
+            Addr::unchecked("lp2"),
+            vec![
+                Coin::new(Uint128::new(25), "denom".to_string()),
+                Coin::new(Uint128::new(100), "other".to_string()),
+            ],
+        )
+        .unwrap();
+        assert_eq!(Uint128::new(75), get_total(&deps.storage, "denom").unwrap());
+    }
+
     #[test]
     fn test_get_capital_invalid() {
         let mut deps = mock_provenance_dependencies();
@@ -0,0 +1,121 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Storage, Uint64};
+use cw_storage_plus::Map;
+
+use crate::{
+    core::{constants::CAPITAL_CALLS_KEY, error::ContractError, security::SecurityCommitment},
+    execute::settlement::commitment::Commitment,
+};
+
+// The total capital that has been called from a lp for each of their security commitments
+#[cw_serde]
+pub struct CapitalCall {
+    pub securities: Vec<SecurityCommitment>,
+    pub due_date: Option<Uint64>,
+    // Set once the called capital has been released to the GP. The lp has then paid in more than the
+    // contract still holds for them, so they can no longer be refunded.
+    #[serde(default)]
+    pub released: bool,
+}
+
+impl CapitalCall {
+    pub fn new(commitment: &Commitment) -> Self {
+        let mut commitment = commitment.clone();
+        commitment.clear_amounts();
+        CapitalCall {
+            securities: commitment.commitments,
+            due_date: None,
+            released: false,
+        }
+    }
+}
+
+pub const CAPITAL_CALLS: Map<Addr, CapitalCall> = Map::new(CAPITAL_CALLS_KEY);
+
+pub fn get(storage: &dyn Storage, lp: Addr) -> Option<CapitalCall> {
+    CAPITAL_CALLS.load(storage, lp).ok()
+}
+
+pub fn set(
+    storage: &mut dyn Storage,
+    lp: Addr,
+    capital_call: &CapitalCall,
+) -> Result<(), ContractError> {
+    Ok(CAPITAL_CALLS.save(storage, lp, capital_call)?)
+}
+
+pub fn remove(storage: &mut dyn Storage, lp: Addr) {
+    CAPITAL_CALLS.remove(storage, lp);
+}
+
+pub fn has_lp(storage: &dyn Storage, lp: Addr) -> bool {
+    CAPITAL_CALLS.has(storage, lp)
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{Addr, Uint128, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        execute::settlement::commitment::Commitment,
+        storage::capital_calls::{get, has_lp, remove, set, CapitalCall},
+        util::testing::SettlementTester,
+    };
+
+    #[test]
+    fn test_new_has_no_called_capital() {
+        let mut settlement_tester = SettlementTester::new();
+        settlement_tester.create_security_commitments(2);
+        let commitment = Commitment::new(
+            Addr::unchecked("lp"),
+            settlement_tester.security_commitments.clone(),
+        );
+
+        let capital_call = CapitalCall::new(&commitment);
+        assert_eq!(2, capital_call.securities.len());
+        for (called, committed) in capital_call.securities.iter().zip(&commitment.commitments) {
+            assert_eq!(committed.name, called.name);
+            assert_eq!(Uint128::zero(), called.amount);
+        }
+        assert_eq!(None, capital_call.due_date);
+    }
+
+    #[test]
+    fn test_get_invalid_lp() {
+        let deps = mock_provenance_dependencies();
+        assert_eq!(None, get(&deps.storage, Addr::unchecked("lp")));
+    }
+
+    #[test]
+    fn test_set_and_get() {
+        let mut deps = mock_provenance_dependencies();
+        let lp = Addr::unchecked("lp");
+        let mut settlement_tester = SettlementTester::new();
+        settlement_tester.create_security_commitments(1);
+        let capital_call = CapitalCall {
+            securities: settlement_tester.security_commitments.clone(),
+            due_date: Some(Uint64::new(100)),
+            released: false,
+        };
+
+        set(deps.as_mut().storage, lp.clone(), &capital_call).unwrap();
+        assert_eq!(Some(capital_call), get(&deps.storage, lp));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut deps = mock_provenance_dependencies();
+        let lp = Addr::unchecked("lp");
+        let capital_call = CapitalCall {
+            securities: vec![],
+            due_date: None,
+            released: false,
+        };
+
+        set(deps.as_mut().storage, lp.clone(), &capital_call).unwrap();
+        assert_eq!(true, has_lp(&deps.storage, lp.clone()));
+        remove(deps.as_mut().storage, lp.clone());
+        assert_eq!(false, has_lp(&deps.storage, lp));
+    }
+}
@@ -0,0 +1,159 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Env, Order, Storage, Uint64};
+use cw_storage_plus::{Bound, Map};
+
+use crate::{
+    core::{constants::COMMITMENT_HISTORY_KEY, error::ContractError, security::SecurityCommitment},
+    execute::settlement::commitment::CommitmentState,
+    storage::commits,
+    util::pagination::paginate,
+};
+
+// Something that happened to a lp's commitment, who did it, when, the securities it applied to,
+// and the state the commitment was left in
+#[cw_serde]
+pub struct HistoryEntry {
+    pub sequence: u64,
+    pub action: String,
+    pub actor: Addr,
+    pub time: Uint64,
+    pub height: Uint64,
+    pub amounts: Vec<SecurityCommitment>,
+    pub state: CommitmentState,
+}
+
+// Every entry recorded for a lp, keyed by its sequence number. Entries are only ever appended.
+pub const COMMITMENT_HISTORY: Map<(Addr, u64), HistoryEntry> = Map::new(COMMITMENT_HISTORY_KEY);
+
+// Appends an entry for the lp's commitment in whatever state it was saved in
+pub fn record(
+    storage: &mut dyn Storage,
+    env: &Env,
+    lp: &Addr,
+    actor: &Addr,
+    action: &str,
+    amounts: &[SecurityCommitment],
+) -> Result<(), ContractError> {
+    let sequence = COMMITMENT_HISTORY
+        .prefix(lp.clone())
+        .keys(storage, None, None, Order::Descending)
+        .next()
+        .transpose()?
+        .map_or(0, |last| last + 1);
+    let entry = HistoryEntry {
+        sequence,
+        action: action.to_string(),
+        actor: actor.clone(),
+        time: Uint64::new(env.block.time.seconds()),
+        height: Uint64::new(env.block.height),
+        amounts: amounts.to_vec(),
+        state: commits::get(storage, lp.clone())?.state,
+    };
+    Ok(COMMITMENT_HISTORY.save(storage, (lp.clone(), sequence), &entry)?)
+}
+
+// Returns a page of the lp's history from oldest to newest, along with the sequence the next page starts after
+pub fn get_page(
+    storage: &dyn Storage,
+    lp: Addr,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> Result<(Vec<HistoryEntry>, Option<u64>), ContractError> {
+    let entries = COMMITMENT_HISTORY
+        .prefix(lp)
+        .range(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .map(|item| item.map(|(_, entry)| entry));
+    Ok(paginate(entries, limit, |entry| entry.sequence)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{testing::mock_env, Addr, Uint128, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        core::security::SecurityCommitment,
+        execute::settlement::commitment::{Commitment, CommitmentState},
+        storage::commits,
+    };
+
+    use super::{get_page, record, HistoryEntry};
+
+    #[test]
+    fn test_record_appends_in_sequence() {
+        let mut deps = mock_provenance_dependencies();
+        let env = mock_env();
+        let lp = Addr::unchecked("lp");
+        let amounts = vec![SecurityCommitment {
+            name: "Security1".to_string(),
+            amount: Uint128::new(5),
+        }];
+        let mut commitment = Commitment::new(lp.clone(), amounts.clone());
+        commits::set(deps.as_mut().storage, &commitment).unwrap();
+        record(deps.as_mut().storage, &env, &lp, &lp, "proposed", &amounts).unwrap();
+        commitment.state = CommitmentState::ACCEPTED;
+        commits::set(deps.as_mut().storage, &commitment).unwrap();
+        record(
+            deps.as_mut().storage,
+            &env,
+            &lp,
+            &Addr::unchecked("gp"),
+            "accepted",
+            &amounts,
+        )
+        .unwrap();
+
+        let (history, next) = get_page(&deps.storage, lp.clone(), None, None).unwrap();
+        assert_eq!(None, next);
+        assert_eq!(
+            vec![
+                HistoryEntry {
+                    sequence: 0,
+                    action: "proposed".to_string(),
+                    actor: lp.clone(),
+                    time: Uint64::new(env.block.time.seconds()),
+                    height: Uint64::new(env.block.height),
+                    amounts: amounts.clone(),
+                    state: CommitmentState::PENDING,
+                },
+                HistoryEntry {
+                    sequence: 1,
+                    action: "accepted".to_string(),
+                    actor: Addr::unchecked("gp"),
+                    time: Uint64::new(env.block.time.seconds()),
+                    height: Uint64::new(env.block.height),
+                    amounts,
+                    state: CommitmentState::ACCEPTED,
+                }
+            ],
+            history
+        );
+
+        let (history, next) = get_page(&deps.storage, lp.clone(), None, Some(1)).unwrap();
+        assert_eq!(1, history.len());
+        assert_eq!(Some(0), next);
+        let (history, next) = get_page(&deps.storage, lp, next, Some(1)).unwrap();
+        assert_eq!(1, history[0].sequence);
+        assert_eq!(None, next);
+    }
+
+    #[test]
+    fn test_record_requires_commitment() {
+        let mut deps = mock_provenance_dependencies();
+        let lp = Addr::unchecked("lp");
+        record(
+            deps.as_mut().storage,
+            &mock_env(),
+            &lp,
+            &lp,
+            "proposed",
+            &[],
+        )
+        .unwrap_err();
+    }
+}
@@ -1,23 +1,61 @@
-use cosmwasm_std::{Addr, Order, Storage, Uint64};
-use cw_storage_plus::Map;
+use cosmwasm_std::{Addr, Order, StdResult, Storage, Uint64};
+use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, MultiIndex};
 
 use crate::{
-    core::{constants::COMMITS_KEY, error::ContractError},
+    core::{
+        constants::{COMMITS_KEY, COMMITS_STATE_INDEX_KEY},
+        error::ContractError,
+    },
     execute::settlement::commitment::{Commitment, CommitmentState},
+    util::pagination::paginate,
 };
 
-pub const COMMITS: Map<Addr, Commitment> = Map::new(COMMITS_KEY);
+use super::offering_totals;
+
+pub struct CommitIndexes<'a> {
+    pub state: MultiIndex<'a, u8, Commitment, Addr>,
+}
+
+impl IndexList<Commitment> for CommitIndexes<'_> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Commitment>> + '_> {
+        let v: Vec<&dyn Index<Commitment>> = vec![&self.state];
+        Box::new(v.into_iter())
+    }
+}
+
+// Commitments are indexed by their state so they can be listed without ranging over every lp
+pub const COMMITS: IndexedMap<Addr, Commitment, CommitIndexes> = IndexedMap::new(
+    COMMITS_KEY,
+    CommitIndexes {
+        state: MultiIndex::new(
+            |_pk, commitment| state_key(&commitment.state),
+            COMMITS_KEY,
+            COMMITS_STATE_INDEX_KEY,
+        ),
+    },
+);
+
+fn state_key(state: &CommitmentState) -> u8 {
+    state.clone() as u8
+}
 
 pub fn get(storage: &dyn Storage, lp: Addr) -> Result<Commitment, ContractError> {
     Ok(COMMITS.load(storage, lp)?)
 }
 
 pub fn set(storage: &mut dyn Storage, commitment: &Commitment) -> Result<(), ContractError> {
+    if let Some(previous) = COMMITS.may_load(storage, commitment.lp.clone())? {
+        offering_totals::remove_commitment(storage, &previous)?;
+    }
+    offering_totals::add_commitment(storage, commitment)?;
     Ok(COMMITS.save(storage, commitment.lp.clone(), commitment)?)
 }
 
-pub fn remove(storage: &mut dyn Storage, commitment_lp: Addr) {
-    COMMITS.remove(storage, commitment_lp);
+pub fn remove(storage: &mut dyn Storage, commitment_lp: Addr) -> Result<(), ContractError> {
+    if let Some(previous) = COMMITS.may_load(storage, commitment_lp.clone())? {
+        offering_totals::remove_commitment(storage, &previous)?;
+    }
+    Ok(COMMITS.remove(storage, commitment_lp)?)
 }
 
 pub fn exists(storage: &dyn Storage, lp: Addr) -> bool {
@@ -26,13 +64,53 @@ pub fn exists(storage: &dyn Storage, lp: Addr) -> bool {
 
 pub fn get_with_state(storage: &dyn Storage, state: CommitmentState) -> Vec<Commitment> {
     let commits: Vec<Commitment> = COMMITS
+        .idx
+        .state
+        .prefix(state_key(&state))
         .range(storage, None, None, Order::Ascending)
-        .filter(|item| item.is_ok() && item.as_ref().unwrap().1.state == state)
-        .map(|item| item.unwrap().1)
+        .filter_map(Result::ok)
+        .map(|(_, commitment)| commitment)
         .collect();
     commits
 }
 
+// Returns a page of the commitments in the state, along with the lp the next page starts after
+pub fn get_page_with_state(
+    storage: &dyn Storage,
+    state: CommitmentState,
+    start_after: Option<Addr>,
+    limit: Option<u32>,
+) -> Result<(Vec<Commitment>, Option<Addr>), ContractError> {
+    let commits = COMMITS
+        .idx
+        .state
+        .prefix(state_key(&state))
+        .range(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .map(|item| item.map(|(_, commitment)| commitment));
+    Ok(paginate(commits, limit, |commitment| {
+        commitment.lp.clone()
+    })?)
+}
+
+// Writes every stored commitment back through the index, for commitments saved before it existed
+pub fn reindex(storage: &mut dyn Storage) -> Result<(), ContractError> {
+    let commits = COMMITS
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, commitment)| commitment))
+        .collect::<StdResult<Vec<Commitment>>>()?;
+    // Saved directly, since the commitments haven't changed and the offering totals are counted
+    // separately
+    for commit in commits {
+        COMMITS.save(storage, commit.lp.clone(), &commit)?;
+    }
+    Ok(())
+}
+
 pub fn set_settlement_time(
     storage: &mut dyn Storage,
     new_settlement_time: Option<Uint64>,
@@ -75,7 +153,7 @@ mod tests {
         let lp = Addr::unchecked("lp");
         let commitment = Commitment::new(lp.clone(), vec![]);
         set(deps.as_mut().storage, &commitment).unwrap();
-        remove(deps.as_mut().storage, lp.clone());
+        remove(deps.as_mut().storage, lp.clone()).unwrap();
         assert_eq!(false, exists(deps.as_mut().storage, lp));
     }
 
@@ -0,0 +1,83 @@
+use cosmwasm_std::{Addr, Storage};
+use cw_storage_plus::Map;
+
+use crate::core::{
+    constants::COUNTER_PROPOSALS_KEY, error::ContractError, security::SecurityCommitment,
+};
+
+// The amounts the GP is willing to accept for a lp's pending commitment
+pub const COUNTER_PROPOSALS: Map<Addr, Vec<SecurityCommitment>> = Map::new(COUNTER_PROPOSALS_KEY);
+
+pub fn get(storage: &dyn Storage, lp: Addr) -> Option<Vec<SecurityCommitment>> {
+    COUNTER_PROPOSALS.load(storage, lp).ok()
+}
+
+pub fn set(
+    storage: &mut dyn Storage,
+    lp: Addr,
+    securities: &Vec<SecurityCommitment>,
+) -> Result<(), ContractError> {
+    Ok(COUNTER_PROPOSALS.save(storage, lp, securities)?)
+}
+
+pub fn remove(storage: &mut dyn Storage, lp: Addr) {
+    COUNTER_PROPOSALS.remove(storage, lp);
+}
+
+pub fn has_lp(storage: &dyn Storage, lp: Addr) -> bool {
+    COUNTER_PROPOSALS.has(storage, lp)
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Addr;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        storage::counter_proposals::{get, has_lp, remove, set},
+        util::testing::SettlementTester,
+    };
+
+    #[test]
+    fn test_get_invalid_lp() {
+        let deps = mock_provenance_dependencies();
+        assert_eq!(None, get(&deps.storage, Addr::unchecked("lp")));
+    }
+
+    #[test]
+    fn test_set_and_get() {
+        let mut deps = mock_provenance_dependencies();
+        let lp = Addr::unchecked("lp");
+        let mut settlement_tester = SettlementTester::new();
+        settlement_tester.create_security_commitments(2);
+
+        set(
+            deps.as_mut().storage,
+            lp.clone(),
+            &settlement_tester.security_commitments,
+        )
+        .unwrap();
+        assert_eq!(
+            Some(settlement_tester.security_commitments),
+            get(&deps.storage, lp)
+        );
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut deps = mock_provenance_dependencies();
+        let lp = Addr::unchecked("lp");
+        let mut settlement_tester = SettlementTester::new();
+        settlement_tester.create_security_commitments(1);
+
+        set(
+            deps.as_mut().storage,
+            lp.clone(),
+            &settlement_tester.security_commitments,
+        )
+        .unwrap();
+        assert!(has_lp(&deps.storage, lp.clone()));
+        remove(deps.as_mut().storage, lp.clone());
+        assert!(!has_lp(&deps.storage, lp));
+    }
+}
@@ -0,0 +1,352 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal256, Order, StdError, Storage, Uint128, Uint256};
+use cw_storage_plus::Map;
+
+use crate::core::{
+    constants::{DISTRIBUTION_INDEXES_KEY, HOLDERS_KEY, HOLDINGS_KEY},
+    error::ContractError,
+};
+
+// The cumulative amount of capital distributed per unit of a security, the units it is spread across,
+// and the total capital that has been distributed to the security
+#[cw_serde]
+#[derive(Default)]
+pub struct DistributionIndex {
+    pub index: Decimal256,
+    pub total_units: Uint128,
+    pub distributed: Uint128,
+}
+
+// The units of a security held by a lp, and the distribution index they were last paid up to
+#[cw_serde]
+#[derive(Default)]
+pub struct Holding {
+    pub units: Uint128,
+    pub index: Decimal256,
+    pub unclaimed: Uint128,
+}
+
+impl Holding {
+    // The distributions owed to the holder up to the supplied index
+    pub fn accrued(&self, index: Decimal256) -> Result<Uint128, ContractError> {
+        let earned = (Decimal256::from_ratio(self.units, 1u128) * (index - self.index))
+            .to_uint_floor()
+            .checked_add(Uint256::from(self.unclaimed))
+            .map_err(StdError::from)?;
+        Ok(Uint128::try_from(earned).map_err(StdError::from)?)
+    }
+}
+
+pub const DISTRIBUTION_INDEXES: Map<String, DistributionIndex> = Map::new(DISTRIBUTION_INDEXES_KEY);
+pub const HOLDINGS: Map<(Addr, String), Holding> = Map::new(HOLDINGS_KEY);
+// Every lp that has ever held units of a security, keyed by address alone so it can be ranged in
+// the same order as the commitments
+pub const HOLDERS: Map<Addr, bool> = Map::new(HOLDERS_KEY);
+
+pub fn get_index(storage: &dyn Storage, security: String) -> DistributionIndex {
+    DISTRIBUTION_INDEXES
+        .load(storage, security)
+        .unwrap_or_default()
+}
+
+pub fn get_holding(storage: &dyn Storage, lp: Addr, security: String) -> Option<Holding> {
+    HOLDINGS.load(storage, (lp, security)).ok()
+}
+
+pub fn get_holdings(storage: &dyn Storage, lp: Addr) -> Vec<(String, Holding)> {
+    HOLDINGS
+        .prefix(lp)
+        .range(storage, None, None, Order::Ascending)
+        .filter_map(Result::ok)
+        .collect()
+}
+
+// Gives the lp additional units of a security. Anything the lp has already earned is kept,
+// but the new units only earn from distributions made after this point.
+pub fn add_units(
+    storage: &mut dyn Storage,
+    lp: Addr,
+    security: String,
+    units: Uint128,
+) -> Result<(), ContractError> {
+    let mut index = get_index(storage, security.clone());
+    let mut holding = get_holding(storage, lp.clone(), security.clone()).unwrap_or_default();
+
+    holding.unclaimed = holding.accrued(index.index)?;
+    holding.index = index.index;
+    holding.units += units;
+    index.total_units += units;
+
+    HOLDINGS.save(storage, (lp.clone(), security.clone()), &holding)?;
+    HOLDERS.save(storage, lp, &true)?;
+    Ok(DISTRIBUTION_INDEXES.save(storage, security, &index)?)
+}
+
+// Takes units of a security away from the lp. Anything the lp earned while holding them is kept.
+pub fn remove_units(
+    storage: &mut dyn Storage,
+    lp: Addr,
+    security: String,
+    units: Uint128,
+) -> Result<(), ContractError> {
+    let mut index = get_index(storage, security.clone());
+    let mut holding = get_holding(storage, lp.clone(), security.clone()).unwrap_or_default();
+    if holding.units < units {
+        return Err(ContractError::InsufficientUnits {});
+    }
+
+    holding.unclaimed = holding.accrued(index.index)?;
+    holding.index = index.index;
+    holding.units -= units;
+    index.total_units -= units;
+
+    HOLDINGS.save(storage, (lp, security.clone()), &holding)?;
+    Ok(DISTRIBUTION_INDEXES.save(storage, security, &index)?)
+}
+
+// Spreads the amount across every unit of the security that is currently held
+pub fn distribute(
+    storage: &mut dyn Storage,
+    security: String,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    let mut index = get_index(storage, security.clone());
+    if index.total_units.is_zero() {
+        return Err(ContractError::NoDistributionHolders {});
+    }
+
+    index.index += Decimal256::from_ratio(amount, index.total_units);
+    index.distributed += amount;
+    Ok(DISTRIBUTION_INDEXES.save(storage, security, &index)?)
+}
+
+// The total distributions the lp can claim across every security they hold
+pub fn claimable(storage: &dyn Storage, lp: Addr) -> Result<Uint128, ContractError> {
+    let mut total = Uint128::zero();
+    for (security, holding) in get_holdings(storage, lp) {
+        total += holding.accrued(get_index(storage, security).index)?;
+    }
+    Ok(total)
+}
+
+// Marks every distribution owed to the lp as paid and returns the amount owed
+pub fn claim(storage: &mut dyn Storage, lp: Addr) -> Result<Uint128, ContractError> {
+    let mut total = Uint128::zero();
+    for (security, mut holding) in get_holdings(storage, lp.clone()) {
+        let index = get_index(storage, security.clone()).index;
+        total += holding.accrued(index)?;
+        holding.index = index;
+        holding.unclaimed = Uint128::zero();
+        HOLDINGS.save(storage, (lp.clone(), security), &holding)?;
+    }
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{Addr, Decimal256, Order, StdResult, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        core::error::ContractError,
+        storage::distributions::{
+            add_units, claim, claimable, distribute, get_holding, get_index, remove_units,
+            DistributionIndex, HOLDERS,
+        },
+    };
+
+    #[test]
+    fn test_get_index_defaults_to_zero() {
+        let deps = mock_provenance_dependencies();
+        assert_eq!(
+            DistributionIndex::default(),
+            get_index(&deps.storage, "Security1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_distribute_fails_without_holders() {
+        let mut deps = mock_provenance_dependencies();
+        let error = distribute(
+            deps.as_mut().storage,
+            "Security1".to_string(),
+            Uint128::new(100),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::NoDistributionHolders {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_distribute_is_pro_rata() {
+        let mut deps = mock_provenance_dependencies();
+        let security = "Security1".to_string();
+        add_units(
+            deps.as_mut().storage,
+            Addr::unchecked("lp1"),
+            security.clone(),
+            Uint128::new(30),
+        )
+        .unwrap();
+        add_units(
+            deps.as_mut().storage,
+            Addr::unchecked("lp2"),
+            security.clone(),
+            Uint128::new(10),
+        )
+        .unwrap();
+
+        distribute(deps.as_mut().storage, security.clone(), Uint128::new(100)).unwrap();
+        let index = get_index(&deps.storage, security);
+        assert_eq!(Uint128::new(40), index.total_units);
+        assert_eq!(Uint128::new(100), index.distributed);
+        assert_eq!(Decimal256::percent(250), index.index);
+        assert_eq!(
+            Uint128::new(75),
+            claimable(&deps.storage, Addr::unchecked("lp1")).unwrap()
+        );
+        assert_eq!(
+            Uint128::new(25),
+            claimable(&deps.storage, Addr::unchecked("lp2")).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_new_units_do_not_earn_past_distributions() {
+        let mut deps = mock_provenance_dependencies();
+        let lp1 = Addr::unchecked("lp1");
+        let lp2 = Addr::unchecked("lp2");
+        let security = "Security1".to_string();
+        add_units(
+            deps.as_mut().storage,
+            lp1.clone(),
+            security.clone(),
+            Uint128::new(10),
+        )
+        .unwrap();
+        distribute(deps.as_mut().storage, security.clone(), Uint128::new(100)).unwrap();
+
+        add_units(
+            deps.as_mut().storage,
+            lp2.clone(),
+            security.clone(),
+            Uint128::new(10),
+        )
+        .unwrap();
+        add_units(
+            deps.as_mut().storage,
+            lp1.clone(),
+            security.clone(),
+            Uint128::new(10),
+        )
+        .unwrap();
+        distribute(deps.as_mut().storage, security.clone(), Uint128::new(100)).unwrap();
+
+        assert_eq!(
+            Uint128::new(100),
+            get_holding(&deps.storage, lp1.clone(), security)
+                .unwrap()
+                .unclaimed
+        );
+        assert_eq!(Uint128::new(166), claimable(&deps.storage, lp1).unwrap());
+        assert_eq!(Uint128::new(33), claimable(&deps.storage, lp2).unwrap());
+    }
+
+    #[test]
+    fn test_claim_across_securities() {
+        let mut deps = mock_provenance_dependencies();
+        let lp = Addr::unchecked("lp1");
+        for security in ["Security1", "Security2"] {
+            add_units(
+                deps.as_mut().storage,
+                lp.clone(),
+                security.to_string(),
+                Uint128::new(10),
+            )
+            .unwrap();
+            distribute(
+                deps.as_mut().storage,
+                security.to_string(),
+                Uint128::new(50),
+            )
+            .unwrap();
+        }
+
+        assert_eq!(
+            Uint128::new(100),
+            claim(deps.as_mut().storage, lp.clone()).unwrap()
+        );
+        assert_eq!(
+            Uint128::zero(),
+            claimable(&deps.storage, lp.clone()).unwrap()
+        );
+        assert_eq!(Uint128::zero(), claim(deps.as_mut().storage, lp).unwrap());
+    }
+
+    #[test]
+    fn test_remove_units_keeps_earned_distributions() {
+        let mut deps = mock_provenance_dependencies();
+        let lp = Addr::unchecked("lp1");
+        let security = "Security1".to_string();
+        add_units(
+            deps.as_mut().storage,
+            lp.clone(),
+            security.clone(),
+            Uint128::new(20),
+        )
+        .unwrap();
+        distribute(deps.as_mut().storage, security.clone(), Uint128::new(100)).unwrap();
+
+        remove_units(
+            deps.as_mut().storage,
+            lp.clone(),
+            security.clone(),
+            Uint128::new(10),
+        )
+        .unwrap();
+        assert_eq!(
+            Uint128::new(10),
+            get_index(&deps.storage, security.clone()).total_units
+        );
+        assert_eq!(
+            Uint128::new(100),
+            claimable(&deps.storage, lp.clone()).unwrap()
+        );
+
+        let error =
+            remove_units(deps.as_mut().storage, lp, security, Uint128::new(11)).unwrap_err();
+        assert_eq!(
+            ContractError::InsufficientUnits {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_add_units_indexes_holders() {
+        let mut deps = mock_provenance_dependencies();
+        for (lp, security) in [
+            ("lp10", "Security1"),
+            ("lp2", "Security2"),
+            ("lp10", "Security2"),
+        ] {
+            add_units(
+                deps.as_mut().storage,
+                Addr::unchecked(lp),
+                security.to_string(),
+                Uint128::new(10),
+            )
+            .unwrap();
+        }
+
+        let holders = HOLDERS
+            .keys(&deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<Addr>>>()
+            .unwrap();
+        assert_eq!(
+            vec![Addr::unchecked("lp10"), Addr::unchecked("lp2")],
+            holders
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use cosmwasm_std::{Addr, Storage};
+use cw_storage_plus::Map;
+
+use crate::core::{
+    constants::{LP_ALLOWLIST_KEY, REQUIRED_ATTRIBUTES_KEY},
+    error::ContractError,
+};
+
+// The account attributes an lp must hold to commit to a security
+pub const REQUIRED_ATTRIBUTES: Map<String, Vec<String>> = Map::new(REQUIRED_ATTRIBUTES_KEY);
+// Lps that the GP has cleared to commit to any security without holding the required attributes
+pub const LP_ALLOWLIST: Map<Addr, bool> = Map::new(LP_ALLOWLIST_KEY);
+
+pub fn get_required_attributes(storage: &dyn Storage, security: String) -> Vec<String> {
+    REQUIRED_ATTRIBUTES
+        .load(storage, security)
+        .unwrap_or_default()
+}
+
+pub fn set_required_attributes(
+    storage: &mut dyn Storage,
+    security: String,
+    attributes: &Vec<String>,
+) -> Result<(), ContractError> {
+    if attributes.is_empty() {
+        REQUIRED_ATTRIBUTES.remove(storage, security);
+        return Ok(());
+    }
+    Ok(REQUIRED_ATTRIBUTES.save(storage, security, attributes)?)
+}
+
+pub fn is_allowlisted(storage: &dyn Storage, lp: Addr) -> bool {
+    LP_ALLOWLIST.has(storage, lp)
+}
+
+pub fn allow(storage: &mut dyn Storage, lp: Addr) -> Result<(), ContractError> {
+    Ok(LP_ALLOWLIST.save(storage, lp, &true)?)
+}
+
+pub fn disallow(storage: &mut dyn Storage, lp: Addr) {
+    LP_ALLOWLIST.remove(storage, lp);
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Addr;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::storage::eligibility::{
+        allow, disallow, get_required_attributes, is_allowlisted, set_required_attributes,
+    };
+
+    #[test]
+    fn test_required_attributes_default_to_empty() {
+        let deps = mock_provenance_dependencies();
+        assert!(get_required_attributes(&deps.storage, "Security1".to_string()).is_empty());
+    }
+
+    #[test]
+    fn test_set_and_clear_required_attributes() {
+        let mut deps = mock_provenance_dependencies();
+        let attributes = vec!["accredited.investor.pb".to_string()];
+        set_required_attributes(deps.as_mut().storage, "Security1".to_string(), &attributes)
+            .unwrap();
+        assert_eq!(
+            attributes,
+            get_required_attributes(&deps.storage, "Security1".to_string())
+        );
+        assert!(get_required_attributes(&deps.storage, "Security2".to_string()).is_empty());
+
+        set_required_attributes(deps.as_mut().storage, "Security1".to_string(), &vec![]).unwrap();
+        assert!(get_required_attributes(&deps.storage, "Security1".to_string()).is_empty());
+    }
+
+    #[test]
+    fn test_allow_and_disallow() {
+        let mut deps = mock_provenance_dependencies();
+        let lp = Addr::unchecked("lp");
+        assert!(!is_allowlisted(&deps.storage, lp.clone()));
+
+        allow(deps.as_mut().storage, lp.clone()).unwrap();
+        assert!(is_allowlisted(&deps.storage, lp.clone()));
+
+        disallow(deps.as_mut().storage, lp.clone());
+        assert!(!is_allowlisted(&deps.storage, lp));
+    }
+}
@@ -0,0 +1,100 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Storage, Uint128, Uint64};
+use cw_storage_plus::{Item, Map};
+
+use crate::core::{
+    constants::{FEE_ACCRUALS_KEY, FEE_SCHEDULE_KEY},
+    error::ContractError,
+    fee::FeeSchedule,
+};
+
+// The fees charged to a lp so far, and how much of them has been deducted
+#[cw_serde]
+#[derive(Default)]
+pub struct FeeAccrual {
+    pub management_fee: Uint128,
+    pub carry: Uint128,
+    pub paid: Uint128,
+    pub distributed: Uint128, // distributions the lp has claimed, used to find when carry starts
+    pub last_accrual: Uint64,
+}
+
+impl FeeAccrual {
+    pub fn outstanding(&self) -> Uint128 {
+        (self.management_fee + self.carry).saturating_sub(self.paid)
+    }
+}
+
+pub const FEE_SCHEDULE: Item<FeeSchedule> = Item::new(FEE_SCHEDULE_KEY);
+pub const FEE_ACCRUALS: Map<Addr, FeeAccrual> = Map::new(FEE_ACCRUALS_KEY);
+
+pub fn get_schedule(storage: &dyn Storage) -> Option<FeeSchedule> {
+    FEE_SCHEDULE.load(storage).ok()
+}
+
+pub fn set_schedule(
+    storage: &mut dyn Storage,
+    schedule: &FeeSchedule,
+) -> Result<(), ContractError> {
+    Ok(FEE_SCHEDULE.save(storage, schedule)?)
+}
+
+pub fn get_accrual(storage: &dyn Storage, lp: Addr) -> FeeAccrual {
+    FEE_ACCRUALS.load(storage, lp).unwrap_or_default()
+}
+
+pub fn set_accrual(
+    storage: &mut dyn Storage,
+    lp: Addr,
+    accrual: &FeeAccrual,
+) -> Result<(), ContractError> {
+    Ok(FEE_ACCRUALS.save(storage, lp, accrual)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{Addr, Decimal, Uint128, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        core::fee::{FeeBasis, FeeSchedule},
+        storage::fees::{get_accrual, get_schedule, set_accrual, set_schedule, FeeAccrual},
+    };
+
+    #[test]
+    fn test_set_and_get_schedule() {
+        let mut deps = mock_provenance_dependencies();
+        assert_eq!(None, get_schedule(&deps.storage));
+
+        let schedule = FeeSchedule {
+            recipient: Addr::unchecked("gp"),
+            management_fee_rate: Decimal::percent(2),
+            management_fee_basis: FeeBasis::Committed,
+            carry_rate: Decimal::percent(20),
+            hurdle: Decimal::percent(8),
+        };
+        set_schedule(deps.as_mut().storage, &schedule).unwrap();
+        assert_eq!(Some(schedule), get_schedule(&deps.storage));
+    }
+
+    #[test]
+    fn test_set_and_get_accrual() {
+        let mut deps = mock_provenance_dependencies();
+        let lp = Addr::unchecked("lp");
+        assert_eq!(
+            FeeAccrual::default(),
+            get_accrual(&deps.storage, lp.clone())
+        );
+
+        let accrual = FeeAccrual {
+            management_fee: Uint128::new(10),
+            carry: Uint128::new(5),
+            paid: Uint128::new(12),
+            distributed: Uint128::new(100),
+            last_accrual: Uint64::new(1),
+        };
+        set_accrual(deps.as_mut().storage, lp.clone(), &accrual).unwrap();
+        assert_eq!(accrual, get_accrual(&deps.storage, lp));
+        assert_eq!(Uint128::new(3), accrual.outstanding());
+    }
+}
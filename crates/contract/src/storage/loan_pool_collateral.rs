@@ -1,8 +1,9 @@
 use crate::core::collateral::LoanPoolMarkerCollateral;
 use crate::core::constants::LOAN_POOL_COLLATERAL;
 use crate::core::error::ContractError;
+use crate::util::pagination::paginate;
 use cosmwasm_std::{Addr, Order, Storage};
-use cw_storage_plus::Map;
+use cw_storage_plus::{Bound, Map};
 
 // Constant for the COLLATERAL storage map with an Addr as key and LoanPoolMarkerCollateral as value
 pub const COLLATERAL: Map<Addr, LoanPoolMarkerCollateral> = Map::new(LOAN_POOL_COLLATERAL);
@@ -93,6 +94,36 @@ pub fn get_all_states(storage: &dyn Storage) -> Vec<LoanPoolMarkerCollateral> {
     collateral
 }
 
+/// Gets a page of the collateral in storage, ordered by marker address.
+///
+/// ## Arguments
+///
+/// - `storage`: A reference to the contract's storage
+/// - `start_after`: The marker address the page starts after, or `None` for the first page
+/// - `limit`: The maximum number of collaterals in the page
+///
+/// ## Returns
+///
+/// - Returns a `Result` with `Ok` variant containing the page and the marker address the next page
+///   starts after, if there is one, otherwise a `Err` variant containing `ContractError`.
+pub fn get_page(
+    storage: &dyn Storage,
+    start_after: Option<Addr>,
+    limit: Option<u32>,
+) -> Result<(Vec<LoanPoolMarkerCollateral>, Option<Addr>), ContractError> {
+    let collaterals = COLLATERAL
+        .range(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .map(|item| item.map(|(_, collateral)| collateral));
+    Ok(paginate(collaterals, limit, |collateral| {
+        collateral.marker_address.clone()
+    })?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -214,4 +245,26 @@ mod tests {
         assert_eq!(collaterals.contains(&collateral2), true);
         assert_eq!(collaterals.contains(&collateral3), true);
     }
+
+    #[test]
+    fn test_get_page() {
+        let mut deps = mock_provenance_dependencies();
+        for addr in ["addr1", "addr2", "addr3"] {
+            let collateral = sample_collateral(addr, "denom", 100, Vec::new(), "owner");
+            set(&mut deps.storage, &collateral).unwrap();
+        }
+
+        let (page, next) = get_page(&deps.storage, None, Some(2)).unwrap();
+        assert_eq!(2, page.len());
+        assert_eq!(Some(Addr::unchecked("addr2")), next);
+
+        let (page, next) = get_page(&deps.storage, next, Some(2)).unwrap();
+        assert_eq!(
+            vec![Addr::unchecked("addr3")],
+            page.into_iter()
+                .map(|c| c.marker_address)
+                .collect::<Vec<Addr>>()
+        );
+        assert_eq!(None, next);
+    }
 }
@@ -1,8 +1,19 @@
 pub mod available_capital;
+pub mod capital_calls;
+pub mod commitment_history;
 pub mod commits;
+pub mod counter_proposals;
+pub mod distributions;
+pub mod eligibility;
+pub mod fees;
 pub mod loan_pool_collateral;
+pub mod nav;
+pub mod offering_totals;
 pub mod paid_in_capital;
+pub mod redemptions;
 pub mod remaining_securities;
+pub mod roles;
 pub mod securities;
+pub mod settlement_times;
 pub mod state;
 pub mod whitelist_contributors_store;
@@ -0,0 +1,193 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Coin, Env, Order, Storage, Uint64};
+use cw_storage_plus::{Bound, Map};
+
+use crate::{
+    core::{
+        constants::NAV_HISTORY_KEY,
+        error::ContractError,
+        security::{Security, SecurityType},
+    },
+    util::pagination::paginate,
+};
+
+// A net asset value per unit published for a fund security, and when it was published
+#[cw_serde]
+pub struct NavEntry {
+    pub sequence: u64,
+    pub nav_per_unit: Coin,
+    pub time: Uint64,
+    pub height: Uint64,
+}
+
+// Every NAV published for a security, keyed by its sequence number. Entries are only ever appended,
+// so several NAVs published in the same block are all kept in the order they were published.
+pub const NAV_HISTORY: Map<(String, u64), NavEntry> = Map::new(NAV_HISTORY_KEY);
+
+// Appends a NAV to the security's history
+pub fn add(
+    storage: &mut dyn Storage,
+    env: &Env,
+    security: String,
+    nav_per_unit: Coin,
+) -> Result<NavEntry, ContractError> {
+    let sequence = get_latest(storage, security.clone())?.map_or(0, |last| last.sequence + 1);
+    let entry = NavEntry {
+        sequence,
+        nav_per_unit,
+        time: Uint64::new(env.block.time.seconds()),
+        height: Uint64::new(env.block.height),
+    };
+    NAV_HISTORY.save(storage, (security, sequence), &entry)?;
+    Ok(entry)
+}
+
+pub fn get_latest(
+    storage: &dyn Storage,
+    security: String,
+) -> Result<Option<NavEntry>, ContractError> {
+    Ok(NAV_HISTORY
+        .prefix(security)
+        .range(storage, None, None, Order::Descending)
+        .next()
+        .transpose()?
+        .map(|(_, entry)| entry))
+}
+
+// Returns a page of the security's history from oldest to newest, along with the sequence the next page starts after
+pub fn get_page(
+    storage: &dyn Storage,
+    security: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> Result<(Vec<NavEntry>, Option<u64>), ContractError> {
+    let entries = NAV_HISTORY
+        .prefix(security)
+        .range(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .map(|item| item.map(|(_, entry)| entry));
+    Ok(paginate(entries, limit, |entry| entry.sequence)?)
+}
+
+// Funds are priced at their latest NAV, and everything else at the price it was created with
+pub fn current_price(storage: &dyn Storage, security: &Security) -> Result<Coin, ContractError> {
+    Ok(match security.security_type {
+        SecurityType::Fund(_) => get_latest(storage, security.name.clone())?
+            .map(|entry| entry.nav_per_unit)
+            .unwrap_or(security.price_per_unit.clone()),
+        _ => security.price_per_unit.clone(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{testing::mock_env, Coin, Env, Uint128, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        core::security::{Security, SecurityType, TrancheSecurity},
+        storage::nav::{add, current_price, get_latest, get_page, NavEntry},
+        util::testing::create_test_securities,
+    };
+
+    fn nav(amount: u128) -> Coin {
+        Coin::new(Uint128::new(amount), "denom")
+    }
+
+    fn entry(sequence: u64, amount: u128, env: &Env) -> NavEntry {
+        NavEntry {
+            sequence,
+            nav_per_unit: nav(amount),
+            time: Uint64::new(env.block.time.seconds()),
+            height: Uint64::new(env.block.height),
+        }
+    }
+
+    #[test]
+    fn test_get_latest_without_history() {
+        let deps = mock_provenance_dependencies();
+        assert_eq!(
+            None,
+            get_latest(&deps.storage, "Security1".to_string()).unwrap()
+        );
+        let (history, next) = get_page(&deps.storage, "Security1".to_string(), None, None).unwrap();
+        assert!(history.is_empty());
+        assert_eq!(None, next);
+    }
+
+    #[test]
+    fn test_history_keeps_every_entry_in_a_block() {
+        let mut deps = mock_provenance_dependencies();
+        let env = mock_env();
+        let security = "Security1".to_string();
+        add(deps.as_mut().storage, &env, security.clone(), nav(110)).unwrap();
+        add(deps.as_mut().storage, &env, security.clone(), nav(120)).unwrap();
+        add(
+            deps.as_mut().storage,
+            &env,
+            "Security2".to_string(),
+            nav(500),
+        )
+        .unwrap();
+
+        assert_eq!(
+            Some(entry(1, 120, &env)),
+            get_latest(&deps.storage, security.clone()).unwrap()
+        );
+        assert_eq!(
+            (vec![entry(0, 110, &env), entry(1, 120, &env)], None),
+            get_page(&deps.storage, security, None, None).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_get_page() {
+        let mut deps = mock_provenance_dependencies();
+        let env = mock_env();
+        let security = "Security1".to_string();
+        for amount in [110, 120, 130] {
+            add(deps.as_mut().storage, &env, security.clone(), nav(amount)).unwrap();
+        }
+
+        assert_eq!(
+            (vec![entry(0, 110, &env), entry(1, 120, &env)], Some(1)),
+            get_page(&deps.storage, security.clone(), None, Some(2)).unwrap()
+        );
+        assert_eq!(
+            (vec![entry(2, 130, &env)], None),
+            get_page(&deps.storage, security, Some(1), Some(2)).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_current_price_uses_nav_for_funds() {
+        let mut deps = mock_provenance_dependencies();
+        let fund = create_test_securities()[0].clone();
+        assert_eq!(
+            fund.price_per_unit,
+            current_price(&deps.storage, &fund).unwrap()
+        );
+
+        add(
+            deps.as_mut().storage,
+            &mock_env(),
+            fund.name.clone(),
+            nav(120),
+        )
+        .unwrap();
+        assert_eq!(nav(120), current_price(&deps.storage, &fund).unwrap());
+
+        let tranche = Security {
+            security_type: SecurityType::Tranche(TrancheSecurity::default()),
+            ..fund
+        };
+        assert_eq!(
+            tranche.price_per_unit,
+            current_price(&deps.storage, &tranche).unwrap()
+        );
+    }
+}
@@ -0,0 +1,219 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Order, StdError, StdResult, Storage, Uint128};
+use cw_storage_plus::Map;
+
+use crate::{
+    core::{constants::OFFERING_TOTALS_KEY, error::ContractError, security::SecurityCommitment},
+    execute::settlement::commitment::{Commitment, CommitmentState},
+};
+
+use super::{commits, paid_in_capital};
+
+// The units of a security in each commitment state, and the units that have been paid in. The
+// totals are kept up to date as commitments and paid in capital are saved, so the offering can be
+// summarized without ranging over every commitment.
+#[cw_serde]
+#[derive(Default)]
+pub struct SecurityTotals {
+    pub pending: Uint128,
+    pub accepted: Uint128,
+    pub settled: Uint128,
+    pub paid_in: Uint128,
+}
+
+impl SecurityTotals {
+    fn committed(&mut self, state: &CommitmentState) -> Option<&mut Uint128> {
+        match state {
+            CommitmentState::PENDING => Some(&mut self.pending),
+            CommitmentState::ACCEPTED => Some(&mut self.accepted),
+            CommitmentState::SETTLED => Some(&mut self.settled),
+            _ => None,
+        }
+    }
+}
+
+pub const OFFERING_TOTALS: Map<String, SecurityTotals> = Map::new(OFFERING_TOTALS_KEY);
+
+pub fn get(storage: &dyn Storage, security: String) -> Result<SecurityTotals, ContractError> {
+    Ok(OFFERING_TOTALS
+        .may_load(storage, security)?
+        .unwrap_or_default())
+}
+
+// Counts the units of the commitment towards its state
+pub fn add_commitment(
+    storage: &mut dyn Storage,
+    commitment: &Commitment,
+) -> Result<(), ContractError> {
+    update(storage, &commitment.commitments, |totals, amount| {
+        if let Some(total) = totals.committed(&commitment.state) {
+            *total = total.checked_add(amount)?;
+        }
+        Ok(())
+    })
+}
+
+// Stops counting the units of the commitment towards its state
+pub fn remove_commitment(
+    storage: &mut dyn Storage,
+    commitment: &Commitment,
+) -> Result<(), ContractError> {
+    update(storage, &commitment.commitments, |totals, amount| {
+        if let Some(total) = totals.committed(&commitment.state) {
+            *total = total.checked_sub(amount)?;
+        }
+        Ok(())
+    })
+}
+
+pub fn add_paid_in(
+    storage: &mut dyn Storage,
+    paid_in: &[SecurityCommitment],
+) -> Result<(), ContractError> {
+    update(storage, paid_in, |totals, amount| {
+        totals.paid_in = totals.paid_in.checked_add(amount)?;
+        Ok(())
+    })
+}
+
+pub fn remove_paid_in(
+    storage: &mut dyn Storage,
+    paid_in: &[SecurityCommitment],
+) -> Result<(), ContractError> {
+    update(storage, paid_in, |totals, amount| {
+        totals.paid_in = totals.paid_in.checked_sub(amount)?;
+        Ok(())
+    })
+}
+
+// Recounts the totals from every stored commitment and paid in capital, for offerings that were
+// saved before the totals existed
+pub fn rebuild(storage: &mut dyn Storage) -> Result<(), ContractError> {
+    OFFERING_TOTALS.clear(storage);
+    let commitments = commits::COMMITS
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, commitment)| commitment))
+        .collect::<StdResult<Vec<Commitment>>>()?;
+    for commitment in commitments {
+        add_commitment(storage, &commitment)?;
+    }
+
+    let paid_in = paid_in_capital::PAID_IN_CAPITAL
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, paid_in)| paid_in))
+        .collect::<StdResult<Vec<Vec<SecurityCommitment>>>>()?;
+    for paid_in in paid_in {
+        add_paid_in(storage, &paid_in)?;
+    }
+    Ok(())
+}
+
+fn update(
+    storage: &mut dyn Storage,
+    securities: &[SecurityCommitment],
+    apply: impl Fn(&mut SecurityTotals, Uint128) -> Result<(), StdError>,
+) -> Result<(), ContractError> {
+    for security in securities {
+        let mut totals = get(storage, security.name.clone())?;
+        apply(&mut totals, security.amount)?;
+        OFFERING_TOTALS.save(storage, security.name.clone(), &totals)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{Addr, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        core::security::SecurityCommitment,
+        execute::settlement::commitment::{Commitment, CommitmentState},
+        storage::{
+            commits,
+            offering_totals::{get, rebuild, SecurityTotals, OFFERING_TOTALS},
+            paid_in_capital,
+        },
+    };
+
+    fn units(amount: u128) -> Vec<SecurityCommitment> {
+        vec![SecurityCommitment {
+            name: "Security1".to_string(),
+            amount: Uint128::new(amount),
+        }]
+    }
+
+    #[test]
+    fn test_get_defaults_to_zero() {
+        let deps = mock_provenance_dependencies();
+        assert_eq!(
+            SecurityTotals::default(),
+            get(&deps.storage, "Security1".to_string()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_totals_follow_commitment_state() {
+        let mut deps = mock_provenance_dependencies();
+        let mut commitment = Commitment::new(Addr::unchecked("lp1"), units(20));
+        commits::set(deps.as_mut().storage, &commitment).unwrap();
+        commits::set(
+            deps.as_mut().storage,
+            &Commitment::new(Addr::unchecked("lp2"), units(5)),
+        )
+        .unwrap();
+        assert_eq!(
+            Uint128::new(25),
+            get(&deps.storage, "Security1".to_string()).unwrap().pending
+        );
+
+        commitment.state = CommitmentState::ACCEPTED;
+        commits::set(deps.as_mut().storage, &commitment).unwrap();
+        paid_in_capital::set(deps.as_mut().storage, commitment.lp.clone(), &units(0)).unwrap();
+        paid_in_capital::add_payment(deps.as_mut().storage, commitment.lp.clone(), units(8))
+            .unwrap();
+        assert_eq!(
+            SecurityTotals {
+                pending: Uint128::new(5),
+                accepted: Uint128::new(20),
+                settled: Uint128::zero(),
+                paid_in: Uint128::new(8),
+            },
+            get(&deps.storage, "Security1".to_string()).unwrap()
+        );
+
+        commitment.state = CommitmentState::CANCELED;
+        commits::set(deps.as_mut().storage, &commitment).unwrap();
+        paid_in_capital::remove(deps.as_mut().storage, commitment.lp.clone()).unwrap();
+        commits::remove(deps.as_mut().storage, Addr::unchecked("lp2")).unwrap();
+        assert_eq!(
+            SecurityTotals::default(),
+            get(&deps.storage, "Security1".to_string()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_rebuild() {
+        let mut deps = mock_provenance_dependencies();
+        let mut commitment = Commitment::new(Addr::unchecked("lp1"), units(20));
+        commitment.state = CommitmentState::SETTLED;
+        commits::set(deps.as_mut().storage, &commitment).unwrap();
+        paid_in_capital::set(deps.as_mut().storage, commitment.lp, &units(20)).unwrap();
+        let expected = get(&deps.storage, "Security1".to_string()).unwrap();
+
+        OFFERING_TOTALS
+            .save(
+                deps.as_mut().storage,
+                "Security1".to_string(),
+                &SecurityTotals::default(),
+            )
+            .unwrap();
+        rebuild(deps.as_mut().storage).unwrap();
+        assert_eq!(
+            expected,
+            get(&deps.storage, "Security1".to_string()).unwrap()
+        );
+        assert_eq!(Uint128::new(20), expected.settled);
+        assert_eq!(Uint128::new(20), expected.paid_in);
+    }
+}
@@ -5,6 +5,8 @@ use crate::core::{
     constants::PAID_IN_CAPITAL_KEY, error::ContractError, security::SecurityCommitment,
 };
 
+use super::offering_totals;
+
 pub const PAID_IN_CAPITAL: Map<Addr, Vec<SecurityCommitment>> = Map::new(PAID_IN_CAPITAL_KEY);
 
 pub fn get(storage: &dyn Storage, lp: Addr) -> Vec<SecurityCommitment> {
@@ -15,8 +17,11 @@ pub fn get(storage: &dyn Storage, lp: Addr) -> Vec<SecurityCommitment> {
     }
 }
 
-pub fn remove(storage: &mut dyn Storage, lp: Addr) {
+pub fn remove(storage: &mut dyn Storage, lp: Addr) -> Result<(), ContractError> {
+    let previous = get(storage, lp.clone());
+    offering_totals::remove_paid_in(storage, &previous)?;
     PAID_IN_CAPITAL.remove(storage, lp);
+    Ok(())
 }
 
 pub fn has_lp(storage: &dyn Storage, lp: Addr) -> bool {
@@ -28,6 +33,9 @@ pub fn set(
     lp: Addr,
     commitments: &Vec<SecurityCommitment>,
 ) -> Result<(), ContractError> {
+    let previous = get(storage, lp.clone());
+    offering_totals::remove_paid_in(storage, &previous)?;
+    offering_totals::add_paid_in(storage, commitments)?;
     Ok(PAID_IN_CAPITAL.save(storage, lp, commitments)?)
 }
 
@@ -36,9 +44,10 @@ pub fn add_payment(
     lp: Addr,
     deposit: Vec<SecurityCommitment>,
 ) -> Result<(), ContractError> {
-    PAID_IN_CAPITAL.update(
+    let previous = get(storage, lp.clone());
+    let paid_in = PAID_IN_CAPITAL.update(
         storage,
-        lp,
+        lp.clone(),
         |already_committed| -> StdResult<Vec<SecurityCommitment>> {
             match already_committed {
                 None => Ok(deposit),
@@ -51,7 +60,8 @@ pub fn add_payment(
             }
         },
     )?;
-    Ok(())
+    offering_totals::remove_paid_in(storage, &previous)?;
+    offering_totals::add_paid_in(storage, &paid_in)
 }
 
 // The purpose of this function is to add new_commitment to commitments.
@@ -102,7 +112,7 @@ mod tests {
         let lp = Addr::unchecked("lp");
         let commitment = Commitment::new(lp.clone(), vec![]);
         set(deps.as_mut().storage, commitment.lp.clone(), &vec![]).unwrap();
-        remove(deps.as_mut().storage, lp.clone());
+        remove(deps.as_mut().storage, lp.clone()).unwrap();
         assert_eq!(false, PAID_IN_CAPITAL.has(&deps.storage, lp));
     }
 
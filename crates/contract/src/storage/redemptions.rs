@@ -0,0 +1,257 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    Addr, Decimal, Order, StdError, StdResult, Storage, Timestamp, Uint128, Uint64,
+};
+use cw_storage_plus::Map;
+
+use crate::core::{
+    constants::{REDEMPTION_QUEUES_KEY, REDEMPTION_REQUESTS_KEY, REDEMPTION_WINDOWS_KEY},
+    error::ContractError,
+};
+
+// The period in which a security can be redeemed at the price published by the GP. The gate
+// limits the units that can be redeemed in the window to a share of the units outstanding
+// when it was opened, and the GP funds the reserve that the redemptions are paid out of.
+#[cw_serde]
+pub struct RedemptionWindow {
+    pub price_per_unit: Uint128,
+    pub start_time: Uint64,
+    pub end_time: Uint64,
+    pub gate: Decimal,
+    pub capacity: Uint128,
+    pub redeemed: Uint128,
+    pub reserve: Uint128,
+}
+
+impl RedemptionWindow {
+    pub fn is_open(&self, time: &Timestamp) -> bool {
+        time.seconds() >= self.start_time.u64() && time.seconds() <= self.end_time.u64()
+    }
+
+    pub fn remaining(&self) -> Uint128 {
+        self.capacity.saturating_sub(self.redeemed)
+    }
+
+    // Draws the payment for redeemed units out of the reserve
+    pub fn redeem(&mut self, units: Uint128) -> Result<Uint128, ContractError> {
+        let payment = units * self.price_per_unit;
+        self.reserve = self
+            .reserve
+            .checked_sub(payment)
+            .map_err(|_| ContractError::InsufficientRedemptionReserve {})?;
+        self.redeemed += units;
+        Ok(payment)
+    }
+}
+
+// Units that a lp has returned to the contract but have not been paid out because of the gate
+#[cw_serde]
+pub struct RedemptionRequest {
+    pub lp: Addr,
+    pub amount: Uint128,
+}
+
+// The id given to the next request in a security's queue, and the units waiting in it
+#[cw_serde]
+#[derive(Default)]
+pub struct RedemptionQueue {
+    pub next_id: u64,
+    pub queued: Uint128,
+}
+
+pub const REDEMPTION_WINDOWS: Map<String, RedemptionWindow> = Map::new(REDEMPTION_WINDOWS_KEY);
+pub const REDEMPTION_QUEUES: Map<String, RedemptionQueue> = Map::new(REDEMPTION_QUEUES_KEY);
+pub const REDEMPTION_REQUESTS: Map<(String, u64), RedemptionRequest> =
+    Map::new(REDEMPTION_REQUESTS_KEY);
+
+pub fn get_window(storage: &dyn Storage, security: String) -> Option<RedemptionWindow> {
+    REDEMPTION_WINDOWS.load(storage, security).ok()
+}
+
+pub fn set_window(
+    storage: &mut dyn Storage,
+    security: String,
+    window: &RedemptionWindow,
+) -> Result<(), ContractError> {
+    Ok(REDEMPTION_WINDOWS.save(storage, security, window)?)
+}
+
+// Adds a request to the end of the security's queue
+pub fn push_request(
+    storage: &mut dyn Storage,
+    security: String,
+    request: &RedemptionRequest,
+) -> Result<(), ContractError> {
+    let mut queue = REDEMPTION_QUEUES
+        .may_load(storage, security.clone())?
+        .unwrap_or_default();
+    REDEMPTION_REQUESTS.save(storage, (security.clone(), queue.next_id), request)?;
+    queue.next_id += 1;
+    queue.queued += request.amount;
+    Ok(REDEMPTION_QUEUES.save(storage, security, &queue)?)
+}
+
+// The requests at the front of the security's queue, oldest first
+pub fn get_requests(
+    storage: &dyn Storage,
+    security: String,
+    limit: usize,
+) -> StdResult<Vec<(u64, RedemptionRequest)>> {
+    REDEMPTION_REQUESTS
+        .prefix(security)
+        .range(storage, None, None, Order::Ascending)
+        .take(limit)
+        .collect()
+}
+
+// Removes units that have been paid out from a request, and drops the request once it is empty
+pub fn remove_units(
+    storage: &mut dyn Storage,
+    security: String,
+    id: u64,
+    units: Uint128,
+) -> Result<(), ContractError> {
+    let mut request = REDEMPTION_REQUESTS.load(storage, (security.clone(), id))?;
+    request.amount = request.amount.checked_sub(units).map_err(StdError::from)?;
+    if request.amount.is_zero() {
+        REDEMPTION_REQUESTS.remove(storage, (security.clone(), id));
+    } else {
+        REDEMPTION_REQUESTS.save(storage, (security.clone(), id), &request)?;
+    }
+
+    let mut queue = REDEMPTION_QUEUES.load(storage, security.clone())?;
+    queue.queued = queue.queued.checked_sub(units).map_err(StdError::from)?;
+    Ok(REDEMPTION_QUEUES.save(storage, security, &queue)?)
+}
+
+// The total units waiting in the security's queue
+pub fn queued_units(storage: &dyn Storage, security: String) -> Uint128 {
+    REDEMPTION_QUEUES
+        .load(storage, security)
+        .map(|queue| queue.queued)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{testing::mock_env, Addr, Decimal, Uint128, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        core::error::ContractError,
+        storage::redemptions::{
+            get_requests, get_window, push_request, queued_units, remove_units, set_window,
+            RedemptionRequest, RedemptionWindow,
+        },
+    };
+
+    fn test_window(start_time: u64, end_time: u64) -> RedemptionWindow {
+        RedemptionWindow {
+            price_per_unit: Uint128::new(100),
+            start_time: Uint64::new(start_time),
+            end_time: Uint64::new(end_time),
+            gate: Decimal::percent(10),
+            capacity: Uint128::new(10),
+            redeemed: Uint128::new(4),
+            reserve: Uint128::new(600),
+        }
+    }
+
+    fn request(lp: &str, amount: u128) -> RedemptionRequest {
+        RedemptionRequest {
+            lp: Addr::unchecked(lp),
+            amount: Uint128::new(amount),
+        }
+    }
+
+    #[test]
+    fn test_get_invalid_window() {
+        let deps = mock_provenance_dependencies();
+        assert_eq!(None, get_window(&deps.storage, "Security1".to_string()));
+    }
+
+    #[test]
+    fn test_set_and_get_window() {
+        let mut deps = mock_provenance_dependencies();
+        let window = test_window(0, 100);
+        set_window(deps.as_mut().storage, "Security1".to_string(), &window).unwrap();
+        assert_eq!(
+            Some(window),
+            get_window(&deps.storage, "Security1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_window_is_open() {
+        let now = mock_env().block.time;
+        assert!(test_window(now.seconds(), now.seconds()).is_open(&now));
+        assert!(!test_window(now.seconds() + 1, now.seconds() + 2).is_open(&now));
+        assert!(!test_window(0, now.seconds() - 1).is_open(&now));
+        assert_eq!(Uint128::new(6), test_window(0, 0).remaining());
+    }
+
+    #[test]
+    fn test_window_redeem_draws_from_reserve() {
+        let mut window = test_window(0, 0);
+        assert_eq!(Uint128::new(500), window.redeem(Uint128::new(5)).unwrap());
+        assert_eq!(Uint128::new(100), window.reserve);
+        assert_eq!(Uint128::new(9), window.redeemed);
+
+        let error = window.redeem(Uint128::new(2)).unwrap_err();
+        assert_eq!(
+            ContractError::InsufficientRedemptionReserve {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_push_and_get_requests() {
+        let mut deps = mock_provenance_dependencies();
+        let security = "Security1".to_string();
+        assert!(get_requests(&deps.storage, security.clone(), 10)
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            Uint128::zero(),
+            queued_units(&deps.storage, security.clone())
+        );
+
+        push_request(deps.as_mut().storage, security.clone(), &request("lp1", 5)).unwrap();
+        push_request(deps.as_mut().storage, security.clone(), &request("lp2", 7)).unwrap();
+        assert_eq!(
+            vec![(0, request("lp1", 5)), (1, request("lp2", 7))],
+            get_requests(&deps.storage, security.clone(), 10).unwrap()
+        );
+        assert_eq!(
+            vec![(0, request("lp1", 5))],
+            get_requests(&deps.storage, security.clone(), 1).unwrap()
+        );
+        assert_eq!(Uint128::new(12), queued_units(&deps.storage, security));
+    }
+
+    #[test]
+    fn test_remove_units() {
+        let mut deps = mock_provenance_dependencies();
+        let security = "Security1".to_string();
+        push_request(deps.as_mut().storage, security.clone(), &request("lp1", 5)).unwrap();
+        push_request(deps.as_mut().storage, security.clone(), &request("lp2", 7)).unwrap();
+
+        remove_units(deps.as_mut().storage, security.clone(), 0, Uint128::new(2)).unwrap();
+        remove_units(deps.as_mut().storage, security.clone(), 0, Uint128::new(3)).unwrap();
+        assert_eq!(
+            vec![(1, request("lp2", 7))],
+            get_requests(&deps.storage, security.clone(), 10).unwrap()
+        );
+        assert_eq!(
+            Uint128::new(7),
+            queued_units(&deps.storage, security.clone())
+        );
+
+        remove_units(deps.as_mut().storage, security.clone(), 1, Uint128::new(8)).unwrap_err();
+        push_request(deps.as_mut().storage, security.clone(), &request("lp1", 1)).unwrap();
+        assert_eq!(
+            vec![(1, request("lp2", 7)), (2, request("lp1", 1))],
+            get_requests(&deps.storage, security, 10).unwrap()
+        );
+    }
+}
@@ -19,7 +19,7 @@ pub fn set(
 }
 
 pub fn has_amount(
-    storage: &mut dyn Storage,
+    storage: &dyn Storage,
     security_name: String,
     amount: u128,
 ) -> Result<bool, ContractError> {
@@ -0,0 +1,84 @@
+use cosmwasm_std::{Addr, Order, Storage};
+use cw_storage_plus::{Item, Map};
+
+use crate::core::{
+    constants::{OPERATORS_KEY, PENDING_GP_KEY},
+    error::ContractError,
+};
+
+// The account the GP has proposed to hand the offering to, until it accepts
+pub const PENDING_GP: Item<Addr> = Item::new(PENDING_GP_KEY);
+// Accounts the GP has appointed to accept commitments and withdraw capital on its behalf
+pub const OPERATORS: Map<Addr, bool> = Map::new(OPERATORS_KEY);
+
+pub fn get_pending_gp(storage: &dyn Storage) -> Option<Addr> {
+    PENDING_GP.load(storage).ok()
+}
+
+pub fn set_pending_gp(storage: &mut dyn Storage, gp: &Addr) -> Result<(), ContractError> {
+    Ok(PENDING_GP.save(storage, gp)?)
+}
+
+pub fn remove_pending_gp(storage: &mut dyn Storage) {
+    PENDING_GP.remove(storage);
+}
+
+pub fn is_operator(storage: &dyn Storage, operator: Addr) -> bool {
+    OPERATORS.has(storage, operator)
+}
+
+pub fn get_operators(storage: &dyn Storage) -> Vec<Addr> {
+    OPERATORS
+        .keys(storage, None, None, Order::Ascending)
+        .filter_map(|operator| operator.ok())
+        .collect()
+}
+
+pub fn add_operator(storage: &mut dyn Storage, operator: Addr) -> Result<(), ContractError> {
+    Ok(OPERATORS.save(storage, operator, &true)?)
+}
+
+pub fn remove_operator(storage: &mut dyn Storage, operator: Addr) {
+    OPERATORS.remove(storage, operator);
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Addr;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::storage::roles::{
+        add_operator, get_operators, get_pending_gp, is_operator, remove_operator,
+        remove_pending_gp, set_pending_gp,
+    };
+
+    #[test]
+    fn test_set_and_remove_pending_gp() {
+        let mut deps = mock_provenance_dependencies();
+        assert_eq!(None, get_pending_gp(&deps.storage));
+
+        set_pending_gp(deps.as_mut().storage, &Addr::unchecked("gp2")).unwrap();
+        assert_eq!(Some(Addr::unchecked("gp2")), get_pending_gp(&deps.storage));
+
+        remove_pending_gp(deps.as_mut().storage);
+        assert_eq!(None, get_pending_gp(&deps.storage));
+    }
+
+    #[test]
+    fn test_add_and_remove_operators() {
+        let mut deps = mock_provenance_dependencies();
+        let operator = Addr::unchecked("operator");
+        assert!(!is_operator(&deps.storage, operator.clone()));
+
+        add_operator(deps.as_mut().storage, operator.clone()).unwrap();
+        add_operator(deps.as_mut().storage, Addr::unchecked("operator2")).unwrap();
+        assert!(is_operator(&deps.storage, operator.clone()));
+        assert_eq!(
+            vec![operator.clone(), Addr::unchecked("operator2")],
+            get_operators(&deps.storage)
+        );
+
+        remove_operator(deps.as_mut().storage, operator.clone());
+        assert!(!is_operator(&deps.storage, operator));
+    }
+}
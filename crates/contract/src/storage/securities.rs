@@ -1,11 +1,18 @@
 use cosmwasm_std::{Order, StdResult, Storage};
 use cw_storage_plus::Map;
 
-use crate::core::{constants::SECURITIES_MAP_KEY, error::ContractError, security::Security};
+use crate::core::{
+    constants::{CLOSED_SECURITIES_KEY, SECURITIES_MAP_KEY},
+    error::ContractError,
+    security::Security,
+};
 
 // We store our securities that we configured on initialization
 pub const SECURITIES_MAP: Map<String, Security> = Map::new(SECURITIES_MAP_KEY);
 
+// Securities that the GP has closed to new proposals
+pub const CLOSED_SECURITIES: Map<String, bool> = Map::new(CLOSED_SECURITIES_KEY);
+
 pub fn get_security_types(storage: &dyn Storage) -> Vec<String> {
     let security_types: StdResult<Vec<_>> = SECURITIES_MAP
         .keys(storage, None, None, Order::Ascending)
@@ -21,6 +28,18 @@ pub fn set(storage: &mut dyn Storage, security: &Security) -> Result<(), Contrac
     Ok(SECURITIES_MAP.save(storage, security.name.clone(), security)?)
 }
 
+pub fn has(storage: &dyn Storage, security_name: String) -> bool {
+    SECURITIES_MAP.has(storage, security_name)
+}
+
+pub fn close(storage: &mut dyn Storage, security_name: String) -> Result<(), ContractError> {
+    Ok(CLOSED_SECURITIES.save(storage, security_name, &true)?)
+}
+
+pub fn is_closed(storage: &dyn Storage, security_name: String) -> bool {
+    CLOSED_SECURITIES.has(storage, security_name)
+}
+
 #[cfg(test)]
 mod tests {
     use cosmwasm_std::{Coin, Uint128};
@@ -28,7 +47,7 @@ mod tests {
 
     use crate::{
         core::security::{Security, SecurityType, TrancheSecurity},
-        storage::securities::set,
+        storage::securities::{close, has, is_closed, set},
     };
 
     use super::get;
@@ -46,13 +65,26 @@ mod tests {
         let security = Security {
             name: "Security1".to_string(),
             amount: Uint128::new(100),
-            security_type: SecurityType::Tranche(TrancheSecurity {}),
+            security_type: SecurityType::Tranche(TrancheSecurity::default()),
             minimum_amount: Uint128::new(10),
             price_per_unit: Coin::new(Uint128::new(100), "denom".to_string()),
+            maximum_amount: None,
+            maximum_percentage: None,
         };
         set(deps.as_mut().storage, &security).unwrap();
 
         let obtained = get(deps.as_mut().storage, security.name.clone()).unwrap();
         assert_eq!(security, obtained);
     }
+
+    #[test]
+    fn test_close() {
+        let mut deps = mock_provenance_dependencies();
+        let security_name = "Security1".to_string();
+        assert!(!has(&deps.storage, security_name.clone()));
+        assert!(!is_closed(&deps.storage, security_name.clone()));
+
+        close(deps.as_mut().storage, security_name.clone()).unwrap();
+        assert!(is_closed(&deps.storage, security_name));
+    }
 }
@@ -0,0 +1,44 @@
+use cosmwasm_std::{Addr, Storage, Uint64};
+use cw_storage_plus::Map;
+
+use crate::core::{constants::SETTLEMENT_TIMES_KEY, error::ContractError};
+
+// The time each lp's commitment settled and they received their investment tokens
+pub const SETTLEMENT_TIMES: Map<Addr, Uint64> = Map::new(SETTLEMENT_TIMES_KEY);
+
+pub fn get(storage: &dyn Storage, lp: Addr) -> Option<Uint64> {
+    SETTLEMENT_TIMES.load(storage, lp).ok()
+}
+
+pub fn set(storage: &mut dyn Storage, lp: Addr, time: Uint64) -> Result<(), ContractError> {
+    Ok(SETTLEMENT_TIMES.save(storage, lp, &time)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{Addr, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::storage::settlement_times::{get, set};
+
+    #[test]
+    fn test_get_invalid_settlement_time() {
+        let deps = mock_provenance_dependencies();
+        assert_eq!(None, get(&deps.storage, Addr::unchecked("lp")));
+    }
+
+    #[test]
+    fn test_set_and_get_settlement_time() {
+        let mut deps = mock_provenance_dependencies();
+        set(
+            deps.as_mut().storage,
+            Addr::unchecked("lp"),
+            Uint64::new(100),
+        )
+        .unwrap();
+        assert_eq!(
+            Some(Uint64::new(100)),
+            get(&deps.storage, Addr::unchecked("lp"))
+        );
+    }
+}
@@ -1,15 +1,49 @@
-use cosmwasm_std::{Addr, Storage, Uint64};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Storage, Uint128, Uint64};
 use cw_storage_plus::Item;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::core::{constants::STATE_KEY, error::ContractError};
+use crate::core::{constants::STATE_KEY, error::ContractError, security::MinimumRaise};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct State {
     pub gp: Addr,
     pub capital_denom: String,
     pub settlement_time: Option<Uint64>,
+    pub minimum_raise: Option<MinimumRaise>,
+    #[serde(default)]
+    pub status: OfferingStatus,
+    #[serde(default)]
+    pub maximum_lp_commitment: Option<Uint128>,
+    #[serde(default)]
+    pub lockup_period: Option<Uint64>,
+    #[serde(default)]
+    pub paused: bool, // blocks every message outside of the safe set while set
+    #[serde(default)]
+    pub deposit_mode: DepositMode,
+}
+
+// The offering is open to proposals, then closed to new proposals,
+// and finalized once every commitment has been settled or refunded
+#[cw_serde]
+#[derive(Eq, Default)]
+pub enum OfferingStatus {
+    #[default]
+    Open,
+    Closed,
+    Finalized,
+}
+
+// How lps pay in their capital. Marker capital is pulled from the lp by the contract, which must be
+// able to transfer the capital denom's restricted marker. Funds capital is attached to the deposit.
+// Capital is always refunded or released to the gp through the same path.
+#[cw_serde]
+#[derive(Eq, Default)]
+pub enum DepositMode {
+    #[default]
+    Marker,
+    Funds,
 }
 
 impl State {
@@ -18,6 +52,12 @@ impl State {
             gp,
             capital_denom,
             settlement_time,
+            minimum_raise: None,
+            status: OfferingStatus::Open,
+            maximum_lp_commitment: None,
+            lockup_period: None,
+            paused: false,
+            deposit_mode: DepositMode::Marker,
         }
     }
 }
@@ -1,6 +1,7 @@
 use crate::core::constants::WHITELIST_CONTRIBUTORS;
 use crate::core::error::ContractError;
 use crate::core::security::LoanPoolContributors;
+use crate::util::pagination::paginate;
 use cosmwasm_std::{Addr, Storage};
 use cw_storage_plus::Item;
 
@@ -116,6 +117,39 @@ pub fn get_whitelist_contributors(storage: &dyn Storage) -> Vec<Addr> {
     WHITELIST.load(storage).unwrap_or_else(|_| vec![])
 }
 
+/// Gets a page of the whitelisted contributors, in the order they were added.
+///
+/// # Arguments
+///
+/// * `storage` - A reference to the contract's storage.
+/// * `start_after` - The contributor the page starts after, or `None` for the first page.
+/// * `limit` - The maximum number of contributors in the page.
+///
+/// # Returns
+///
+/// * A `Result` which is:
+///     - `Ok((contributors, next_start_after))` with the contributor the next page starts after, if there is one.
+///     - `Err(ContractError)` on failure.
+pub fn get_whitelist_contributors_page(
+    storage: &dyn Storage,
+    start_after: Option<Addr>,
+    limit: Option<u32>,
+) -> Result<(Vec<Addr>, Option<Addr>), ContractError> {
+    let contributors = get_whitelist_contributors(storage);
+    let start = match start_after {
+        None => 0,
+        Some(start_after) => contributors
+            .iter()
+            .position(|contributor| *contributor == start_after)
+            .map_or(contributors.len(), |position| position + 1),
+    };
+    Ok(paginate(
+        contributors.into_iter().skip(start).map(Ok),
+        limit,
+        Addr::clone,
+    )?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -193,4 +227,29 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_get_whitelist_contributors_page() -> StdResult<()> {
+        let mut storage = MockStorage::new();
+        let contributors = vec![
+            Addr::unchecked("addr3"),
+            Addr::unchecked("addr1"),
+            Addr::unchecked("addr2"),
+        ];
+        save_contributors(&mut storage, contributors).unwrap();
+
+        // Pages follow the order the contributors were added in
+        let (page, next) = get_whitelist_contributors_page(&storage, None, Some(2)).unwrap();
+        assert_eq!(
+            page,
+            vec![Addr::unchecked("addr3"), Addr::unchecked("addr1")]
+        );
+        assert_eq!(next, Some(Addr::unchecked("addr1")));
+
+        let (page, next) = get_whitelist_contributors_page(&storage, next, Some(2)).unwrap();
+        assert_eq!(page, vec![Addr::unchecked("addr2")]);
+        assert_eq!(next, None);
+
+        Ok(())
+    }
 }
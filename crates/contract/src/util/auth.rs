@@ -0,0 +1,138 @@
+use cosmwasm_std::{Addr, Env, Storage};
+
+use crate::{
+    core::{aliases::ProvDeps, error::ContractError},
+    storage::{
+        roles,
+        state::{self, State},
+    },
+};
+
+// The accounts that can send a privileged message. The GP holds every role, while operators
+// can only accept commitments and withdraw capital.
+pub enum Role {
+    Gp,
+    Operator,
+}
+
+/// Checks that the sender holds the role, and returns the contract's state so that the caller
+/// doesn't need to load it again.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage object in the dependencies provided by the cosmwasm framework.
+/// * `sender` A reference to the address of the account that sent the message.
+/// * `role` The role the message requires.
+pub fn authorize(storage: &dyn Storage, sender: &Addr, role: Role) -> Result<State, ContractError> {
+    let state = state::get(storage)?;
+    let authorized = match role {
+        Role::Gp => *sender == state.gp,
+        Role::Operator => *sender == state.gp || roles::is_operator(storage, sender.clone()),
+    };
+    if !authorized {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(state)
+}
+
+/// Checks that the sender is either the GP or the admin of the contract, who can both act in an
+/// emergency. Returns the contract's state so that the caller doesn't need to load it again.
+///
+/// # Parameters
+///
+/// * `deps` A reference to the dependencies provided by the cosmwasm framework.
+/// * `env` A reference to an environment object provided by the cosmwasm framework.
+/// * `sender` A reference to the address of the account that sent the message.
+pub fn authorize_gp_or_admin(
+    deps: &ProvDeps,
+    env: &Env,
+    sender: &Addr,
+) -> Result<State, ContractError> {
+    let state = state::get(deps.storage)?;
+    if *sender == state.gp {
+        return Ok(state);
+    }
+
+    let contract_info = deps
+        .querier
+        .query_wasm_contract_info(env.contract.address.clone())?;
+    if contract_info.admin.as_ref() != Some(sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{testing::mock_env, Addr};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        core::error::ContractError,
+        storage::roles,
+        util::testing::{create_test_state, mock_contract_admin},
+    };
+
+    use super::{authorize, authorize_gp_or_admin, Role};
+
+    #[test]
+    fn test_gp_holds_every_role() {
+        let mut deps = mock_provenance_dependencies();
+        create_test_state(&mut deps, &mock_env(), false);
+        let gp = Addr::unchecked("gp");
+
+        authorize(&deps.storage, &gp, Role::Gp).unwrap();
+        authorize(&deps.storage, &gp, Role::Operator).unwrap();
+    }
+
+    #[test]
+    fn test_operator_cannot_act_as_gp() {
+        let mut deps = mock_provenance_dependencies();
+        create_test_state(&mut deps, &mock_env(), false);
+        let operator = Addr::unchecked("operator");
+        roles::add_operator(deps.as_mut().storage, operator.clone()).unwrap();
+
+        authorize(&deps.storage, &operator, Role::Operator).unwrap();
+        let error = authorize(&deps.storage, &operator, Role::Gp).unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_other_accounts_are_unauthorized() {
+        let mut deps = mock_provenance_dependencies();
+        create_test_state(&mut deps, &mock_env(), false);
+        let lp = Addr::unchecked("lp");
+
+        for role in [Role::Gp, Role::Operator] {
+            let error = authorize(&deps.storage, &lp, role).unwrap_err();
+            assert_eq!(
+                ContractError::Unauthorized {}.to_string(),
+                error.to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn test_gp_or_admin_can_act_in_an_emergency() {
+        let mut deps = mock_provenance_dependencies();
+        let env = mock_env();
+        create_test_state(&mut deps, &env, false);
+        mock_contract_admin(&mut deps, "admin");
+        roles::add_operator(deps.as_mut().storage, Addr::unchecked("operator")).unwrap();
+
+        for sender in ["gp", "admin"] {
+            authorize_gp_or_admin(&deps.as_ref(), &env, &Addr::unchecked(sender)).unwrap();
+        }
+        for sender in ["operator", "lp"] {
+            let error =
+                authorize_gp_or_admin(&deps.as_ref(), &env, &Addr::unchecked(sender)).unwrap_err();
+            assert_eq!(
+                ContractError::Unauthorized {}.to_string(),
+                error.to_string()
+            );
+        }
+    }
+}
@@ -0,0 +1,250 @@
+use cosmwasm_std::{Addr, BankMsg, Coin};
+
+use crate::{
+    core::{aliases::ProvMsg, error::ContractError},
+    storage::state::DepositMode,
+};
+
+use super::provenance_utilities::transfer_marker_coins;
+
+/// Creates the message that moves capital held by the contract to another account. The capital
+/// leaves the contract through the same path that it was deposited with, so marker capital is
+/// transferred by the contract as the marker's administrator, and attached funds are sent back
+/// out with a bank send.
+///
+/// # Parameters
+///
+/// * `deposit_mode` A reference to the deposit mode the offering was instantiated with.
+/// * `capital` The capital being sent. This must not be zero.
+/// * `to` A reference to the address receiving the capital.
+/// * `contract` A reference to the address of this contract.
+pub fn send_capital(
+    deposit_mode: &DepositMode,
+    capital: Coin,
+    to: &Addr,
+    contract: &Addr,
+) -> Result<ProvMsg, ContractError> {
+    match deposit_mode {
+        DepositMode::Marker => Ok(transfer_marker_coins(
+            capital.amount.u128(),
+            capital.denom,
+            to.clone(),
+            contract.clone(),
+            contract.clone(),
+        )?),
+        DepositMode::Funds => Ok(ProvMsg::Bank(BankMsg::Send {
+            to_address: to.to_string(),
+            amount: vec![capital],
+        })),
+    }
+}
+
+/// Creates the messages that move capital from an account into the contract. Capital comes in
+/// through the offering's deposit mode, so marker capital is pulled from the account by the
+/// contract and nothing can be attached, while attached funds must be exactly the capital. No
+/// capital is moved when the capital is zero.
+///
+/// # Parameters
+///
+/// * `deposit_mode` A reference to the deposit mode the offering was instantiated with.
+/// * `attached` A reference to the funds that were attached to the message.
+/// * `capital` The capital being paid into the contract.
+/// * `from` A reference to the address paying the capital.
+/// * `contract` A reference to the address of this contract.
+pub fn receive_capital(
+    deposit_mode: &DepositMode,
+    attached: &[Coin],
+    capital: Coin,
+    from: &Addr,
+    contract: &Addr,
+) -> Result<Vec<ProvMsg>, ContractError> {
+    match deposit_mode {
+        DepositMode::Marker => {
+            if !attached.is_empty() {
+                return Err(ContractError::UnexpectedFunds {});
+            }
+            if capital.amount.is_zero() {
+                return Ok(vec![]);
+            }
+            Ok(vec![transfer_marker_coins(
+                capital.amount.u128(),
+                capital.denom,
+                contract.clone(),
+                from.clone(),
+                contract.clone(),
+            )?])
+        }
+        DepositMode::Funds => {
+            if !funds_match(attached, &[capital]) {
+                return Err(ContractError::FundMismatch {});
+            }
+            Ok(vec![])
+        }
+    }
+}
+
+/// Checks that the funds attached to a deposit are exactly the funds that the deposit costs.
+/// Every coin must be attached in full, and nothing else can be attached.
+///
+/// # Parameters
+///
+/// * `attached` A reference to the funds that were attached to the message.
+/// * `expected` A reference to the funds that the deposit costs.
+pub fn funds_match(attached: &[Coin], expected: &[Coin]) -> bool {
+    let expected: Vec<&Coin> = expected
+        .iter()
+        .filter(|coin| !coin.amount.is_zero())
+        .collect();
+    attached.len() == expected.len() && expected.iter().all(|coin| attached.contains(coin))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{Addr, BankMsg, Coin};
+
+    use crate::{
+        core::{aliases::ProvMsg, error::ContractError},
+        storage::state::DepositMode,
+        util::provenance_utilities::transfer_marker_coins,
+    };
+
+    use super::{funds_match, receive_capital, send_capital};
+
+    #[test]
+    fn test_send_capital_with_marker() {
+        let lp = Addr::unchecked("lp");
+        let contract = Addr::unchecked("contract");
+        let message = send_capital(
+            &DepositMode::Marker,
+            Coin::new(100u128, "denom"),
+            &lp,
+            &contract,
+        )
+        .unwrap();
+        assert_eq!(
+            transfer_marker_coins(100, "denom", lp, contract.clone(), contract).unwrap(),
+            message
+        );
+    }
+
+    #[test]
+    fn test_send_capital_with_funds() {
+        let message = send_capital(
+            &DepositMode::Funds,
+            Coin::new(100u128, "denom"),
+            &Addr::unchecked("lp"),
+            &Addr::unchecked("contract"),
+        )
+        .unwrap();
+        assert_eq!(
+            ProvMsg::Bank(BankMsg::Send {
+                to_address: "lp".to_string(),
+                amount: vec![Coin::new(100u128, "denom")],
+            }),
+            message
+        );
+    }
+
+    #[test]
+    fn test_receive_capital_with_marker() {
+        let gp = Addr::unchecked("gp");
+        let contract = Addr::unchecked("contract");
+        let messages = receive_capital(
+            &DepositMode::Marker,
+            &[],
+            Coin::new(100u128, "denom"),
+            &gp,
+            &contract,
+        )
+        .unwrap();
+        assert_eq!(
+            vec![transfer_marker_coins(
+                100,
+                "denom",
+                contract.clone(),
+                gp.clone(),
+                contract.clone()
+            )
+            .unwrap()],
+            messages
+        );
+
+        let error = receive_capital(
+            &DepositMode::Marker,
+            &[Coin::new(100u128, "denom")],
+            Coin::new(100u128, "denom"),
+            &gp,
+            &contract,
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::UnexpectedFunds {}.to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_receive_capital_with_funds() {
+        let gp = Addr::unchecked("gp");
+        let contract = Addr::unchecked("contract");
+        let messages = receive_capital(
+            &DepositMode::Funds,
+            &[Coin::new(100u128, "denom")],
+            Coin::new(100u128, "denom"),
+            &gp,
+            &contract,
+        )
+        .unwrap();
+        assert!(messages.is_empty());
+
+        for attached in [
+            vec![],
+            vec![Coin::new(99u128, "denom")],
+            vec![Coin::new(100u128, "other")],
+        ] {
+            let error = receive_capital(
+                &DepositMode::Funds,
+                &attached,
+                Coin::new(100u128, "denom"),
+                &gp,
+                &contract,
+            )
+            .unwrap_err();
+            assert_eq!(
+                ContractError::FundMismatch {}.to_string(),
+                error.to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn test_receive_capital_without_capital() {
+        let gp = Addr::unchecked("gp");
+        let contract = Addr::unchecked("contract");
+        for deposit_mode in [DepositMode::Marker, DepositMode::Funds] {
+            let messages = receive_capital(
+                &deposit_mode,
+                &[],
+                Coin::new(0u128, "denom"),
+                &gp,
+                &contract,
+            )
+            .unwrap();
+            assert!(messages.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_funds_match() {
+        let expected = vec![Coin::new(100u128, "denom")];
+        assert!(funds_match(&[Coin::new(100u128, "denom")], &expected));
+        assert!(!funds_match(&[], &expected));
+        assert!(!funds_match(&[Coin::new(99u128, "denom")], &expected));
+        assert!(!funds_match(&[Coin::new(100u128, "other")], &expected));
+        assert!(!funds_match(
+            &[Coin::new(100u128, "denom"), Coin::new(1u128, "other")],
+            &expected
+        ));
+        assert!(funds_match(&[], &[Coin::new(0u128, "denom")]));
+    }
+}
@@ -0,0 +1,322 @@
+use cosmwasm_std::{Addr, Decimal, Storage, Timestamp, Uint128, Uint64};
+
+use crate::{
+    core::{
+        error::ContractError,
+        fee::{FeeBasis, FeeSchedule},
+    },
+    execute::settlement::commitment::CommitmentState,
+    storage::{
+        commits,
+        fees::{self, FeeAccrual},
+        nav, paid_in_capital, securities,
+    },
+};
+
+const SECONDS_PER_YEAR: u64 = 31_536_000;
+
+/// Calculates what a lp's fees will be at the supplied time without saving them. The management
+/// fee accrues pro rata on the lp's basis since the last accrual, and the first accrual only starts
+/// the clock.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage object in the dependencies provided by the cosmwasm framework.
+/// * `schedule` A reference to the fee schedule defined when the contract was instantiated.
+/// * `lp` The address of the lp being charged.
+/// * `time` The time the fees are being accrued to.
+pub fn projected_accrual(
+    storage: &dyn Storage,
+    schedule: &FeeSchedule,
+    lp: Addr,
+    time: Timestamp,
+) -> Result<FeeAccrual, ContractError> {
+    let mut accrual = fees::get_accrual(storage, lp.clone());
+    let now = time.seconds();
+    if !accrual.last_accrual.is_zero() {
+        let elapsed = now.saturating_sub(accrual.last_accrual.u64());
+        let basis = fee_basis(storage, &schedule.management_fee_basis, lp)?;
+        accrual.management_fee += basis
+            .mul_floor(schedule.management_fee_rate)
+            .multiply_ratio(elapsed, SECONDS_PER_YEAR);
+    }
+    accrual.last_accrual = Uint64::new(now.max(accrual.last_accrual.u64()));
+    Ok(accrual)
+}
+
+/// Accrues a lp's management fee up to the supplied time. Does nothing when the offering has no fee schedule.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the storage object in the dependencies provided by the cosmwasm framework.
+/// * `lp` The address of the lp being charged.
+/// * `time` The time the fees are being accrued to.
+pub fn accrue_management_fee(
+    storage: &mut dyn Storage,
+    lp: Addr,
+    time: Timestamp,
+) -> Result<(), ContractError> {
+    if let Some(schedule) = fees::get_schedule(storage) {
+        let accrual = projected_accrual(storage, &schedule, lp.clone(), time)?;
+        fees::set_accrual(storage, lp, &accrual)?;
+    }
+    Ok(())
+}
+
+/// Charges carry on the part of a lp's distributions that exceeds their paid in capital and hurdle.
+/// Does nothing when the offering has no fee schedule.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the storage object in the dependencies provided by the cosmwasm framework.
+/// * `lp` The address of the lp being charged.
+/// * `claimed` The amount of distributions the lp is claiming.
+pub fn accrue_carry(
+    storage: &mut dyn Storage,
+    lp: Addr,
+    claimed: Uint128,
+) -> Result<(), ContractError> {
+    let schedule = match fees::get_schedule(storage) {
+        None => return Ok(()),
+        Some(schedule) => schedule,
+    };
+
+    let mut accrual = fees::get_accrual(storage, lp.clone());
+    let contributed = fee_basis(storage, &FeeBasis::Called, lp.clone())?;
+    let hurdle = contributed.mul_floor(Decimal::one() + schedule.hurdle);
+    let previous_profit = accrual.distributed.saturating_sub(hurdle);
+    accrual.distributed += claimed;
+    let profit = accrual.distributed.saturating_sub(hurdle);
+    accrual.carry += (profit - previous_profit).mul_floor(schedule.carry_rate);
+    fees::set_accrual(storage, lp, &accrual)
+}
+
+/// Deducts as much of a lp's outstanding fees as possible from an amount being paid out. Returns
+/// the fee recipient and the amount deducted, or None when nothing is owed.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the storage object in the dependencies provided by the cosmwasm framework.
+/// * `lp` The address of the lp being charged.
+/// * `available` The amount the fees can be deducted from.
+pub fn deduct(
+    storage: &mut dyn Storage,
+    lp: Addr,
+    available: Uint128,
+) -> Result<Option<(Addr, Uint128)>, ContractError> {
+    let schedule = match fees::get_schedule(storage) {
+        None => return Ok(None),
+        Some(schedule) => schedule,
+    };
+
+    let mut accrual = fees::get_accrual(storage, lp.clone());
+    let fee = accrual.outstanding().min(available);
+    if fee.is_zero() {
+        return Ok(None);
+    }
+    accrual.paid += fee;
+    fees::set_accrual(storage, lp, &accrual)?;
+    Ok(Some((schedule.recipient, fee)))
+}
+
+// The capital the management fee is charged on, where committed capital only counts once it is accepted
+fn fee_basis(storage: &dyn Storage, basis: &FeeBasis, lp: Addr) -> Result<Uint128, ContractError> {
+    let units = match basis {
+        FeeBasis::Called => paid_in_capital::get(storage, lp),
+        FeeBasis::Committed => match commits::get(storage, lp) {
+            Ok(commitment)
+                if commitment.state == CommitmentState::ACCEPTED
+                    || commitment.state == CommitmentState::SETTLED =>
+            {
+                commitment.commitments
+            }
+            _ => vec![],
+        },
+    };
+
+    let mut capital = Uint128::zero();
+    for security in units {
+        let price = nav::current_price(storage, &securities::get(storage, security.name)?)?;
+        capital += security.amount * price.amount;
+    }
+    Ok(capital)
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{testing::mock_env, Addr, Coin, Decimal, Uint128, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::{
+        core::fee::{FeeBasis, FeeSchedule},
+        storage::{
+            fees::{self, FeeAccrual},
+            nav,
+        },
+        util::testing::{create_testing_commitments, instantiate_contract, MockDeps},
+    };
+
+    use super::{accrue_carry, accrue_management_fee, deduct, projected_accrual};
+
+    fn setup_fee_schedule(deps: &mut MockDeps, basis: FeeBasis) -> FeeSchedule {
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(deps);
+        let schedule = FeeSchedule {
+            recipient: Addr::unchecked("manager"),
+            management_fee_rate: Decimal::percent(2),
+            management_fee_basis: basis,
+            carry_rate: Decimal::percent(20),
+            hurdle: Decimal::percent(10),
+        };
+        fees::set_schedule(deps.as_mut().storage, &schedule).unwrap();
+        schedule
+    }
+
+    #[test]
+    fn test_accrue_does_nothing_without_schedule() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        let lp = Addr::unchecked("lp1");
+
+        accrue_management_fee(deps.as_mut().storage, lp.clone(), mock_env().block.time).unwrap();
+        accrue_carry(deps.as_mut().storage, lp.clone(), Uint128::new(100)).unwrap();
+        assert_eq!(
+            FeeAccrual::default(),
+            fees::get_accrual(&deps.storage, lp.clone())
+        );
+        assert_eq!(
+            None,
+            deduct(deps.as_mut().storage, lp, Uint128::new(100)).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_first_accrual_starts_the_clock() {
+        let mut deps = mock_provenance_dependencies();
+        setup_fee_schedule(&mut deps, FeeBasis::Committed);
+        let lp = Addr::unchecked("lp1");
+        let time = mock_env().block.time;
+
+        accrue_management_fee(deps.as_mut().storage, lp.clone(), time).unwrap();
+        let accrual = fees::get_accrual(&deps.storage, lp);
+        assert_eq!(Uint128::zero(), accrual.management_fee);
+        assert_eq!(Uint64::new(time.seconds()), accrual.last_accrual);
+    }
+
+    #[test]
+    fn test_management_fee_accrues_on_committed_capital() {
+        let mut deps = mock_provenance_dependencies();
+        let schedule = setup_fee_schedule(&mut deps, FeeBasis::Committed);
+        let lp = Addr::unchecked("lp2");
+        let time = mock_env().block.time;
+        accrue_management_fee(deps.as_mut().storage, lp.clone(), time).unwrap();
+
+        // lp2 committed 20000 and has paid in half of it
+        let accrual =
+            projected_accrual(&deps.storage, &schedule, lp.clone(), time.plus_days(365)).unwrap();
+        assert_eq!(Uint128::new(400), accrual.management_fee);
+
+        let accrual = projected_accrual(&deps.storage, &schedule, lp, time.plus_days(73)).unwrap();
+        assert_eq!(Uint128::new(80), accrual.management_fee);
+    }
+
+    #[test]
+    fn test_management_fee_basis_uses_latest_nav() {
+        let mut deps = mock_provenance_dependencies();
+        let schedule = setup_fee_schedule(&mut deps, FeeBasis::Committed);
+        let lp = Addr::unchecked("lp2");
+        let time = mock_env().block.time;
+        accrue_management_fee(deps.as_mut().storage, lp.clone(), time).unwrap();
+        nav::add(
+            deps.as_mut().storage,
+            &mock_env(),
+            "Security1".to_string(),
+            Coin::new(150u128, "denom"),
+        )
+        .unwrap();
+
+        // lp2 committed 100 units of each security, and Security1 is now valued at 150 a unit
+        let accrual = projected_accrual(&deps.storage, &schedule, lp, time.plus_days(365)).unwrap();
+        assert_eq!(Uint128::new(500), accrual.management_fee);
+    }
+
+    #[test]
+    fn test_management_fee_accrues_on_called_capital() {
+        let mut deps = mock_provenance_dependencies();
+        let schedule = setup_fee_schedule(&mut deps, FeeBasis::Called);
+        let lp = Addr::unchecked("lp2");
+        let time = mock_env().block.time;
+        accrue_management_fee(deps.as_mut().storage, lp.clone(), time).unwrap();
+
+        let accrual = projected_accrual(&deps.storage, &schedule, lp, time.plus_days(365)).unwrap();
+        assert_eq!(Uint128::new(200), accrual.management_fee);
+    }
+
+    #[test]
+    fn test_management_fee_ignores_pending_commitments() {
+        let mut deps = mock_provenance_dependencies();
+        let schedule = setup_fee_schedule(&mut deps, FeeBasis::Committed);
+        let lp = Addr::unchecked("lp4");
+        let time = mock_env().block.time;
+        accrue_management_fee(deps.as_mut().storage, lp.clone(), time).unwrap();
+
+        let accrual = projected_accrual(&deps.storage, &schedule, lp, time.plus_days(365)).unwrap();
+        assert_eq!(Uint128::zero(), accrual.management_fee);
+    }
+
+    #[test]
+    fn test_carry_is_charged_above_the_hurdle() {
+        let mut deps = mock_provenance_dependencies();
+        setup_fee_schedule(&mut deps, FeeBasis::Committed);
+        let lp = Addr::unchecked("lp1");
+
+        // lp1 paid in 20000, so carry starts once they have received 22000
+        accrue_carry(deps.as_mut().storage, lp.clone(), Uint128::new(21000)).unwrap();
+        assert_eq!(
+            Uint128::zero(),
+            fees::get_accrual(&deps.storage, lp.clone()).carry
+        );
+
+        accrue_carry(deps.as_mut().storage, lp.clone(), Uint128::new(2000)).unwrap();
+        let accrual = fees::get_accrual(&deps.storage, lp.clone());
+        assert_eq!(Uint128::new(200), accrual.carry);
+        assert_eq!(Uint128::new(23000), accrual.distributed);
+
+        accrue_carry(deps.as_mut().storage, lp.clone(), Uint128::new(500)).unwrap();
+        assert_eq!(
+            Uint128::new(300),
+            fees::get_accrual(&deps.storage, lp).carry
+        );
+    }
+
+    #[test]
+    fn test_deduct_is_limited_to_available() {
+        let mut deps = mock_provenance_dependencies();
+        setup_fee_schedule(&mut deps, FeeBasis::Committed);
+        let lp = Addr::unchecked("lp1");
+        fees::set_accrual(
+            deps.as_mut().storage,
+            lp.clone(),
+            &FeeAccrual {
+                management_fee: Uint128::new(150),
+                carry: Uint128::new(50),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            Some((Addr::unchecked("manager"), Uint128::new(120))),
+            deduct(deps.as_mut().storage, lp.clone(), Uint128::new(120)).unwrap()
+        );
+        assert_eq!(
+            Some((Addr::unchecked("manager"), Uint128::new(80))),
+            deduct(deps.as_mut().storage, lp.clone(), Uint128::new(120)).unwrap()
+        );
+        assert_eq!(
+            None,
+            deduct(deps.as_mut().storage, lp.clone(), Uint128::new(120)).unwrap()
+        );
+        assert_eq!(Uint128::new(200), fees::get_accrual(&deps.storage, lp).paid);
+    }
+}
@@ -1,5 +1,9 @@
+pub mod auth;
+pub mod capital;
+pub mod fees;
 #[cfg(test)]
 pub mod mock_marker;
+pub mod pagination;
 pub mod provenance_utilities;
 pub mod settlement;
 #[cfg(test)]
@@ -0,0 +1,61 @@
+use cosmwasm_std::StdResult;
+
+use crate::core::constants::{DEFAULT_QUERY_LIMIT, MAX_QUERY_LIMIT};
+
+/// Collects a single page from an ordered iterator of items. The page holds at most `limit`
+/// items, and a cursor to the last item of the page is returned when there are more to come.
+///
+/// # Parameters
+///
+/// * `items` The items that come after the requested cursor, in order.
+/// * `limit` The requested page size, which defaults to DEFAULT_QUERY_LIMIT and can't exceed MAX_QUERY_LIMIT.
+/// * `cursor` A function returning the key of an item, which the next page will start after.
+pub fn paginate<T, K>(
+    items: impl Iterator<Item = StdResult<T>>,
+    limit: Option<u32>,
+    cursor: fn(&T) -> K,
+) -> StdResult<(Vec<T>, Option<K>)> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let mut page = items.take(limit + 1).collect::<StdResult<Vec<T>>>()?;
+    if page.len() <= limit {
+        return Ok((page, None));
+    }
+
+    page.truncate(limit);
+    let next = page.last().map(cursor);
+    Ok((page, next))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{Addr, StdResult};
+
+    use crate::core::constants::MAX_QUERY_LIMIT;
+
+    use super::paginate;
+
+    fn addresses(count: usize) -> impl Iterator<Item = StdResult<Addr>> {
+        (0..count).map(|i| Ok(Addr::unchecked(format!("lp{}", i))))
+    }
+
+    #[test]
+    fn test_paginate_returns_cursor_when_more_remain() {
+        let (page, next) = paginate(addresses(3), Some(2), Addr::clone).unwrap();
+        assert_eq!(vec![Addr::unchecked("lp0"), Addr::unchecked("lp1")], page);
+        assert_eq!(Some(Addr::unchecked("lp1")), next);
+    }
+
+    #[test]
+    fn test_paginate_last_page_has_no_cursor() {
+        let (page, next) = paginate(addresses(2), Some(2), Addr::clone).unwrap();
+        assert_eq!(2, page.len());
+        assert_eq!(None, next);
+    }
+
+    #[test]
+    fn test_paginate_caps_limit() {
+        let (page, next) = paginate(addresses(100), Some(1000), Addr::clone).unwrap();
+        assert_eq!(MAX_QUERY_LIMIT as usize, page.len());
+        assert!(next.is_some());
+    }
+}
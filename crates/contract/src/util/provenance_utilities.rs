@@ -9,9 +9,9 @@ use provwasm_std::types::cosmos::auth::v1beta1::BaseAccount;
 use provwasm_std::types::cosmos::base::v1beta1::Coin;
 use provwasm_std::types::provenance::marker::v1::{
     Access, AccessGrant, MarkerAccount, MarkerQuerier, MarkerStatus, MarkerType,
-    MsgActivateRequest, MsgAddAccessRequest, MsgAddMarkerRequest, MsgDeleteAccessRequest,
-    MsgFinalizeRequest, MsgMintRequest, MsgTransferRequest, MsgWithdrawRequest,
-    QueryHoldingRequest, QueryHoldingResponse,
+    MsgActivateRequest, MsgAddAccessRequest, MsgAddMarkerRequest, MsgBurnRequest,
+    MsgDeleteAccessRequest, MsgFinalizeRequest, MsgMintRequest, MsgTransferRequest,
+    MsgWithdrawRequest, QueryHoldingRequest, QueryHoldingResponse,
 };
 use provwasm_std::types::provenance::msgfees::v1::MsgAssessCustomMsgFeeRequest;
 use result_extensions::ResultExtensions;
@@ -277,6 +277,26 @@ pub fn mint_marker_supply<S: Into<String>>(
     .into())
 }
 
+pub fn burn_marker_supply<S: Into<String>>(
+    amount: u128,
+    denom: S,
+    contract_address: Addr,
+) -> StdResult<CosmosMsg> {
+    if amount == 0 {
+        return Err(StdError::generic_err("burn amount must be > 0"));
+    }
+    let coin = Coin {
+        denom: validate_string(denom, "denom")?,
+        amount: amount.to_string(),
+    };
+
+    Ok(MsgBurnRequest {
+        amount: Some(coin),
+        administrator: validate_address(contract_address)?.to_string(),
+    }
+    .into())
+}
+
 pub fn withdraw_coins<S: Into<String>, H: Into<Addr>>(
     marker_denom: S,
     amount: u128,
@@ -1,9 +1,17 @@
-use cosmwasm_std::{Env, Storage, Timestamp};
+use cosmwasm_std::{Addr, Env, Storage, Timestamp, Uint128};
+use provwasm_std::types::provenance::attribute::v1::AttributeQuerier;
 
 use crate::{
-    core::error::ContractError,
+    core::{
+        aliases::ProvDeps,
+        error::ContractError,
+        security::{MinimumRaise, SecurityCommitment},
+    },
     execute::settlement::commitment::{Commitment, CommitmentState},
-    storage::{self, paid_in_capital},
+    storage::{
+        self, available_capital, capital_calls, eligibility, nav, offering_totals, paid_in_capital,
+        securities,
+    },
 };
 
 /// Checks if the defined settlment_date within the commitment has expired. A settlement_date
@@ -58,17 +66,171 @@ pub fn is_settling(storage: &dyn Storage, commitment: &Commitment) -> bool {
     paid_in_capital == commitment.commitments && commitment.state == CommitmentState::ACCEPTED
 }
 
+/// Checks if the commitment has met its outstanding capital call. A funded capital call is one where
+/// the commitment has been accepted, the paid_in_capital matches the called capital, and the deposited
+/// capital has not been released to the GP.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage object in the dependencies provided by the cosmwasm framework.
+/// * `commitment` A reference to the commitment to check.
+pub fn is_call_funded(storage: &dyn Storage, commitment: &Commitment) -> bool {
+    let capital_call = match capital_calls::get(storage, commitment.lp.clone()) {
+        None => return false,
+        Some(capital_call) => capital_call,
+    };
+    let paid_in_capital = paid_in_capital::get(storage, commitment.lp.clone());
+    paid_in_capital == capital_call.securities
+        && commitment.state == CommitmentState::ACCEPTED
+        && available_capital::has_lp(storage, commitment.lp.clone())
+}
+
+/// Checks if the lp is eligible to invest in the security. A lp is eligible when the security has no
+/// required attributes, when the GP has allowlisted them, or when their account holds every one of
+/// the security's required attributes.
+///
+/// # Parameters
+///
+/// * `deps` A reference to the dependencies provided by the cosmwasm framework.
+/// * `lp` A reference to the address of the lp.
+/// * `security` The name of the security the lp is investing in.
+pub fn is_eligible(deps: &ProvDeps, lp: &Addr, security: String) -> Result<bool, ContractError> {
+    let required_attributes = eligibility::get_required_attributes(deps.storage, security);
+    if required_attributes.is_empty() || eligibility::is_allowlisted(deps.storage, lp.clone()) {
+        return Ok(true);
+    }
+
+    let querier = AttributeQuerier::new(&deps.querier);
+    for name in required_attributes {
+        let response = querier.attribute(lp.to_string(), name.clone(), None)?;
+        if !response
+            .attributes
+            .iter()
+            .any(|attribute| attribute.name == name)
+        {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// Checks that a lp's commitments stay within each security's maximum amount and maximum percentage,
+/// and that their combined capital stays within the offering's maximum commitment for a single lp.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage object in the dependencies provided by the cosmwasm framework.
+/// * `maximum_lp_commitment` A reference to the maximum lp commitment defined when the contract was instantiated.
+/// * `commitments` The lp's commitment to each security.
+pub fn check_allocation_limits(
+    storage: &dyn Storage,
+    maximum_lp_commitment: &Option<Uint128>,
+    commitments: &[SecurityCommitment],
+) -> Result<(), ContractError> {
+    let exceeded = |security: &str, limit: &str| ContractError::AllocationLimitExceeded {
+        security: security.to_string(),
+        limit: limit.to_string(),
+    };
+
+    let mut committed_capital = Uint128::zero();
+    for commitment in commitments {
+        let security = securities::get(storage, commitment.name.clone())?;
+        if let Some(maximum_amount) = security.maximum_amount {
+            if commitment.amount > maximum_amount {
+                return Err(exceeded(&security.name, "maximum amount"));
+            }
+        }
+        if let Some(maximum_percentage) = security.maximum_percentage {
+            if commitment.amount > security.amount.mul_floor(maximum_percentage) {
+                return Err(exceeded(&security.name, "maximum percentage"));
+            }
+        }
+
+        committed_capital += commitment.amount * nav::current_price(storage, &security)?.amount;
+        if let Some(maximum_lp_commitment) = maximum_lp_commitment {
+            if committed_capital > *maximum_lp_commitment {
+                return Err(exceeded(&security.name, "maximum lp commitment"));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Checks if the capital paid in across every accepted and settled commitment satisfies the
+/// offering's minimum raise. An offering without a minimum raise is always met.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage object in the dependencies provided by the cosmwasm framework.
+/// * `minimum_raise` A reference to the minimum raise defined when the contract was instantiated.
+pub fn minimum_raise_met(
+    storage: &dyn Storage,
+    minimum_raise: &Option<MinimumRaise>,
+) -> Result<bool, ContractError> {
+    let minimum_raise = match minimum_raise {
+        None => return Ok(true),
+        Some(minimum_raise) => minimum_raise,
+    };
+
+    let paid_in = paid_in_totals(storage)?;
+    match minimum_raise {
+        MinimumRaise::Securities(minimums) => Ok(minimums.iter().all(|minimum| {
+            paid_in
+                .iter()
+                .find(|security| security.name == minimum.name)
+                .map_or(Uint128::zero(), |security| security.amount)
+                >= minimum.amount
+        })),
+        MinimumRaise::Offering(amount) => {
+            let mut raised = Uint128::zero();
+            for security in &paid_in {
+                let price =
+                    nav::current_price(storage, &securities::get(storage, security.name.clone())?)?;
+                raised += security.amount * price.amount;
+            }
+            Ok(raised >= *amount)
+        }
+    }
+}
+
+// The paid in capital of every security, from the running offering totals
+pub fn paid_in_totals(storage: &dyn Storage) -> Result<Vec<SecurityCommitment>, ContractError> {
+    securities::get_security_types(storage)
+        .into_iter()
+        .map(|name| {
+            Ok(SecurityCommitment {
+                amount: offering_totals::get(storage, name.clone())?.paid_in,
+                name,
+            })
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::{testing::mock_env, Addr, Uint64};
+    use cosmwasm_std::{testing::mock_env, Addr, Coin, Decimal, Uint128, Uint64};
     use provwasm_mocks::mock_provenance_dependencies;
 
     use crate::{
+        core::{
+            error::ContractError,
+            security::{MinimumRaise, SecurityCommitment},
+        },
         execute::settlement::commitment::{Commitment, CommitmentState},
-        storage::paid_in_capital,
+        storage::{
+            available_capital,
+            capital_calls::{self, CapitalCall},
+            nav, paid_in_capital, securities,
+        },
         util::{
-            settlement::{is_expired, is_settling, timestamp_is_expired},
-            testing::{create_test_state, SettlementTester},
+            settlement::{
+                check_allocation_limits, is_call_funded, is_expired, is_settling,
+                minimum_raise_met, timestamp_is_expired,
+            },
+            testing::{
+                create_test_state, create_testing_commitments, instantiate_contract,
+                SettlementTester,
+            },
         },
     };
 
@@ -187,4 +349,236 @@ mod tests {
         let settling = is_settling(&deps.storage, &commitment);
         assert_eq!(false, settling);
     }
+
+    #[test]
+    fn test_is_call_funded_fails_without_capital_call() {
+        let mut deps = mock_provenance_dependencies();
+        let mut settlement_tester = SettlementTester::new();
+        settlement_tester.create_security_commitments(1);
+        let lp = Addr::unchecked("lp");
+        let mut commitment =
+            Commitment::new(lp.clone(), settlement_tester.security_commitments.clone());
+        commitment.state = CommitmentState::ACCEPTED;
+        paid_in_capital::set(
+            deps.as_mut().storage,
+            lp.clone(),
+            &settlement_tester.security_commitments,
+        )
+        .unwrap();
+        assert_eq!(false, is_call_funded(&deps.storage, &commitment));
+    }
+
+    #[test]
+    fn test_is_call_funded_success() {
+        let mut deps = mock_provenance_dependencies();
+        let mut settlement_tester = SettlementTester::new();
+        settlement_tester.create_security_commitments(1);
+        let lp = Addr::unchecked("lp");
+        let mut commitment =
+            Commitment::new(lp.clone(), settlement_tester.security_commitments.clone());
+        commitment.state = CommitmentState::ACCEPTED;
+
+        let mut called = settlement_tester.security_commitments.clone();
+        called[0].amount = Uint128::new(5);
+        capital_calls::set(
+            deps.as_mut().storage,
+            lp.clone(),
+            &CapitalCall {
+                securities: called.clone(),
+                due_date: None,
+                released: false,
+            },
+        )
+        .unwrap();
+        paid_in_capital::set(deps.as_mut().storage, lp.clone(), &called).unwrap();
+        assert_eq!(false, is_call_funded(&deps.storage, &commitment));
+
+        available_capital::add_capital(
+            deps.as_mut().storage,
+            lp.clone(),
+            vec![Coin::new(Uint128::new(5), "denom")],
+        )
+        .unwrap();
+        assert_eq!(true, is_call_funded(&deps.storage, &commitment));
+    }
+
+    #[test]
+    fn test_is_call_funded_fails_on_missing_capital() {
+        let mut deps = mock_provenance_dependencies();
+        let mut settlement_tester = SettlementTester::new();
+        settlement_tester.create_security_commitments(1);
+        let lp = Addr::unchecked("lp");
+        let mut commitment =
+            Commitment::new(lp.clone(), settlement_tester.security_commitments.clone());
+        commitment.state = CommitmentState::ACCEPTED;
+
+        capital_calls::set(
+            deps.as_mut().storage,
+            lp.clone(),
+            &CapitalCall {
+                securities: settlement_tester.security_commitments.clone(),
+                due_date: None,
+                released: false,
+            },
+        )
+        .unwrap();
+        available_capital::add_capital(
+            deps.as_mut().storage,
+            lp.clone(),
+            vec![Coin::new(Uint128::new(5), "denom")],
+        )
+        .unwrap();
+        let mut paid = commitment.clone();
+        paid.clear_amounts();
+        paid_in_capital::set(deps.as_mut().storage, lp.clone(), &paid.commitments).unwrap();
+        assert_eq!(false, is_call_funded(&deps.storage, &commitment));
+    }
+
+    #[test]
+    fn test_minimum_raise_met_without_minimum_raise() {
+        let deps = mock_provenance_dependencies();
+        assert!(minimum_raise_met(&deps.storage, &None).unwrap());
+    }
+
+    #[test]
+    fn test_minimum_raise_met_for_offering() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+
+        // lp1 and lp7 paid in 20000 each, and lp2 paid in 10000
+        let met = Some(MinimumRaise::Offering(Uint128::new(50000)));
+        assert!(minimum_raise_met(&deps.storage, &met).unwrap());
+        let unmet = Some(MinimumRaise::Offering(Uint128::new(50001)));
+        assert!(!minimum_raise_met(&deps.storage, &unmet).unwrap());
+    }
+
+    #[test]
+    fn test_minimum_raise_met_for_offering_uses_latest_nav() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+        nav::add(
+            deps.as_mut().storage,
+            &mock_env(),
+            "Security1".to_string(),
+            Coin::new(200u128, "denom"),
+        )
+        .unwrap();
+
+        // The 250 units of Security1 that were paid in are now worth twice as much
+        let met = Some(MinimumRaise::Offering(Uint128::new(75000)));
+        assert!(minimum_raise_met(&deps.storage, &met).unwrap());
+        let unmet = Some(MinimumRaise::Offering(Uint128::new(75001)));
+        assert!(!minimum_raise_met(&deps.storage, &unmet).unwrap());
+    }
+
+    #[test]
+    fn test_minimum_raise_met_for_securities() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        create_testing_commitments(&mut deps);
+
+        let met = Some(MinimumRaise::Securities(vec![SecurityCommitment {
+            name: "Security1".to_string(),
+            amount: Uint128::new(250),
+        }]));
+        assert!(minimum_raise_met(&deps.storage, &met).unwrap());
+        let unmet = Some(MinimumRaise::Securities(vec![
+            SecurityCommitment {
+                name: "Security1".to_string(),
+                amount: Uint128::new(250),
+            },
+            SecurityCommitment {
+                name: "Security2".to_string(),
+                amount: Uint128::new(251),
+            },
+        ]));
+        assert!(!minimum_raise_met(&deps.storage, &unmet).unwrap());
+    }
+
+    #[test]
+    fn test_check_allocation_limits() {
+        let mut deps = mock_provenance_dependencies();
+        instantiate_contract(deps.as_mut()).expect("should be able to instantiate contract");
+        let mut security = securities::get(&deps.storage, "Security1".to_string()).unwrap();
+        security.maximum_amount = Some(Uint128::new(200));
+        security.maximum_percentage = Some(Decimal::percent(15));
+        securities::set(deps.as_mut().storage, &security).unwrap();
+        let commitments = |security1: u128, security2: u128| {
+            vec![
+                SecurityCommitment {
+                    name: "Security1".to_string(),
+                    amount: Uint128::new(security1),
+                },
+                SecurityCommitment {
+                    name: "Security2".to_string(),
+                    amount: Uint128::new(security2),
+                },
+            ]
+        };
+        let exceeded = |security: &str, limit: &str| {
+            ContractError::AllocationLimitExceeded {
+                security: security.to_string(),
+                limit: limit.to_string(),
+            }
+            .to_string()
+        };
+
+        check_allocation_limits(&deps.storage, &None, &commitments(150, 500)).unwrap();
+        check_allocation_limits(
+            &deps.storage,
+            &Some(Uint128::new(65000)),
+            &commitments(150, 500),
+        )
+        .unwrap();
+
+        let error =
+            check_allocation_limits(&deps.storage, &None, &commitments(151, 500)).unwrap_err();
+        assert_eq!(
+            exceeded("Security1", "maximum percentage"),
+            error.to_string()
+        );
+
+        security.maximum_amount = Some(Uint128::new(100));
+        securities::set(deps.as_mut().storage, &security).unwrap();
+        let error =
+            check_allocation_limits(&deps.storage, &None, &commitments(101, 500)).unwrap_err();
+        assert_eq!(exceeded("Security1", "maximum amount"), error.to_string());
+
+        let error = check_allocation_limits(
+            &deps.storage,
+            &Some(Uint128::new(59999)),
+            &commitments(100, 500),
+        )
+        .unwrap_err();
+        assert_eq!(
+            exceeded("Security2", "maximum lp commitment"),
+            error.to_string()
+        );
+
+        nav::add(
+            deps.as_mut().storage,
+            &mock_env(),
+            "Security1".to_string(),
+            Coin::new(150u128, "denom"),
+        )
+        .unwrap();
+        check_allocation_limits(
+            &deps.storage,
+            &Some(Uint128::new(65000)),
+            &commitments(100, 500),
+        )
+        .unwrap();
+        let error = check_allocation_limits(
+            &deps.storage,
+            &Some(Uint128::new(64999)),
+            &commitments(100, 500),
+        )
+        .unwrap_err();
+        assert_eq!(
+            exceeded("Security2", "maximum lp commitment"),
+            error.to_string()
+        );
+    }
 }
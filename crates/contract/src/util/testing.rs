@@ -1,21 +1,31 @@
 use cosmwasm_std::testing::mock_info;
 use cosmwasm_std::{
     testing::{message_info, mock_env, MockApi, MockStorage},
-    Addr, Coin, Env, OwnedDeps, Storage, Uint128, Uint64,
+    to_json_binary, Addr, Binary, Coin, ContractInfoResponse, ContractResult, Decimal, Env,
+    OwnedDeps, Storage, SystemResult, Uint128, Uint64, WasmQuery,
 };
 use provwasm_mocks::MockProvenanceQuerier;
+use provwasm_std::shim::Any;
+use provwasm_std::types::provenance::attribute::v1::{
+    Attribute, AttributeType, QueryAttributeRequest, QueryAttributeResponse,
+};
+use provwasm_std::types::provenance::marker::v1::{QueryMarkerRequest, QueryMarkerResponse};
 
 use crate::{
     contract::{execute, instantiate},
     core::{
         aliases::{ProvDepsMut, ProvTxResponse},
         msg::{ExecuteMsg, InstantiateMsg},
-        security::{AcceptedCommitment, FundSecurity, Security, SecurityCommitment},
+        security::{
+            AcceptedCommitment, CapitalCallAmount, FundSecurity, RedemptionTerms, Security,
+            SecurityCommitment,
+        },
     },
     storage::{
         self,
-        state::{self, State},
+        state::{self, DepositMode, State},
     },
+    util::mock_marker::MockMarker,
 };
 
 pub struct SettlementTester {
@@ -32,15 +42,20 @@ impl SettlementTester {
     pub fn setup_test_state(&self, storage: &mut dyn Storage) {
         state::set(
             storage,
-            &State {
-                gp: Addr::unchecked("gp"),
-                capital_denom: "denom".to_string(),
-                settlement_time: None,
-            },
+            &State::new(Addr::unchecked("gp"), "denom".to_string(), None),
         )
         .unwrap();
     }
 
+    // Stores a security for each of the security commitments
+    pub fn setup_test_securities(&self, storage: &mut dyn Storage) {
+        for commitment in &self.security_commitments {
+            let mut security = create_test_securities()[0].clone();
+            security.name = commitment.name.clone();
+            storage::securities::set(storage, &security).unwrap();
+        }
+    }
+
     pub fn create_security_commitments(&mut self, amount: u32) {
         for _ in 0..amount {
             self.security_commitments.push(SecurityCommitment {
@@ -62,16 +77,20 @@ pub fn create_test_securities() -> Vec<Security> {
         Security {
             name: "Security1".to_string(),
             amount: Uint128::new(1000),
-            security_type: crate::core::security::SecurityType::Fund(FundSecurity {}),
+            security_type: crate::core::security::SecurityType::Fund(FundSecurity::default()),
             minimum_amount: Uint128::new(10),
             price_per_unit: Coin::new(Uint128::new(100), "denom".to_string()),
+            maximum_amount: None,
+            maximum_percentage: None,
         },
         Security {
             name: "Security2".to_string(),
             amount: Uint128::new(1000),
-            security_type: crate::core::security::SecurityType::Fund(FundSecurity {}),
+            security_type: crate::core::security::SecurityType::Fund(FundSecurity::default()),
             minimum_amount: Uint128::new(10),
             price_per_unit: Coin::new(Uint128::new(100), "denom".to_string()),
+            maximum_amount: None,
+            maximum_percentage: None,
         },
     ]
 }
@@ -82,7 +101,12 @@ pub fn test_init_message() -> InstantiateMsg {
         securities: create_test_securities(),
         capital_denom: "denom".to_string(),
         settlement_time: None,
+        minimum_raise: None,
         fee: None,
+        maximum_lp_commitment: None,
+        lockup_period: None,
+        fee_schedule: None,
+        deposit_mode: None,
     }
 }
 
@@ -146,6 +170,241 @@ pub fn cancel_test(deps: ProvDepsMut, env: Env, sender: &str, lp: &str) -> ProvT
     execute(deps, env, info, msg)
 }
 
+pub fn reject_test(deps: ProvDepsMut, env: Env, sender: &str, lp: &str) -> ProvTxResponse {
+    let info = message_info(&Addr::unchecked(sender), &[]);
+    let msg = ExecuteMsg::RejectCommitment {
+        lp: Addr::unchecked(lp),
+        reason: "rejected".to_string(),
+    };
+    execute(deps, env, info, msg)
+}
+
+pub fn claim_refund_test(deps: ProvDepsMut, env: Env, sender: &str, lp: &str) -> ProvTxResponse {
+    let info = message_info(&Addr::unchecked(sender), &[]);
+    let msg = ExecuteMsg::ClaimRefund {
+        lp: Addr::unchecked(lp),
+    };
+    execute(deps, env, info, msg)
+}
+
+pub fn reclaim_expired_deposit_test(deps: ProvDepsMut, env: Env, sender: &str) -> ProvTxResponse {
+    let info = message_info(&Addr::unchecked(sender), &[]);
+    let msg = ExecuteMsg::ReclaimExpiredDeposit {};
+    execute(deps, env, info, msg)
+}
+
+pub fn distribute_test(
+    deps: ProvDepsMut,
+    env: Env,
+    sender: &str,
+    amount: Uint128,
+) -> ProvTxResponse {
+    let info = message_info(&Addr::unchecked(sender), &[]);
+    let msg = ExecuteMsg::Distribute {
+        security: "Security1".to_string(),
+        amount,
+    };
+    execute(deps, env, info, msg)
+}
+
+pub fn claim_distributions_test(deps: ProvDepsMut, env: Env, sender: &str) -> ProvTxResponse {
+    let info = message_info(&Addr::unchecked(sender), &[]);
+    let msg = ExecuteMsg::ClaimDistributions {};
+    execute(deps, env, info, msg)
+}
+
+pub fn open_redemption_window_test(
+    deps: ProvDepsMut,
+    env: Env,
+    sender: &str,
+    gate: Decimal,
+) -> ProvTxResponse {
+    let info = message_info(&Addr::unchecked(sender), &[]);
+    let msg = ExecuteMsg::OpenRedemptionWindow {
+        security: "Security1".to_string(),
+        terms: RedemptionTerms {
+            price_per_unit: Uint128::new(100),
+            start_time: Uint64::new(env.block.time.seconds()),
+            end_time: Uint64::new(env.block.time.seconds()),
+            gate,
+        },
+    };
+    execute(deps, env, info, msg)
+}
+
+pub fn redeem_test(deps: ProvDepsMut, env: Env, sender: &str, amount: Uint128) -> ProvTxResponse {
+    let info = message_info(&Addr::unchecked(sender), &[]);
+    let msg = ExecuteMsg::Redeem {
+        security: "Security1".to_string(),
+        amount,
+    };
+    execute(deps, env, info, msg)
+}
+
+pub fn process_redemption_queue_test(deps: ProvDepsMut, env: Env, sender: &str) -> ProvTxResponse {
+    let info = message_info(&Addr::unchecked(sender), &[]);
+    let msg = ExecuteMsg::ProcessRedemptionQueue {
+        security: "Security1".to_string(),
+        limit: None,
+    };
+    execute(deps, env, info, msg)
+}
+
+pub fn update_nav_test(deps: ProvDepsMut, env: Env, sender: &str, nav: Uint128) -> ProvTxResponse {
+    let info = message_info(&Addr::unchecked(sender), &[]);
+    let msg = ExecuteMsg::UpdateNav {
+        security: "Security1".to_string(),
+        nav_per_unit: Coin::new(nav, "denom"),
+    };
+    execute(deps, env, info, msg)
+}
+
+pub fn add_security_test(deps: ProvDepsMut, env: Env, sender: &str) -> ProvTxResponse {
+    let info = message_info(&Addr::unchecked(sender), &[]);
+    let mut security = create_test_securities()[0].clone();
+    security.name = "Security3".to_string();
+    let msg = ExecuteMsg::AddSecurity { security };
+    execute(deps, env, info, msg)
+}
+
+pub fn resize_security_test(
+    deps: ProvDepsMut,
+    env: Env,
+    sender: &str,
+    amount: Uint128,
+) -> ProvTxResponse {
+    let info = message_info(&Addr::unchecked(sender), &[]);
+    let msg = ExecuteMsg::ResizeSecurity {
+        security: "Security1".to_string(),
+        amount,
+    };
+    execute(deps, env, info, msg)
+}
+
+pub fn close_security_test(deps: ProvDepsMut, env: Env, sender: &str) -> ProvTxResponse {
+    let info = message_info(&Addr::unchecked(sender), &[]);
+    let msg = ExecuteMsg::CloseSecurity {
+        security: "Security1".to_string(),
+    };
+    execute(deps, env, info, msg)
+}
+
+pub fn close_offering_test(deps: ProvDepsMut, env: Env, sender: &str) -> ProvTxResponse {
+    let info = message_info(&Addr::unchecked(sender), &[]);
+    let msg = ExecuteMsg::CloseOffering {};
+    execute(deps, env, info, msg)
+}
+
+pub fn finalize_offering_test(deps: ProvDepsMut, env: Env, sender: &str) -> ProvTxResponse {
+    let info = message_info(&Addr::unchecked(sender), &[]);
+    let msg = ExecuteMsg::FinalizeOffering {};
+    execute(deps, env, info, msg)
+}
+
+pub fn update_eligibility_test(
+    deps: ProvDepsMut,
+    env: Env,
+    sender: &str,
+    required_attributes: &[&str],
+) -> ProvTxResponse {
+    let info = message_info(&Addr::unchecked(sender), &[]);
+    let msg = ExecuteMsg::UpdateEligibility {
+        security: "Security1".to_string(),
+        required_attributes: required_attributes
+            .iter()
+            .map(|name| name.to_string())
+            .collect(),
+    };
+    execute(deps, env, info, msg)
+}
+
+pub fn add_to_allowlist_test(
+    deps: ProvDepsMut,
+    env: Env,
+    sender: &str,
+    lp: &str,
+) -> ProvTxResponse {
+    let info = message_info(&Addr::unchecked(sender), &[]);
+    let msg = ExecuteMsg::AddToAllowlist {
+        lps: vec![Addr::unchecked(lp)],
+    };
+    execute(deps, env, info, msg)
+}
+
+pub fn remove_from_allowlist_test(
+    deps: ProvDepsMut,
+    env: Env,
+    sender: &str,
+    lp: &str,
+) -> ProvTxResponse {
+    let info = message_info(&Addr::unchecked(sender), &[]);
+    let msg = ExecuteMsg::RemoveFromAllowlist {
+        lps: vec![Addr::unchecked(lp)],
+    };
+    execute(deps, env, info, msg)
+}
+
+pub fn transfer_investment_test(
+    deps: ProvDepsMut,
+    env: Env,
+    sender: &str,
+    to: &str,
+    amount: Uint128,
+) -> ProvTxResponse {
+    let info = message_info(&Addr::unchecked(sender), &[]);
+    let msg = ExecuteMsg::TransferInvestment {
+        security: "Security1".to_string(),
+        to: Addr::unchecked(to),
+        amount,
+    };
+    execute(deps, env, info, msg)
+}
+
+pub fn propose_gp_transfer_test(
+    deps: ProvDepsMut,
+    env: Env,
+    sender: &str,
+    gp: &str,
+) -> ProvTxResponse {
+    let info = message_info(&Addr::unchecked(sender), &[]);
+    let msg = ExecuteMsg::ProposeGpTransfer {
+        gp: Addr::unchecked(gp),
+    };
+    execute(deps, env, info, msg)
+}
+
+pub fn accept_gp_transfer_test(deps: ProvDepsMut, env: Env, sender: &str) -> ProvTxResponse {
+    let info = message_info(&Addr::unchecked(sender), &[]);
+    let msg = ExecuteMsg::AcceptGpTransfer {};
+    execute(deps, env, info, msg)
+}
+
+pub fn add_operator_test(
+    deps: ProvDepsMut,
+    env: Env,
+    sender: &str,
+    operator: &str,
+) -> ProvTxResponse {
+    let info = message_info(&Addr::unchecked(sender), &[]);
+    let msg = ExecuteMsg::AddOperator {
+        operator: Addr::unchecked(operator),
+    };
+    execute(deps, env, info, msg)
+}
+
+pub fn remove_operator_test(
+    deps: ProvDepsMut,
+    env: Env,
+    sender: &str,
+    operator: &str,
+) -> ProvTxResponse {
+    let info = message_info(&Addr::unchecked(sender), &[]);
+    let msg = ExecuteMsg::RemoveOperator {
+        operator: Addr::unchecked(operator),
+    };
+    execute(deps, env, info, msg)
+}
+
 pub fn accept_test_commitment(
     deps: ProvDepsMut,
     env: Env,
@@ -157,6 +416,48 @@ pub fn accept_test_commitment(
     execute(deps, env, info, msg)
 }
 
+pub fn test_counter_propose_message(lp: &str) -> ExecuteMsg {
+    ExecuteMsg::CounterProposeCommitment {
+        lp: Addr::unchecked(lp),
+        securities: test_security_commitments(),
+    }
+}
+
+pub fn counter_propose_test_commitment(
+    deps: ProvDepsMut,
+    env: Env,
+    sender: &str,
+    lp: &str,
+) -> ProvTxResponse {
+    let info = message_info(&Addr::unchecked(sender), &[]);
+    let msg = test_counter_propose_message(lp);
+    execute(deps, env, info, msg)
+}
+
+pub fn accept_counter_proposal_test(deps: ProvDepsMut, env: Env, sender: &str) -> ProvTxResponse {
+    let info = message_info(&Addr::unchecked(sender), &[]);
+    let msg = ExecuteMsg::AcceptCounterProposal {};
+    execute(deps, env, info, msg)
+}
+
+pub fn test_issue_capital_call_message(percentage: Decimal) -> ExecuteMsg {
+    ExecuteMsg::IssueCapitalCall {
+        call: CapitalCallAmount::Percentage(percentage),
+        due_date: None,
+    }
+}
+
+pub fn issue_capital_call_test(
+    deps: ProvDepsMut,
+    env: Env,
+    sender: &str,
+    percentage: Decimal,
+) -> ProvTxResponse {
+    let info = message_info(&Addr::unchecked(sender), &[]);
+    let msg = test_issue_capital_call_message(percentage);
+    execute(deps, env, info, msg)
+}
+
 pub fn test_deposit_message(deposit: &[SecurityCommitment]) -> ExecuteMsg {
     ExecuteMsg::DepositCommitment {
         securities: deposit.to_vec(),
@@ -175,6 +476,24 @@ pub fn deposit_test(
     execute(deps, env, info, msg)
 }
 
+pub fn deposit_funds_test(
+    deps: ProvDepsMut,
+    env: Env,
+    sender: &str,
+    deposit: &[SecurityCommitment],
+    funds: &[Coin],
+) -> ProvTxResponse {
+    let info = message_info(&Addr::unchecked(sender), funds);
+    let msg = test_deposit_message(deposit);
+    execute(deps, env, info, msg)
+}
+
+pub fn set_deposit_mode(storage: &mut dyn Storage, deposit_mode: DepositMode) {
+    let mut state = state::get(storage).unwrap();
+    state.deposit_mode = deposit_mode;
+    state::set(storage, &state).unwrap();
+}
+
 pub fn withdraw_test(deps: ProvDepsMut, env: Env, sender: &str, lp: &str) -> ProvTxResponse {
     let info = mock_info(sender, &[]);
     let msg = test_withdraw_message(lp);
@@ -211,6 +530,88 @@ pub fn test_update_settlement_time_message() -> ExecuteMsg {
     }
 }
 
+// Answers marker queries with a marker for the requested denom
+pub fn mock_investment_markers(deps: &mut MockDeps) {
+    let cb = Box::new(|bin: &Binary| -> SystemResult<ContractResult<Binary>> {
+        let message = QueryMarkerRequest::try_from(bin.clone()).unwrap();
+        let marker = MockMarker::new(false, message.id).to_marker_account();
+        let response = QueryMarkerResponse {
+            marker: Some(Any {
+                type_url: "/provenance.marker.v1.MarkerAccount".to_string(),
+                value: marker.to_proto_bytes(),
+            }),
+        };
+        SystemResult::Ok(ContractResult::Ok(to_json_binary(&response).unwrap()))
+    });
+    deps.querier
+        .registered_custom_queries
+        .insert("/provenance.marker.v1.Query/Marker".to_string(), cb);
+}
+
+// Answers attribute queries as though each account only holds the listed attributes
+// Makes the admin the account that can migrate the contract
+pub fn mock_contract_admin(deps: &mut MockDeps, admin: &str) {
+    let admin = Addr::unchecked(admin);
+    deps.querier
+        .mock_querier
+        .update_wasm(move |query| match query {
+            WasmQuery::ContractInfo { .. } => {
+                let response = ContractInfoResponse::new(
+                    1,
+                    Addr::unchecked("creator"),
+                    Some(admin.clone()),
+                    false,
+                    None,
+                );
+                SystemResult::Ok(ContractResult::Ok(to_json_binary(&response).unwrap()))
+            }
+            _ => panic!("unexpected wasm query"),
+        });
+}
+
+pub fn pause_test(deps: ProvDepsMut, env: Env, sender: &str) -> ProvTxResponse {
+    let info = message_info(&Addr::unchecked(sender), &[]);
+    let msg = ExecuteMsg::Pause {};
+    execute(deps, env, info, msg)
+}
+
+pub fn unpause_test(deps: ProvDepsMut, env: Env, sender: &str) -> ProvTxResponse {
+    let info = message_info(&Addr::unchecked(sender), &[]);
+    let msg = ExecuteMsg::Unpause {};
+    execute(deps, env, info, msg)
+}
+
+pub fn mock_lp_attributes(deps: &mut MockDeps, attributes: &[(&str, &str)]) {
+    let attributes: Vec<(String, String)> = attributes
+        .iter()
+        .map(|(account, name)| (account.to_string(), name.to_string()))
+        .collect();
+    let cb = Box::new(
+        move |bin: &Binary| -> SystemResult<ContractResult<Binary>> {
+            let message = QueryAttributeRequest::try_from(bin.clone()).unwrap();
+            let response = QueryAttributeResponse {
+                account: message.account.clone(),
+                attributes: attributes
+                    .iter()
+                    .filter(|(account, name)| account == &message.account && name == &message.name)
+                    .map(|(account, name)| Attribute {
+                        name: name.clone(),
+                        value: vec![],
+                        attribute_type: AttributeType::String.into(),
+                        address: account.clone(),
+                        expiration_date: None,
+                    })
+                    .collect(),
+                pagination: None,
+            };
+            SystemResult::Ok(ContractResult::Ok(to_json_binary(&response).unwrap()))
+        },
+    );
+    deps.querier
+        .registered_custom_queries
+        .insert("/provenance.attribute.v1.Query/Attribute".to_string(), cb);
+}
+
 pub fn create_test_state(deps: &mut MockDeps, env: &Env, has_settlement: bool) {
     let settlement_time = match has_settlement {
         true => Some(Uint64::new(86400) + Uint64::new(env.block.time.seconds())),